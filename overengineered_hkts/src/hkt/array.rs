@@ -1,60 +1,493 @@
-use std::{cell::RefCell, convert::Infallible, marker::PhantomData, rc::Rc};
+use core::{convert::Infallible, marker::PhantomData};
 
-use naan::fun::{F1, F2Once};
+use alloc::rc::Rc;
 use tap::Pipe;
 
-use crate::hkt::dependent_wrapper::{DependentWrapper, DependentWrapperT};
-use crate::hkt::{FoldWhile, IntoIteratorHkt};
 use crate::{
     hkt::{
-        Applicative, CloneFreeApplicative, DependentCloneK, Foldable, Functor, Hkt, HktUnsized,
-        Monad, Pure, Traversable, id::IdHkt,
+        Align, CloneK, CloneOwnedK, CovariantK, Distributive, FoldWhile, Foldable,
+        FoldableWithIndex, Functor, FunctorWithIndex, Hkt, HktUnsized, Pure, Representable,
+        Rfoldable, TCloneableOf5, Unfoldable, WithIndex, Zip,
+        hkt_classification::{self, HktClassification},
+        id::IdHkt,
+        one_of::{NotT1Of5, OneOf5, OneOf5Hkt, T4Of5Hkt},
+        option::OptionT,
+        these::These,
     },
-    utils::AsFn,
+    marker_classification::{AssertBlankOutput, ConstBool, TypeGuard},
 };
 
+const IN_BOUNDS: &str = "index out of bounds";
+
+/// Fixed size array of `N` elements.
 pub struct ArrayT<const N: usize, TInner = IdHkt>(Infallible, PhantomData<TInner>);
 
-impl<'a, TInner: Hkt<'a>, const N: usize> Hkt<'a> for ArrayT<N, TInner> {
-    type F<A: 'a> = [TInner::F<A>; N];
+impl<'t, TInner: Hkt<'t>, const N: usize> Hkt<'t> for ArrayT<N, TInner> {
+    type F<'a, A: 'a>
+        = [TInner::F<'a, A>; N]
+    where
+        't: 'a;
+}
+
+impl<'t, TInner: HktUnsized<'t>, const N: usize> HktUnsized<'t> for ArrayT<N, TInner> {
+    type FUnsized<'a, A: 'a + ?Sized>
+        = [TInner::FUnsized<'a, A>; N]
+    where
+        't: 'a;
+}
+
+impl<const N: usize, TInner> HktClassification for ArrayT<N, TInner> {
+    type Choice = hkt_classification::OuterHkt;
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: TCloneableOf5<'t>,
+    TInner: Functor<'t, ReqIn, ReqOut, ReqF1>,
+    const N: usize,
+> Functor<'t, ReqIn, ReqOut, ReqF1> for ArrayT<N, TInner>
+{
+    fn map<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> B,
+        F1Mut: 'a + FnMut(A) -> B,
+        F1Fn: 'a + Fn(A) -> B,
+        F1Clone: 'a + Fn(A) -> B + Clone,
+        F1Copy: 'a + Fn(A) -> B + Copy,
+        't: 'a,
+    {
+        let f = ReqF1::arbitrary_uncloneable(f, PhantomData::<fn(A) -> B>);
+
+        fa.map(|x| {
+            TInner::map(
+                clone_a.clone(),
+                clone_b.clone(),
+                ReqF1::clone_one_of_5(&f),
+                x,
+            )
+        })
+    }
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+    TInner: Foldable<'t, ReqIn, ReqOut, ReqF1>,
+    const N: usize,
+> Foldable<'t, ReqIn, ReqOut, ReqF1> for ArrayT<N, TInner>
+{
+    fn fold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+        f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: Self::F<'a, A>,
+    ) -> FoldWhile<B>
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, A) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, A) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, A) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, A) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, A) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        let mut f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, A) -> FoldWhile<B>>);
+
+        fa.into_iter().try_fold(init, move |b, ka| {
+            let f_ref = ReqF1::as_mut(&mut f)
+                .pipe(|f| map_one_of_5_with!(ReqF1, f, |mut f| |b, a| f(b, a)));
+
+            TInner::fold_while(clone_a.clone(), clone_b.clone(), f_ref, b, ka)
+        })
+    }
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+    TInner: Rfoldable<'t, ReqIn, ReqOut, ReqF1>,
+    const N: usize,
+> Rfoldable<'t, ReqIn, ReqOut, ReqF1> for ArrayT<N, TInner>
+{
+    fn rfold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+        f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: Self::F<'a, A>,
+    ) -> FoldWhile<B>
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, A) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, A) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, A) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, A) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, A) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        let mut f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, A) -> FoldWhile<B>>);
+
+        fa.into_iter().rev().try_fold(init, move |b, ka| {
+            let f_ref = ReqF1::as_mut(&mut f)
+                .pipe(|f| map_one_of_5_with!(ReqF1, f, |mut f| |b, a| f(b, a)));
+
+            TInner::rfold_while(clone_a.clone(), clone_b.clone(), f_ref, b, ka)
+        })
+    }
+}
+
+impl<'t, const N: usize> WithIndex<'t> for ArrayT<N> {
+    type Index = usize;
+}
+
+/// Shares each array of `gfa` behind an [Rc], so every position only clones its own element.
+impl<
+    't,
+    const N: usize,
+    G: Functor<'t, ConstBool<false>, ConstBool<false>, T4Of5Hkt> + CloneK<'t, ConstBool<true>>,
+> Distributive<'t, ConstBool<true>, G> for ArrayT<N>
+{
+    fn distribute<'a, A>(
+        clone_a: impl 'a + Fn(&A) -> A + Clone,
+        gfa: G::F<'a, Self::F<'a, A>>,
+    ) -> Self::F<'a, G::F<'a, A>>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        let shared = G::map::<
+            [A; N],
+            Rc<[A; N]>,
+            fn([A; N]) -> Rc<[A; N]>,
+            fn([A; N]) -> Rc<[A; N]>,
+            fn([A; N]) -> Rc<[A; N]>,
+            _,
+            fn([A; N]) -> Rc<[A; N]>,
+        >(|_| AssertBlankOutput, |_| AssertBlankOutput, Rc::new, gfa);
+
+        core::array::from_fn(|i| {
+            let clone_a = clone_a.clone();
+
+            G::map::<
+                Rc<[A; N]>,
+                A,
+                fn(Rc<[A; N]>) -> A,
+                fn(Rc<[A; N]>) -> A,
+                fn(Rc<[A; N]>) -> A,
+                _,
+                fn(Rc<[A; N]>) -> A,
+            >(
+                |_| AssertBlankOutput,
+                |_| AssertBlankOutput,
+                move |array: Rc<[A; N]>| clone_a(&array[i]),
+                G::clone(Rc::clone, &shared),
+            )
+        })
+    }
+}
+
+impl<'t, const N: usize> Representable<'t> for ArrayT<N> {
+    fn tabulate<'a, A>(f: impl 'a + Fn(Self::Index) -> A + Clone) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        core::array::from_fn(f)
+    }
+
+    /// Panics if `index` is out of bounds.
+    fn index<'a, A>(fa: Self::F<'a, A>, index: Self::Index) -> A
+    where
+        A: 'a,
+        't: 'a,
+    {
+        fa.into_iter().nth(index).expect(IN_BOUNDS)
+    }
 }
 
-impl<'a, TInner: HktUnsized<'a>, const N: usize> HktUnsized<'a> for ArrayT<N, TInner> {
-    type FUnsized<A: 'a + ?Sized> = [TInner::FUnsized<A>; N];
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+    const N: usize,
+>
+    FunctorWithIndex<'t, ReqIn, ReqOut, ReqF1> for ArrayT<N>
+{
+    fn map_with_index<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        _clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(usize, A) -> B,
+        F1Mut: 'a + FnMut(usize, A) -> B,
+        F1Fn: 'a + Fn(usize, A) -> B,
+        F1Clone: 'a + Fn(usize, A) -> B + Clone,
+        F1Copy: 'a + Fn(usize, A) -> B + Copy,
+        't: 'a,
+    {
+        let f = ReqF1::arbitrary_t1(f, PhantomData::<fn(usize, A) -> B>);
+
+        let mut i = 0;
+
+        match_one_of_5!(
+            ReqF1::into_one_of_5_enum(f),
+            f,
+            fa.map(|a| {
+                let b = f(i, a);
+                i += 1;
+                b
+            })
+        )
+    }
 }
 
-impl<'a, TInner: Functor<'a>, const N: usize> Functor<'a> for ArrayT<N, TInner> {
-    fn map<A, B, F: 'a + FnMut(A) -> B + Clone>(f: F, fa: Self::F<A>) -> Self::F<B> {
-        fa
-            .map(TInner::map.curry().call(f).as_fn())
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+    const N: usize,
+>
+    FoldableWithIndex<'t, ReqIn, ReqOut, ReqF1> for ArrayT<N>
+{
+    fn fold_while_with_index<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        _clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        _clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+        f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: Self::F<'a, A>,
+    ) -> FoldWhile<B>
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, usize, A) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, usize, A) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, usize, A) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, usize, A) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, usize, A) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        let f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, usize, A) -> FoldWhile<B>>);
+
+        match_one_of_5!(
+            ReqF1::into_one_of_5_enum(f),
+            f,
+            fa.into_iter()
+                .enumerate()
+                .try_fold(init, |b, (i, a)| f(b, i, a))
+        )
+    }
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, TInner: Pure<'t, ReqIn>> Pure<'t, ReqIn> for ArrayT<1, TInner> {
+    fn pure<'a, A>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        a: A,
+    ) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        [TInner::pure(clone_a, a)]
+    }
+}
+
+/// Always unfolds exactly `N` times, positions after `step` returns [None] are [None].
+impl<'t, ReqIn: TypeGuard<'t>, const N: usize> Unfoldable<'t, ReqIn> for ArrayT<N, OptionT> {
+    fn unfold<'a, A, S>(
+        _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        mut step: impl 'a + FnMut(S) -> Option<(A, S)>,
+        seed: S,
+    ) -> Self::F<'a, A>
+    where
+        A: 'a,
+        S: 'a,
+        't: 'a,
+    {
+        let mut seed = Some(seed);
+
+        core::array::from_fn(|_| {
+            let (a, next) = step(seed.take()?)?;
+            seed = Some(next);
+            Some(a)
+        })
     }
 }
 
-impl<'a, TInner: Pure<'a>> Pure<'a> for ArrayT<1, TInner> {
-    fn pure<A: 'a>(a: A) -> Self::F<A> {
-        [TInner::pure(a)]
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: TCloneableOf5<'t>,
+    TInner: Zip<'t, ReqIn, ReqOut, ReqF1>,
+    const N: usize,
+> Zip<'t, ReqIn, ReqOut, ReqF1> for ArrayT<N, TInner>
+{
+    fn zip_with<'a, A, B, C, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqIn::Output<'a, B> + Clone,
+        clone_c: impl 'a + Fn(&C) -> ReqOut::Output<'a, C> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+        fb: Self::F<'a, B>,
+    ) -> Self::F<'a, C>
+    where
+        A: 'a,
+        B: 'a,
+        C: 'a,
+        F1Once: 'a + FnOnce(A, B) -> C,
+        F1Mut: 'a + FnMut(A, B) -> C,
+        F1Fn: 'a + Fn(A, B) -> C,
+        F1Clone: 'a + Fn(A, B) -> C + Clone,
+        F1Copy: 'a + Fn(A, B) -> C + Copy,
+        't: 'a,
+    {
+        let f = ReqF1::arbitrary_uncloneable(f, PhantomData::<fn(A, B) -> C>);
+        let mut fb = fb.into_iter();
+
+        fa.map(|ta| {
+            // Both arrays have exactly N elements.
+            let Some(tb) = fb.next() else { unreachable!() };
+
+            TInner::zip_with(
+                clone_a.clone(),
+                clone_b.clone(),
+                clone_c.clone(),
+                ReqF1::clone_one_of_5(&f),
+                ta,
+                tb,
+            )
+        })
+    }
+
+    fn unzip<'a, A, B>(fab: Self::F<'a, (A, B)>) -> (Self::F<'a, A>, Self::F<'a, B>)
+    where
+        A: 'a,
+        B: 'a,
+        't: 'a,
+    {
+        let mut fab = fab.map(|tab| TInner::unzip(tab).pipe(|(ta, tb)| (Some(ta), Some(tb))));
+
+        let fa = core::array::from_fn(|i| fab[i].0.take().unwrap_or_else(|| unreachable!()));
+        let fb = core::array::from_fn(|i| fab[i].1.take().unwrap_or_else(|| unreachable!()));
+
+        (fa, fb)
     }
 }
 
-impl<'a, TInner: Pure<'a>> Pure<'a> for ArrayT<0, TInner> {
-    fn pure<A: 'a>(_: A) -> Self::F<A> {
-        []
+/// Arrays of the same length always align with [These::Both].
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: TCloneableOf5<'t>,
+    TInner: Align<'t, ReqIn, ReqOut, ReqF1>,
+    const N: usize,
+> Align<'t, ReqIn, ReqOut, ReqF1> for ArrayT<N, TInner>
+{
+    fn align_with<'a, A, B, C, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqIn::Output<'a, B> + Clone,
+        clone_c: impl 'a + Fn(&C) -> ReqOut::Output<'a, C> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+        fb: Self::F<'a, B>,
+    ) -> Self::F<'a, C>
+    where
+        A: 'a,
+        B: 'a,
+        C: 'a,
+        F1Once: 'a + FnOnce(These<A, B>) -> C,
+        F1Mut: 'a + FnMut(These<A, B>) -> C,
+        F1Fn: 'a + Fn(These<A, B>) -> C,
+        F1Clone: 'a + Fn(These<A, B>) -> C + Clone,
+        F1Copy: 'a + Fn(These<A, B>) -> C + Copy,
+        't: 'a,
+    {
+        let f = ReqF1::arbitrary_uncloneable(f, PhantomData::<fn(These<A, B>) -> C>);
+        let mut fb = fb.into_iter();
+
+        fa.map(|ta| {
+            // Both arrays have exactly N elements.
+            let Some(tb) = fb.next() else { unreachable!() };
+
+            TInner::align_with(
+                clone_a.clone(),
+                clone_b.clone(),
+                clone_c.clone(),
+                ReqF1::clone_one_of_5(&f),
+                ta,
+                tb,
+            )
+        })
     }
 }
 
-impl<'a, TInner: DependentCloneK<'a>, const N: usize> DependentCloneK<'a> for ArrayT<N, TInner> {
-    fn clone<A: 'a + Clone>(a: &Self::F<A>) -> Self::F<A> {
-        a.each_ref().map(TInner::clone)
+impl<'t, TInner: CovariantK<'t>, const N: usize> CovariantK<'t> for ArrayT<N, TInner> {
+    fn covariant_convert<'a, 'b, A>(a: Self::F<'a, A>) -> Self::F<'b, A>
+    where
+        A: 'a,
+        'a: 'b,
+        't: 'a + 'b,
+    {
+        a.map(TInner::covariant_convert)
     }
 }
 
-impl<'a, TInner: Foldable<'a>, const N: usize> Foldable<'a> for ArrayT<N, TInner> {
-    fn fold_while<A, B, F>(f: F, init: B, fb: Self::F<A>) -> FoldWhile<B>
+impl<'t, ReqIn: TypeGuard<'t>, TInner: CloneOwnedK<'t, ReqIn>, const N: usize>
+    CloneOwnedK<'t, ReqIn> for ArrayT<N, TInner>
+{
+    fn clone_owned<'a, 'b, A>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'b, A> + Clone,
+        a: &Self::F<'a, A>,
+    ) -> Self::F<'b, A>
     where
-        F: 'a + FnMut(B, A) -> FoldWhile<B> + Clone,
+        A: 'a + 'b,
+        't: 'a + 'b,
     {
-        fb.into_iter()
-            .try_fold(init, |b, ka| TInner::fold_while(f.clone(), b, ka))
+        a.each_ref()
+            .map(|ta| TInner::clone_owned(clone_a.clone(), ta))
     }
 }
 
+impl<'t, ReqIn: TypeGuard<'t>, TInner: CloneK<'t, ReqIn>, const N: usize> CloneK<'t, ReqIn>
+    for ArrayT<N, TInner>
+{
+    fn clone<'a, A>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        a: &Self::F<'a, A>,
+    ) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        a.each_ref().map(|ta| TInner::clone(clone_a.clone(), ta))
+    }
+}
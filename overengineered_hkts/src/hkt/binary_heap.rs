@@ -0,0 +1,178 @@
+use core::{convert::Infallible, marker::PhantomData};
+
+use alloc::collections::BinaryHeap;
+use tap::Pipe;
+
+use crate::{
+    hkt::{
+        FoldWhile, Foldable, Hkt, HktUnsized,
+        hkt_classification::{self, HktClassification},
+        id::IdHkt,
+        one_of::{NotT1Of5, OneOf5Hkt},
+        reference::RefT,
+    },
+    marker_classification::TypeGuard,
+    utils::sum_size_hints,
+};
+
+/// Fold-only: [Functor](crate::hkt::Functor) would need `Ord` on the mapped elements.
+///
+/// [Foldable] has no `Ord` on the elements, so it visits them in heap order, i.e. the order of the underlying array, which is not sorted.
+/// [fold_while_by_priority](BinaryHeapT::fold_while_by_priority) folds them greatest first.
+pub struct BinaryHeapT<TInner = IdHkt>(Infallible, PhantomData<TInner>);
+
+impl<'t, TInner: Hkt<'t>> Hkt<'t> for BinaryHeapT<TInner> {
+    type F<'a, A: 'a>
+        = BinaryHeap<TInner::F<'a, A>>
+    where
+        't: 'a;
+}
+
+impl<'t, TInner: HktUnsized<'t>> HktUnsized<'t> for BinaryHeapT<TInner> {
+    type FUnsized<'a, A: 'a + ?Sized>
+        = BinaryHeap<TInner::FUnsized<'a, A>>
+    where
+        't: 'a;
+}
+
+impl<TInner> HktClassification for BinaryHeapT<TInner> {
+    type Choice = hkt_classification::OuterHkt;
+}
+
+impl<TInner> BinaryHeapT<TInner> {
+    /// [Foldable::fold_while] in priority order, i.e. the order [BinaryHeap::pop] would yield.
+    pub fn fold_while_by_priority<
+        't,
+        'a,
+        'b,
+        'f,
+        A,
+        B,
+        ReqIn,
+        ReqOut,
+        ReqF1,
+        F1Once,
+        F1Mut,
+        F1Fn,
+        F1Clone,
+        F1Copy,
+    >(
+        clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+        f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: <Self as Hkt<'t>>::F<'a, A>,
+    ) -> FoldWhile<B>
+    where
+        ReqIn: TypeGuard<'t>,
+        ReqOut: TypeGuard<'t>,
+        ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+        TInner: Foldable<'t, ReqIn, ReqOut, ReqF1>,
+        TInner::F<'a, A>: Ord,
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, A) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, A) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, A) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, A) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, A) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        let mut f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, A) -> FoldWhile<B>>);
+
+        fa.into_sorted_vec()
+            .into_iter()
+            .rev()
+            .try_fold(init, move |b, ka| {
+                let f_ref = ReqF1::as_mut(&mut f)
+                    .pipe(|f| map_one_of_5_with!(ReqF1, f, |mut f| |b, a| f(b, a)));
+
+                TInner::fold_while(clone_a.clone(), clone_b.clone(), f_ref, b, ka)
+            })
+    }
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+    TInner: Foldable<'t, ReqIn, ReqOut, ReqF1>,
+> Foldable<'t, ReqIn, ReqOut, ReqF1> for BinaryHeapT<TInner>
+{
+    fn fold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+        f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: Self::F<'a, A>,
+    ) -> FoldWhile<B>
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, A) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, A) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, A) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, A) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, A) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        let mut f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, A) -> FoldWhile<B>>);
+
+        fa.into_vec().into_iter().try_fold(init, move |b, ka| {
+            let f_ref = ReqF1::as_mut(&mut f)
+                .pipe(|f| map_one_of_5_with!(ReqF1, f, |mut f| |b, a| f(b, a)));
+
+            TInner::fold_while(clone_a.clone(), clone_b.clone(), f_ref, b, ka)
+        })
+    }
+
+    fn size_hint<'a, A>(s: &Self::F<'a, A>) -> (usize, Option<usize>)
+    where
+        't: 'a,
+    {
+        sum_size_hints(s.iter().map(|x| TInner::size_hint(x)))
+    }
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+    TInner: Foldable<'t, ReqIn, ReqOut, ReqF1, RefT>,
+> Foldable<'t, ReqIn, ReqOut, ReqF1, RefT> for BinaryHeapT<TInner>
+{
+    fn fold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'f + Fn(&A) -> <ReqIn>::Output<'a, A> + Clone,
+        clone_b: impl 'f + Fn(&B) -> <ReqOut>::Output<'b, B> + Clone,
+        f: <ReqF1>::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: <RefT as Hkt<'t>>::F<'a, Self::F<'a, A>>,
+    ) -> FoldWhile<B>
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        let mut f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, &'a A) -> FoldWhile<B>>);
+
+        fa.iter().try_fold(init, move |b, ka| {
+            let f_ref = ReqF1::as_mut(&mut f)
+                .pipe(|f| map_one_of_5_with!(ReqF1, f, |mut f| |b, a| f(b, a)));
+
+            TInner::fold_while(clone_a.clone(), clone_b.clone(), f_ref, b, ka)
+        })
+    }
+}
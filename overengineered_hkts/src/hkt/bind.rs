@@ -4,7 +4,7 @@ use tap::Pipe as _;
 
 use crate::{
     hkt::{
-        CloneK, CovariantK, FoldWhile, Foldable, Functor, Hkt, HktUnsized, Rfoldable, TCloneableOf5, UnsizedHkt, UnsizedHktUnsized, one_of::{NotT5Of5, OneOf5Hkt, T5Of5Hkt}
+        CloneK, CovariantK, FoldWhile, Foldable, FoldableWithIndex, Functor, FunctorWithIndex, Hkt, HktUnsized, Pure, Rfoldable, TCloneableOf5, Unfoldable, UnsizedHkt, UnsizedHktUnsized, WithIndex, one_of::{NotT5Of5, OneOf5Hkt, T5Of5Hkt}
     },
     marker_classification::{AssertBlankOutput, ConstBool, TypeGuard},
     utils::CloneWrapper,
@@ -332,3 +332,157 @@ impl<
         )
     }
 }
+
+impl<'t, TOuter: WithIndex<'t>, TInner: WithIndex<'t>> WithIndex<'t> for BindT<TOuter, TInner> {
+    type Index = (TOuter::Index, TInner::Index);
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: TCloneableOf5<'t> + NotT5Of5<'t>,
+    TOuter: FunctorWithIndex<'t, ReqIn, ReqOut, ReqF1, Index: Clone>,
+    TInner: FunctorWithIndex<'t, ReqIn, ReqOut, ReqF1> + CloneK<'t, ReqIn> + CloneK<'t, ReqOut>,
+> FunctorWithIndex<'t, ReqIn, ReqOut, ReqF1> for BindT<TOuter, TInner>
+{
+    fn map_with_index<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(Self::Index, A) -> B,
+        F1Mut: 'a + FnMut(Self::Index, A) -> B,
+        F1Fn: 'a + Fn(Self::Index, A) -> B,
+        F1Clone: 'a + Fn(Self::Index, A) -> B + Clone,
+        F1Copy: 'a + Fn(Self::Index, A) -> B + Copy,
+        't: 'a,
+    {
+        let f = ReqF1::arbitrary_uncloneable(f, PhantomData::<fn(Self::Index, A) -> B>);
+
+        let f = CloneWrapper(f, |f: &_| ReqF1::clone_one_of_5(f));
+
+        TOuter::map_with_index(
+            {
+                let clone_a2 = clone_a.clone();
+                move |a| {
+                    <TInner as CloneK<'t, ReqIn>>::clone(clone_a2.clone(), a)
+                        .pipe(ReqIn::into_guarded)
+                }
+            },
+            {
+                let clone_b2 = clone_b.clone();
+                move |b| {
+                    <TInner as CloneK<'t, ReqOut>>::clone(clone_b2.clone(), b)
+                        .pipe(ReqOut::into_guarded)
+                }
+            },
+            ReqF1::create_from(&f.clone().0, move |i: TOuter::Index, ka| {
+                let f = map_one_of_5_with!(ReqF1, f.clone().0, |mut f| {
+                    let i = i.clone();
+                    move |j: TInner::Index, a: A| f((i.clone(), j), a)
+                })
+                .pipe(|f| ReqF1::arbitrary_t5(f, PhantomData::<fn(TInner::Index, A) -> B>));
+
+                TInner::map_with_index(clone_a.clone(), clone_b.clone(), f, ka)
+            })
+            .pipe(|f| {
+                ReqF1::arbitrary_t5(
+                    f,
+                    PhantomData::<fn(TOuter::Index, TInner::F<'a, A>) -> TInner::F<'a, B>>,
+                )
+            }),
+            fa,
+        )
+    }
+}
+
+// Limited due to input clone func lifetime limit
+impl<
+    't,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t> + TCloneableOf5<'t> + NotT5Of5<'t>,
+    TOuter: FoldableWithIndex<'t, ConstBool<false>, ReqOut, ReqF1, Index: Clone>,
+    TInner: FoldableWithIndex<'t, ConstBool<false>, ReqOut, ReqF1>,
+> FoldableWithIndex<'t, ConstBool<false>, ReqOut, ReqF1> for BindT<TOuter, TInner>
+{
+    fn fold_while_with_index<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        _: impl 'f + Fn(&A) -> AssertBlankOutput + Clone,
+        clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+        f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: Self::F<'a, A>,
+    ) -> FoldWhile<B>
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, Self::Index, A) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, Self::Index, A) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, Self::Index, A) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, Self::Index, A) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, Self::Index, A) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        let f =
+            ReqF1::arbitrary_uncloneable(f, PhantomData::<fn(B, Self::Index, A) -> FoldWhile<B>>);
+
+        TOuter::fold_while_with_index(
+            |_| AssertBlankOutput,
+            clone_b.clone(),
+            ReqF1::create_from(&f, |b, i: TOuter::Index, ka| {
+                let f = map_one_of_5_with!(ReqF1, ReqF1::clone_one_of_5(&f), |mut f| {
+                    let i = i.clone();
+                    move |b, j: TInner::Index, a: A| f(b, (i.clone(), j), a)
+                })
+                .pipe(|f| {
+                    ReqF1::arbitrary_t5(f, PhantomData::<fn(B, TInner::Index, A) -> FoldWhile<B>>)
+                });
+
+                TInner::fold_while_with_index(|_| AssertBlankOutput, clone_b.clone(), f, b, ka)
+            })
+            .pipe(|f| {
+                ReqF1::arbitrary_t5(
+                    f,
+                    PhantomData::<fn(B, TOuter::Index, TInner::F<'a, A>) -> FoldWhile<B>>,
+                )
+            }),
+            init,
+            fa,
+        )
+    }
+}
+
+/// Each element is wrapped with [Pure::pure] of `TInner`.
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    TOuter: Unfoldable<'t, ReqIn>,
+    TInner: Pure<'t, ReqIn> + CloneK<'t, ReqIn>,
+> Unfoldable<'t, ReqIn> for BindT<TOuter, TInner>
+{
+    fn unfold<'a, A, S>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        mut step: impl 'a + FnMut(S) -> Option<(A, S)>,
+        seed: S,
+    ) -> Self::F<'a, A>
+    where
+        A: 'a,
+        S: 'a,
+        't: 'a,
+    {
+        TOuter::unfold(
+            {
+                let clone_a = clone_a.clone();
+                move |ta| TInner::clone(clone_a.clone(), ta).pipe(ReqIn::into_guarded)
+            },
+            move |s| step(s).map(|(a, s)| (TInner::pure(clone_a.clone(), a), s)),
+            seed,
+        )
+    }
+}
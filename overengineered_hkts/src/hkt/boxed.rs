@@ -9,7 +9,7 @@ use crate::hkt::hkt_classification::{self, HktClassification};
 use crate::hkt::one_of::OneOf5Hkt;
 use crate::hkt::reference::RefT;
 use crate::hkt::{
-    Applicative, CloneK, CloneOwnedK, CovariantK, FoldWhile, Foldable, Functor, Monad,
+    Applicative, CloneK, CloneOwnedK, CovariantK, DerefHkt, DerefMutHkt, FoldWhile, Foldable, Functor, Monad,
     Pure, Rfoldable, Traversable, UnsizedHkt, UnsizedHktUnsized,
 };
 use crate::marker_classification::{ConstBool, TypeGuard, TyEq};
@@ -38,6 +38,24 @@ impl<TInner> HktClassification for BoxT<TInner> {
     type Choice = hkt_classification::OuterHkt;
 }
 
+impl<'t, TInner: DerefHkt<'t>> DerefHkt<'t> for BoxT<TInner> {
+    fn deref<'a, 'b: 'a, A: 'a + ?Sized>(fa: &'a Self::UnsizedFUnsized<'b, A>) -> &'a A
+    where
+        't: 'b + 'a,
+    {
+        TInner::deref(fa)
+    }
+}
+
+impl<'t, TInner: DerefMutHkt<'t>> DerefMutHkt<'t> for BoxT<TInner> {
+    fn deref_mut<'a, 'b: 'a, A: 'a + ?Sized>(fa: &'a mut Self::UnsizedFUnsized<'b, A>) -> &'a mut A
+    where
+        't: 'b + 'a,
+    {
+        TInner::deref_mut(fa)
+    }
+}
+
 // impl<'a, TInner: Functor<'a>> Functor<'a> for BoxT<TInner> {
 //     fn map<A, B, F: 'a + FnMut(A) -> B + Clone>(f: F, fa: Self::F<A>) -> Self::F<B> {
 //         let fa: Box<TInner::F<A>> = fa;
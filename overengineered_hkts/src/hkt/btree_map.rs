@@ -0,0 +1,66 @@
+use core::{convert::Infallible, marker::PhantomData};
+
+use alloc::{collections::BTreeMap, vec::Vec};
+use tap::Pipe;
+
+use crate::{
+    hkt::{
+        Applicative, CloneK, CloneOwnedK, CovariantK, FoldWhile, Foldable, FoldableWithIndex,
+        Functor, FunctorWithIndex, Hkt, HktUnsized, PhantomMarker, Rfoldable, SemigroupK,
+        TCloneableOf5, Traversable, TraversableWithIndex, WithIndex,
+        hkt_classification::{self, HktClassification},
+        id::IdHkt,
+        one_of::{NotT1Of5, NotT5Of5, OneOf5, OneOf5Hkt},
+        reference::{RefMutT, RefT},
+        vec::VecT,
+    },
+    marker_classification::{AssertBlankOutput, ConstBool, TypeGuard},
+    utils::sum_size_hints,
+};
+
+impl_map_hkt!(
+    /// Hkt over the values of a [BTreeMap], folding in key order.
+    BTreeMapT,
+    BTreeMap,
+    Ord
+);
+
+/// Folds the values in reverse key order.
+impl<
+    't,
+    K: 't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+    TInner: Rfoldable<'t, ReqIn, ReqOut, ReqF1>,
+> Rfoldable<'t, ReqIn, ReqOut, ReqF1> for BTreeMapT<K, TInner>
+{
+    fn rfold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+        f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: Self::F<'a, A>,
+    ) -> FoldWhile<B>
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, A) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, A) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, A) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, A) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, A) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        let mut f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, A) -> FoldWhile<B>>);
+
+        fa.into_values().rev().try_fold(init, move |b, ka| {
+            let f_ref = ReqF1::as_mut(&mut f)
+                .pipe(|f| map_one_of_5_with!(ReqF1, f, |mut f| |b, a| f(b, a)));
+
+            TInner::rfold_while(clone_a.clone(), clone_b.clone(), f_ref, b, ka)
+        })
+    }
+}
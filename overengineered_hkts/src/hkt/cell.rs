@@ -0,0 +1,187 @@
+use core::{
+    cell::{Cell, RefCell},
+    convert::Infallible,
+    marker::PhantomData,
+};
+
+use tap::Pipe as _;
+
+use crate::{
+    hkt::{
+        Applicative, CloneK, FoldWhile, Foldable, Functor, Hkt, HktUnsized, Traversable,
+        hkt_classification::{self, HktClassification},
+        id::IdHkt,
+        one_of::OneOf5Hkt,
+    },
+    marker_classification::{AssertBlankOutput, ConstBool, TypeGuard},
+};
+
+pub struct CellT<TInner = IdHkt>(Infallible, PhantomData<TInner>);
+
+impl<'t, TInner: Hkt<'t>> Hkt<'t> for CellT<TInner> {
+    type F<'a, A: 'a>
+        = Cell<TInner::F<'a, A>>
+    where
+        't: 'a;
+}
+
+impl<'t, TInner: HktUnsized<'t>> HktUnsized<'t> for CellT<TInner> {
+    type FUnsized<'a, A: 'a + ?Sized>
+        = Cell<TInner::FUnsized<'a, A>>
+    where
+        't: 'a;
+}
+
+impl<TInner> HktClassification for CellT<TInner> {
+    type Choice = hkt_classification::OuterHkt;
+}
+
+pub struct RefCellT<TInner = IdHkt>(Infallible, PhantomData<TInner>);
+
+impl<'t, TInner: Hkt<'t>> Hkt<'t> for RefCellT<TInner> {
+    type F<'a, A: 'a>
+        = RefCell<TInner::F<'a, A>>
+    where
+        't: 'a;
+}
+
+impl<'t, TInner: HktUnsized<'t>> HktUnsized<'t> for RefCellT<TInner> {
+    type FUnsized<'a, A: 'a + ?Sized>
+        = RefCell<TInner::FUnsized<'a, A>>
+    where
+        't: 'a;
+}
+
+impl<TInner> HktClassification for RefCellT<TInner> {
+    type Choice = hkt_classification::OuterHkt;
+}
+
+/// Instances consume the cell with `into_inner` and rewrap the result.
+macro_rules! impl_cell_hkt {
+    ($hkt:ident, $cell:ident) => {
+        impl<
+            't,
+            ReqIn: TypeGuard<'t>,
+            ReqOut: TypeGuard<'t>,
+            ReqF1: OneOf5Hkt<'t>,
+            TInner: Functor<'t, ReqIn, ReqOut, ReqF1>,
+        > Functor<'t, ReqIn, ReqOut, ReqF1> for $hkt<TInner>
+        {
+            fn map<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+                clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+                f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+                fa: Self::F<'a, A>,
+            ) -> Self::F<'a, B>
+            where
+                A: 'a,
+                B: 'a,
+                F1Once: 'a + FnOnce(A) -> B,
+                F1Mut: 'a + FnMut(A) -> B,
+                F1Fn: 'a + Fn(A) -> B,
+                F1Clone: 'a + Fn(A) -> B + Clone,
+                F1Copy: 'a + Fn(A) -> B + Copy,
+                't: 'a,
+            {
+                <TInner as Functor<'t, ReqIn, ReqOut, ReqF1>>::map(
+                    clone_a,
+                    clone_b,
+                    f,
+                    fa.into_inner(),
+                )
+                .pipe($cell::new)
+            }
+        }
+
+        impl<
+            't,
+            ReqIn: TypeGuard<'t>,
+            ReqOut: TypeGuard<'t>,
+            ReqF1: OneOf5Hkt<'t>,
+            TInner: Foldable<'t, ReqIn, ReqOut, ReqF1>,
+        > Foldable<'t, ReqIn, ReqOut, ReqF1> for $hkt<TInner>
+        {
+            fn fold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+                clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+                f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+                init: B,
+                fa: Self::F<'a, A>,
+            ) -> FoldWhile<B>
+            where
+                A: 'a,
+                B: 'b,
+                F1Once: 'f + FnOnce(B, A) -> FoldWhile<B>,
+                F1Mut: 'f + FnMut(B, A) -> FoldWhile<B>,
+                F1Fn: 'f + Fn(B, A) -> FoldWhile<B>,
+                F1Clone: 'f + Fn(B, A) -> FoldWhile<B> + Clone,
+                F1Copy: 'f + Fn(B, A) -> FoldWhile<B> + Copy,
+                'a: 'f,
+                'b: 'f,
+                't: 'a + 'b,
+            {
+                <TInner as Foldable<'t, ReqIn, ReqOut, ReqF1>>::fold_while(
+                    clone_a,
+                    clone_b,
+                    f,
+                    init,
+                    fa.into_inner(),
+                )
+            }
+        }
+
+        impl<
+            't,
+            ReqIn: TypeGuard<'t>,
+            ReqOut: TypeGuard<'t>,
+            ReqF1: OneOf5Hkt<'t>,
+            TInner: Traversable<'t, ReqIn, ReqOut, ReqF1> + CloneK<'t, ReqOut>,
+        > Traversable<'t, ReqIn, ReqOut, ReqF1> for $hkt<TInner>
+        {
+            fn traverse<'a, A, B, F, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+                clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+                f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+                fa: Self::F<'a, A>,
+            ) -> F::F<'a, Self::F<'a, B>>
+            where
+                A: 'a,
+                B: 'a,
+                F1Once: 'a + FnOnce(A) -> F::F<'a, B>,
+                F1Mut: 'a + FnMut(A) -> F::F<'a, B>,
+                F1Fn: 'a + Fn(A) -> F::F<'a, B>,
+                F1Clone: 'a + Fn(A) -> F::F<'a, B> + Clone,
+                F1Copy: 'a + Fn(A) -> F::F<'a, B> + Copy,
+                F: Applicative<'t, ReqIn, ReqOut, ReqF1>
+                    + Functor<'t, ReqIn, ConstBool<false>, ReqF1>,
+                't: 'a,
+            {
+                let f_lock = ReqF1::create_from(&f, $cell::new);
+
+                let result = <TInner as Traversable<'t, ReqIn, ReqOut, ReqF1>>::traverse::<
+                    A,
+                    B,
+                    F,
+                    _,
+                    _,
+                    _,
+                    _,
+                    _,
+                >(clone_a, clone_b.clone(), f, fa.into_inner());
+
+                <F as Functor<'t, ReqIn, ConstBool<false>, ReqF1>>::map(
+                    move |tb| {
+                        <TInner as CloneK<'t, ReqOut>>::clone(clone_b.clone(), tb)
+                            .pipe(ReqIn::into_guarded)
+                    },
+                    |_| AssertBlankOutput,
+                    f_lock,
+                    result,
+                )
+            }
+        }
+    };
+}
+
+impl_cell_hkt!(CellT, Cell);
+impl_cell_hkt!(RefCellT, RefCell);
@@ -0,0 +1,405 @@
+use core::{
+    cell::RefCell,
+    convert::{Infallible, identity},
+    marker::PhantomData,
+    mem,
+};
+
+use alloc::{boxed::Box, rc::Rc, vec::Vec};
+use tap::Pipe as _;
+
+use crate::{
+    hkt::{
+        Applicative, CloneK, Functor, Hkt, Monad, Pure, Traversable,
+        hkt_classification::{self, HktClassification},
+        id::IdHkt,
+        one_of::{NotT5Of5, OneOf5, OneOf5Hkt},
+    },
+    marker_classification::{AssertBlankOutput, ConstBool, TypeGuard},
+};
+
+trait Erased {}
+
+impl<T: ?Sized> Erased for T {}
+
+/// Intermediate value whose type is only known by the [Step] that produced it and the [Continuation] that consumes it.
+type ErasedBox<'a> = Box<dyn 'a + Erased>;
+
+type Continuation<'a> = Box<dyn 'a + FnOnce(ErasedBox<'a>) -> Step<'a>>;
+
+fn erase<'a, T: 'a>(value: T) -> ErasedBox<'a> {
+    Box::new(value)
+}
+
+/// # Safety
+///
+/// `value` must have been created by [`erase::<T>`](erase).
+unsafe fn unerase<'a, T: 'a>(value: ErasedBox<'a>) -> T {
+    // SAFETY: the data pointer of the trait object points to a `T` allocated by `Box::new` in `erase`,
+    // dropping the vtable leaves exactly that pointer.
+    unsafe { *Box::from_raw(Box::into_raw(value).cast::<T>()) }
+}
+
+/// # Safety
+///
+/// `value` must have been created by [`erase::<T>`](erase).
+unsafe fn unerase_ref<'b, 'a, T: 'a>(value: &'b ErasedBox<'a>) -> &'b T {
+    // SAFETY: see `unerase`.
+    unsafe { &*(&**value as *const dyn Erased).cast::<T>() }
+}
+
+/// # Safety
+///
+/// `value` must have been created by [`erase::<T>`](erase).
+unsafe fn clone_erased<'a, T: 'a + Clone>(value: &ErasedBox<'a>) -> ErasedBox<'a> {
+    // SAFETY: forwarded to the caller.
+    erase(unsafe { unerase_ref::<T>(value) }.clone())
+}
+
+enum Step<'a> {
+    Now(ErasedBox<'a>),
+    Later(Box<dyn 'a + FnOnce() -> ErasedBox<'a>>),
+    Always(Rc<dyn 'a + Fn() -> ErasedBox<'a>>),
+    Defer(Box<dyn 'a + FnOnce() -> Step<'a>>),
+    FlatMap(Box<Step<'a>>, Continuation<'a>),
+    /// Evaluation shared between clones of an [Eval].
+    Memo(Rc<Memo<'a>>),
+}
+
+impl<'a> Step<'a> {
+    /// Stands in for a step that has been moved out, never run.
+    fn taken() -> Self {
+        Step::Now(erase(()))
+    }
+}
+
+struct Memo<'a> {
+    state: RefCell<MemoState<'a>>,
+    /// Clones the erased value of `state` for every run.
+    clone_value: unsafe fn(&ErasedBox<'a>) -> ErasedBox<'a>,
+}
+
+enum MemoState<'a> {
+    Pending(Step<'a>),
+    Running,
+    Done(ErasedBox<'a>),
+}
+
+/// Stack-safe deferred computation, similar to `Eval` in Scala's cats.
+///
+/// [flat_map](Eval::flat_map) only records the continuation, [run](Eval::run) then interprets the chain with a heap-allocated stack,
+/// so arbitrarily deep binds (both left and right nested) and recursion through [defer](Eval::defer) run in constant stack space.
+/// Dropping a chain that was never run unlinks it iteratively as well.
+///
+/// Clones share the evaluation: [later](Eval::later) runs once and its result is cloned out for every clone,
+/// while [always](Eval::always) runs again each time a clone is run.
+pub struct Eval<'a, A> {
+    step: RefCell<Step<'a>>,
+    _value: PhantomData<A>,
+}
+
+impl<'a, A: 'a> Eval<'a, A> {
+    fn from_step(step: Step<'a>) -> Self {
+        Self {
+            step: RefCell::new(step),
+            _value: PhantomData,
+        }
+    }
+
+    fn into_step(mut self) -> Step<'a> {
+        mem::replace(self.step.get_mut(), Step::taken())
+    }
+
+    /// Already evaluated value.
+    pub fn now(a: A) -> Self {
+        Self::from_step(Step::Now(erase(a)))
+    }
+
+    /// Evaluated when run, at most once across clones.
+    pub fn later(f: impl 'a + FnOnce() -> A) -> Self {
+        Self::from_step(Step::Later(Box::new(move || erase(f()))))
+    }
+
+    /// Evaluated every time the [Eval] or one of its clones is run.
+    pub fn always(f: impl 'a + Fn() -> A) -> Self {
+        Self::from_step(Step::Always(Rc::new(move || erase(f()))))
+    }
+
+    /// Defers building the [Eval] itself, for recursive definitions.
+    pub fn defer(f: impl 'a + FnOnce() -> Eval<'a, A>) -> Self {
+        Self::from_step(Step::Defer(Box::new(move || f().into_step())))
+    }
+
+    pub fn flat_map<B: 'a>(self, f: impl 'a + FnOnce(A) -> Eval<'a, B>) -> Eval<'a, B> {
+        Eval::from_step(Step::FlatMap(
+            Box::new(self.into_step()),
+            // SAFETY: `self.step` evaluates to an erased `A`.
+            Box::new(move |a| f(unsafe { unerase::<A>(a) }).into_step()),
+        ))
+    }
+
+    pub fn map<B: 'a>(self, f: impl 'a + FnOnce(A) -> B) -> Eval<'a, B> {
+        Eval::from_step(Step::FlatMap(
+            Box::new(self.into_step()),
+            // SAFETY: `self.step` evaluates to an erased `A`.
+            Box::new(move |a| Step::Now(erase(f(unsafe { unerase::<A>(a) })))),
+        ))
+    }
+
+    /// Interprets the computation in a loop.
+    ///
+    /// # Panics
+    ///
+    /// If a shared evaluation needs its own result.
+    pub fn run(self) -> A {
+        let mut continuations: Vec<Continuation<'a>> = Vec::new();
+        let mut current = self.into_step();
+
+        loop {
+            current = match current {
+                Step::Now(value) => match continuations.pop() {
+                    Some(continuation) => continuation(value),
+                    // SAFETY: with no continuation left, `value` is the erased output of `self.step`, an `A`.
+                    None => return unsafe { unerase::<A>(value) },
+                },
+                Step::Later(f) => Step::Now(f()),
+                Step::Always(f) => Step::Now(f()),
+                Step::Defer(f) => f(),
+                Step::FlatMap(step, continuation) => {
+                    continuations.push(continuation);
+                    *step
+                }
+                Step::Memo(memo) => {
+                    let mut state = memo.state.borrow_mut();
+                    match mem::replace(&mut *state, MemoState::Running) {
+                        MemoState::Pending(step) => {
+                            drop(state);
+                            continuations.push(Box::new(move |value| {
+                                // SAFETY: `clone_value` was created for the output type of the pending step.
+                                let cloned = unsafe { (memo.clone_value)(&value) };
+                                *memo.state.borrow_mut() = MemoState::Done(value);
+                                Step::Now(cloned)
+                            }));
+                            step
+                        }
+                        MemoState::Done(value) => {
+                            // SAFETY: `clone_value` was created for the output type of the pending step.
+                            let cloned = unsafe { (memo.clone_value)(&value) };
+                            *state = MemoState::Done(value);
+                            Step::Now(cloned)
+                        }
+                        MemoState::Running => panic!("Eval depends on its own result"),
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<'a, A: 'a + Clone> Clone for Eval<'a, A> {
+    fn clone(&self) -> Self {
+        let mut step = self.step.borrow_mut();
+        let memo = match &*step {
+            // SAFETY: the top-level step evaluates to an erased `A`.
+            Step::Now(value) => return Eval::now(unsafe { unerase_ref::<A>(value) }.clone()),
+            Step::Always(f) => return Eval::from_step(Step::Always(f.clone())),
+            Step::Memo(memo) => memo.clone(),
+            _ => {
+                let memo = Rc::new(Memo {
+                    state: RefCell::new(MemoState::Pending(mem::replace(
+                        &mut *step,
+                        Step::taken(),
+                    ))),
+                    clone_value: clone_erased::<A>,
+                });
+                *step = Step::Memo(memo.clone());
+                memo
+            }
+        };
+
+        Eval::from_step(Step::Memo(memo))
+    }
+}
+
+impl<A> Drop for Eval<'_, A> {
+    fn drop(&mut self) {
+        let mut steps = Vec::from([mem::replace(self.step.get_mut(), Step::taken())]);
+
+        while let Some(step) = steps.pop() {
+            match step {
+                Step::FlatMap(step, continuation) => {
+                    drop(continuation);
+                    steps.push(*step);
+                }
+                Step::Memo(memo) => {
+                    if let Some(memo) = Rc::into_inner(memo)
+                        && let MemoState::Pending(step) = memo.state.into_inner()
+                    {
+                        steps.push(step);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Base monad for stack-safe recursion, the `TInner` layer lives inside the deferred computation.
+///
+/// [Monad] binds through `TInner`'s [Traversable] inside the thunk, so the continuation of every `TInner` element is deferred as well.
+/// `EvalT<IdHkt>` additionally binds values that cannot be cloned.
+pub struct EvalT<TInner = IdHkt>(Infallible, PhantomData<TInner>);
+
+impl<'t, TInner: Hkt<'t>> Hkt<'t> for EvalT<TInner> {
+    type F<'a, A: 'a>
+        = Eval<'a, TInner::F<'a, A>>
+    where
+        't: 'a;
+}
+
+impl<TInner> HktClassification for EvalT<TInner> {
+    type Choice = hkt_classification::OuterHkt;
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t>,
+    TInner: Functor<'t, ReqIn, ReqOut, ReqF1>,
+> Functor<'t, ReqIn, ReqOut, ReqF1> for EvalT<TInner>
+{
+    fn map<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> B,
+        F1Mut: 'a + FnMut(A) -> B,
+        F1Fn: 'a + Fn(A) -> B,
+        F1Clone: 'a + Fn(A) -> B + Clone,
+        F1Copy: 'a + Fn(A) -> B + Copy,
+        't: 'a,
+    {
+        fa.map(move |ta| TInner::map(clone_a, clone_b, f, ta))
+    }
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, TInner: Pure<'t, ReqIn>> Pure<'t, ReqIn> for EvalT<TInner> {
+    fn pure<'a, A>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        a: A,
+    ) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        TInner::pure(clone_a, a).pipe(Eval::now)
+    }
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t>,
+    TInner: Applicative<'t, ReqIn, ReqOut, ReqF1>,
+> Applicative<'t, ReqIn, ReqOut, ReqF1> for EvalT<TInner>
+{
+    fn apply<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        ff: Self::F<'a, <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> B,
+        F1Mut: 'a + FnMut(A) -> B,
+        F1Fn: 'a + Fn(A) -> B,
+        F1Clone: 'a + Fn(A) -> B + Clone,
+        F1Copy: 'a + Fn(A) -> B + Copy,
+        't: 'a,
+    {
+        ff.flat_map(move |tf| fa.map(move |ta| TInner::apply(clone_a, clone_b, tf, ta)))
+    }
+}
+
+impl<
+    't,
+    ReqF1: OneOf5Hkt<'t> + NotT5Of5<'t>,
+    TInner: Monad<'t, ConstBool<true>, ConstBool<true>, ReqF1>
+        + Traversable<'t, ConstBool<true>, ConstBool<true>, ReqF1>
+        + CloneK<'t, ConstBool<true>>,
+> Monad<'t, ConstBool<true>, ConstBool<true>, ReqF1> for EvalT<TInner>
+{
+    fn bind<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> A + Clone,
+        clone_b: impl 'a + Fn(&B) -> B + Clone,
+        fa: Self::F<'a, A>,
+        f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> Self::F<'a, B>,
+        F1Mut: 'a + FnMut(A) -> Self::F<'a, B>,
+        F1Fn: 'a + Fn(A) -> Self::F<'a, B>,
+        F1Clone: 'a + Fn(A) -> Self::F<'a, B> + Clone,
+        F1Copy: 'a + Fn(A) -> Self::F<'a, B> + Copy,
+        't: 'a,
+    {
+        let flatten = ReqF1::create_from(&f, identity);
+
+        fa.flat_map(move |ta| {
+            TInner::traverse::<_, _, EvalT, _, _, _, _, _>(
+                clone_a,
+                {
+                    let clone_b = clone_b.clone();
+                    move |tb| TInner::clone(clone_b.clone(), tb)
+                },
+                f,
+                ta,
+            )
+            .map(move |ttb| {
+                TInner::bind(
+                    {
+                        let clone_b = clone_b.clone();
+                        move |tb| TInner::clone(clone_b.clone(), tb)
+                    },
+                    clone_b,
+                    ttb,
+                    flatten,
+                )
+            })
+        })
+    }
+}
+
+impl<'t, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>> Monad<'t, ConstBool<false>, ReqOut, ReqF1>
+    for EvalT<IdHkt>
+{
+    fn bind<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        _clone_a: impl 'a + Fn(&A) -> AssertBlankOutput + Clone,
+        _clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        fa: Self::F<'a, A>,
+        f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> Self::F<'a, B>,
+        F1Mut: 'a + FnMut(A) -> Self::F<'a, B>,
+        F1Fn: 'a + Fn(A) -> Self::F<'a, B>,
+        F1Clone: 'a + Fn(A) -> Self::F<'a, B> + Clone,
+        F1Copy: 'a + Fn(A) -> Self::F<'a, B> + Copy,
+        't: 'a,
+    {
+        match_one_of_5!(ReqF1::into_one_of_5_enum(f), f, fa.flat_map(f))
+    }
+}
@@ -0,0 +1,334 @@
+use core::{cell::RefCell, convert::Infallible, marker::PhantomData, mem::ManuallyDrop};
+
+use alloc::{rc::Rc, vec::Vec};
+use either::Either;
+use tap::Pipe as _;
+
+use crate::{
+    hkt::{
+        CloneK, Functor, Hkt, PhantomMarker, TCloneableOf5,
+        hkt_classification::{self, HktClassification},
+        one_of::{OneOf5, OneOf5Hkt, T4Of5Hkt},
+    },
+    marker_classification::{AssertBlankOutput, ConstBool, TypeGuard},
+};
+
+/// Functors that recursion schemes can map over.
+///
+/// Nothing is cloned: the recursive step is passed as a [Clone] function sharing the algebra.
+pub trait PatternFunctor<'t>:
+    't + Functor<'t, ConstBool<false>, ConstBool<false>, T4Of5Hkt>
+{
+}
+
+impl<'t, F: 't + Functor<'t, ConstBool<false>, ConstBool<false>, T4Of5Hkt>> PatternFunctor<'t>
+    for F
+{
+}
+
+/// Least fixpoint of the pattern functor `F`, e.g. [ListF] for lists or [ExprF] for expression trees.
+///
+/// Layers are shared behind [Rc], so cloning a [Fix] never copies the structure.
+///
+/// All schemes recurse on the call stack, as deep as the structure they consume or produce.
+/// Dropping unlinks the uniquely owned layers iteratively, so long structures drop in constant stack space.
+pub struct Fix<'t, F: PatternFunctor<'t>>(ManuallyDrop<Rc<F::F<'t, Fix<'t, F>>>>);
+
+impl<'t, F: PatternFunctor<'t>> Fix<'t, F> {
+    pub fn new(layer: F::F<'t, Fix<'t, F>>) -> Self {
+        Self(ManuallyDrop::new(Rc::new(layer)))
+    }
+
+    fn into_rc(self) -> Rc<F::F<'t, Fix<'t, F>>> {
+        let mut this = ManuallyDrop::new(self);
+        // SAFETY: `this` is never dropped, so the `Rc` is taken exactly once.
+        unsafe { ManuallyDrop::take(&mut this.0) }
+    }
+
+    /// Copies the outermost layer only if it is shared, its children stay shared.
+    pub fn unfix(self) -> F::F<'t, Fix<'t, F>>
+    where
+        F: CloneK<'t, ConstBool<true>>,
+    {
+        Rc::try_unwrap(self.into_rc()).unwrap_or_else(|layer| F::clone(Fix::clone, &layer))
+    }
+
+    pub fn layer(&self) -> &F::F<'t, Fix<'t, F>> {
+        &self.0
+    }
+}
+
+impl<'t, F: PatternFunctor<'t>> Clone for Fix<'t, F> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<'t, F: PatternFunctor<'t>> Drop for Fix<'t, F> {
+    fn drop(&mut self) {
+        // SAFETY: `self.0` is not used after `drop`.
+        let mut layers = Vec::from([unsafe { ManuallyDrop::take(&mut self.0) }]);
+        let children = Rc::new(RefCell::new(Vec::new()));
+
+        while let Some(layer) = layers.pop() {
+            // Shared layers are only released, their last owner unlinks them.
+            if let Some(layer) = Rc::into_inner(layer) {
+                map_layer::<F, _, _>(
+                    {
+                        let children = children.clone();
+                        move |child: Fix<'t, F>| children.borrow_mut().push(child.into_rc())
+                    },
+                    layer,
+                );
+                layers.append(&mut children.borrow_mut());
+            }
+        }
+    }
+}
+
+fn map_layer<'t, F: PatternFunctor<'t>, A: 't, B: 't>(
+    f: impl 't + Fn(A) -> B + Clone,
+    fa: F::F<'t, A>,
+) -> F::F<'t, B> {
+    F::map::<A, B, fn(A) -> B, fn(A) -> B, fn(A) -> B, _, fn(A) -> B>(
+        |_| AssertBlankOutput,
+        |_| AssertBlankOutput,
+        f,
+        fa,
+    )
+}
+
+type Algebra<'t, F, A> = Rc<dyn 't + Fn(<F as Hkt<'t>>::F<'t, A>) -> A>;
+type Coalgebra<'t, F, S> = Rc<dyn 't + Fn(S) -> <F as Hkt<'t>>::F<'t, S>>;
+type RAlgebra<'t, F, A> = Rc<dyn 't + Fn(<F as Hkt<'t>>::F<'t, (Fix<'t, F>, A)>) -> A>;
+type RCoalgebra<'t, F, S> = Rc<dyn 't + Fn(S) -> <F as Hkt<'t>>::F<'t, Either<Fix<'t, F>, S>>>;
+
+fn cata_rc<'t, F: PatternFunctor<'t> + CloneK<'t, ConstBool<true>>, A: 't>(
+    alg: Algebra<'t, F, A>,
+    fix: Fix<'t, F>,
+) -> A {
+    let layer = map_layer::<F, _, _>(
+        {
+            let alg = alg.clone();
+            move |child| cata_rc(alg.clone(), child)
+        },
+        fix.unfix(),
+    );
+
+    alg(layer)
+}
+
+/// Folds bottom-up: `alg` receives each layer with its children already folded.
+pub fn cata<'t, F: PatternFunctor<'t> + CloneK<'t, ConstBool<true>>, A: 't>(
+    alg: impl 't + Fn(F::F<'t, A>) -> A,
+    fix: Fix<'t, F>,
+) -> A {
+    cata_rc(Rc::new(alg), fix)
+}
+
+fn ana_rc<'t, F: PatternFunctor<'t>, S: 't>(coalg: Coalgebra<'t, F, S>, seed: S) -> Fix<'t, F> {
+    let layer = coalg(seed);
+
+    map_layer::<F, _, _>(move |seed| ana_rc(coalg.clone(), seed), layer).pipe(Fix::new)
+}
+
+/// Unfolds top-down: `coalg` produces a layer whose seeds are unfolded in turn.
+pub fn ana<'t, F: PatternFunctor<'t>, S: 't>(
+    coalg: impl 't + Fn(S) -> F::F<'t, S>,
+    seed: S,
+) -> Fix<'t, F> {
+    ana_rc(Rc::new(coalg), seed)
+}
+
+fn hylo_rc<'t, F: PatternFunctor<'t>, S: 't, A: 't>(
+    alg: Algebra<'t, F, A>,
+    coalg: Coalgebra<'t, F, S>,
+    seed: S,
+) -> A {
+    let layer = map_layer::<F, _, _>(
+        {
+            let alg = alg.clone();
+            let coalg = coalg.clone();
+            move |seed| hylo_rc::<F, S, A>(alg.clone(), coalg.clone(), seed)
+        },
+        coalg(seed),
+    );
+
+    alg(layer)
+}
+
+/// [ana] followed by [cata] without building the intermediate [Fix].
+pub fn hylo<'t, F: PatternFunctor<'t>, S: 't, A: 't>(
+    alg: impl 't + Fn(F::F<'t, A>) -> A,
+    coalg: impl 't + Fn(S) -> F::F<'t, S>,
+    seed: S,
+) -> A {
+    hylo_rc::<F, S, A>(Rc::new(alg), Rc::new(coalg), seed)
+}
+
+fn para_rc<'t, F: PatternFunctor<'t> + CloneK<'t, ConstBool<true>>, A: 't>(
+    alg: RAlgebra<'t, F, A>,
+    fix: &Fix<'t, F>,
+) -> A {
+    let layer = map_layer::<F, _, _>(
+        {
+            let alg = alg.clone();
+            move |child: Fix<'t, F>| {
+                let a = para_rc(alg.clone(), &child);
+                (child, a)
+            }
+        },
+        F::clone(Fix::clone, fix.layer()),
+    );
+
+    alg(layer)
+}
+
+/// [cata] whose algebra also sees the original children, shared with the folded [Fix].
+pub fn para<'t, F: PatternFunctor<'t> + CloneK<'t, ConstBool<true>>, A: 't>(
+    alg: impl 't + Fn(F::F<'t, (Fix<'t, F>, A)>) -> A,
+    fix: Fix<'t, F>,
+) -> A {
+    para_rc(Rc::new(alg), &fix)
+}
+
+fn apo_rc<'t, F: PatternFunctor<'t>, S: 't>(coalg: RCoalgebra<'t, F, S>, seed: S) -> Fix<'t, F> {
+    let layer = coalg(seed);
+
+    map_layer::<F, _, _>(
+        move |child| match child {
+            Either::Left(fix) => fix,
+            Either::Right(seed) => apo_rc(coalg.clone(), seed),
+        },
+        layer,
+    )
+    .pipe(Fix::new)
+}
+
+/// [ana] whose coalgebra can end a branch early with [Either::Left] of a finished [Fix].
+pub fn apo<'t, F: PatternFunctor<'t>, S: 't>(
+    coalg: impl 't + Fn(S) -> F::F<'t, Either<Fix<'t, F>, S>>,
+    seed: S,
+) -> Fix<'t, F> {
+    apo_rc(Rc::new(coalg), seed)
+}
+
+/// Pattern functor of cons lists with elements `E`: [None] is the empty list.
+pub struct ListF<E>(Infallible, PhantomMarker<E>);
+
+impl<'t, E: 't> Hkt<'t> for ListF<E> {
+    type F<'a, A: 'a>
+        = Option<(E, A)>
+    where
+        't: 'a;
+}
+
+impl<E> HktClassification for ListF<E> {
+    type Choice = hkt_classification::OuterHkt;
+}
+
+impl<'t, E: 't, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>
+    Functor<'t, ReqIn, ReqOut, ReqF1> for ListF<E>
+{
+    fn map<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        _clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> B,
+        F1Mut: 'a + FnMut(A) -> B,
+        F1Fn: 'a + Fn(A) -> B,
+        F1Clone: 'a + Fn(A) -> B + Clone,
+        F1Copy: 'a + Fn(A) -> B + Copy,
+        't: 'a,
+    {
+        fa.map(|(e, a)| (e, match_one_of_5!(ReqF1::into_one_of_5_enum(f), f, f(a))))
+    }
+}
+
+impl<'t, E: 't + Clone> CloneK<'t, ConstBool<true>> for ListF<E> {
+    fn clone<'a, A>(clone_a: impl 'a + Fn(&A) -> A + Clone, a: &Self::F<'a, A>) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        a.as_ref().map(|(e, a)| (e.clone(), clone_a(a)))
+    }
+}
+
+/// One layer of an arithmetic expression tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Expr<R> {
+    Lit(i64),
+    Neg(R),
+    Add(R, R),
+    Mul(R, R),
+}
+
+/// Pattern functor of [Expr].
+pub struct ExprF(Infallible);
+
+impl<'t> Hkt<'t> for ExprF {
+    type F<'a, A: 'a>
+        = Expr<A>
+    where
+        't: 'a;
+}
+
+impl HktClassification for ExprF {
+    type Choice = hkt_classification::OuterHkt;
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: TCloneableOf5<'t>>
+    Functor<'t, ReqIn, ReqOut, ReqF1> for ExprF
+{
+    fn map<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        _clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> B,
+        F1Mut: 'a + FnMut(A) -> B,
+        F1Fn: 'a + Fn(A) -> B,
+        F1Clone: 'a + Fn(A) -> B + Clone,
+        F1Copy: 'a + Fn(A) -> B + Copy,
+        't: 'a,
+    {
+        let f = ReqF1::arbitrary_uncloneable(f, PhantomData::<fn(A) -> B>);
+        let f = match_one_of_5!(
+            ReqF1::into_one_of_5_enum(f),
+            f,
+            Rc::new(f) as Rc<dyn 'a + Fn(A) -> B>
+        );
+
+        match fa {
+            Expr::Lit(i) => Expr::Lit(i),
+            Expr::Neg(a) => Expr::Neg(f(a)),
+            Expr::Add(l, r) => Expr::Add(f(l), f(r)),
+            Expr::Mul(l, r) => Expr::Mul(f(l), f(r)),
+        }
+    }
+}
+
+impl<'t> CloneK<'t, ConstBool<true>> for ExprF {
+    fn clone<'a, A>(clone_a: impl 'a + Fn(&A) -> A + Clone, a: &Self::F<'a, A>) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        match a {
+            Expr::Lit(i) => Expr::Lit(*i),
+            Expr::Neg(a) => Expr::Neg(clone_a(a)),
+            Expr::Add(l, r) => Expr::Add(clone_a(l), clone_a(r)),
+            Expr::Mul(l, r) => Expr::Mul(clone_a(l), clone_a(r)),
+        }
+    }
+}
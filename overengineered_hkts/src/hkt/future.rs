@@ -1,44 +1,92 @@
-use core::{convert::Infallible, future::ready, marker::PhantomData, pin::Pin};
+use core::{convert::Infallible, future::ready, marker::PhantomData, num::NonZeroUsize, ops::ControlFlow, pin::Pin};
 
-use alloc::boxed::Box;
-use futures::FutureExt;
+use alloc::{boxed::Box, vec::{self, Vec}};
+use futures::{
+    FutureExt, StreamExt as _, TryStreamExt as _,
+    future::{join, join_all, lazy},
+    stream,
+};
 use tap::Pipe;
 
 use crate::{hkt::{
-    Applicative, CovariantK, Functor, Hkt, HktUnsized, Monad, Pure, hkt_classification::{self, HktClassification}, id::IdHkt, one_of::OneOf5Hkt
-}, marker_classification::TypeGuard};
+    Applicative, Converge as _, CovariantK, Foldable, Functor, Hkt, HktUnsized, Monad, Pure, Unfoldable, UnsizedHkt, UnsizedHktUnsized, boxed::BoxT, id::IdHkt, one_of::{NotT1Of5, OneOf5Hkt, T4Of5Hkt}, pin::PinT, result::ResultT
+}, marker_classification::{AssertBlankOutput, AutoTraitsK, ConstBool, IsSend, IsSync, TypeGuard, Wrapper, WrapperK}};
 
 use super::one_of::OneOf5;
 
-/// Will be changed to type alias for `PinT<BoxT<DynFutureT<TInner>>>`
-pub struct PinBoxFutureT<TInner = IdHkt>(Infallible, PhantomData<TInner>);
+pub struct DynFutureT<TInner = IdHkt>(Infallible, PhantomData<TInner>);
 
-impl<'t, TInner: Hkt<'t>> Hkt<'t> for PinBoxFutureT<TInner> {
-    type F<'a, A: 'a>
-        = Pin<Box<dyn 'a + Future<Output = TInner::F<'a, A>>>>
+impl<'t, TInner: Hkt<'t>> UnsizedHkt<'t> for DynFutureT<TInner> {
+    type UnsizedF<'a, A: 'a>
+        = dyn 'a + Future<Output = TInner::F<'a, A>>
     where
         't: 'a;
 }
 
-impl<'t, TInner: HktUnsized<'t>> HktUnsized<'t> for PinBoxFutureT<TInner> {
-    type FUnsized<'a, A: 'a + ?Sized>
-        = Pin<Box<dyn 'a + Future<Output = TInner::FUnsized<'a, A>>>>
+impl<'t, TInner: HktUnsized<'t>> UnsizedHktUnsized<'t> for DynFutureT<TInner> {
+    type UnsizedFUnsized<'a, A: 'a + ?Sized>
+        = dyn 'a + Future<Output = TInner::FUnsized<'a, A>>
     where
         't: 'a;
 }
 
-impl<TInner> HktClassification for PinBoxFutureT<TInner> {
-    type Choice = hkt_classification::OuterHkt;
+pub type PinBoxFutureT<TInner = IdHkt> = PinT<BoxT<DynFutureT<TInner>>>;
+
+/// Owning pointer layer over a `dyn Future` layer, such as `BoxT<DynFutureT<TInner>>`.
+///
+/// [PinT] over any such layer is a [Functor], [Pure] and [Applicative],
+/// and a [Monad] when the output is not wrapped by another hkt.
+pub trait FuturePointerK<'t>: Hkt<'t> {
+    /// Hkt wrapping the output of the future.
+    type Output: Hkt<'t>;
+
+    fn pin_future<'a, A>(
+        fut: impl 'a + Future<Output = <Self::Output as Hkt<'t>>::F<'a, A>>,
+    ) -> Pin<Self::F<'a, A>>
+    where
+        A: 'a,
+        't: 'a;
+
+    fn into_future<'a, A>(
+        fa: Pin<Self::F<'a, A>>,
+    ) -> impl 'a + Future<Output = <Self::Output as Hkt<'t>>::F<'a, A>>
+    where
+        A: 'a,
+        't: 'a;
+}
+
+impl<'t, TInner: Hkt<'t>> FuturePointerK<'t> for BoxT<DynFutureT<TInner>> {
+    type Output = TInner;
+
+    fn pin_future<'a, A>(
+        fut: impl 'a + Future<Output = TInner::F<'a, A>>,
+    ) -> Pin<Self::F<'a, A>>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        Box::pin(fut)
+    }
+
+    fn into_future<'a, A>(
+        fa: Pin<Self::F<'a, A>>,
+    ) -> impl 'a + Future<Output = TInner::F<'a, A>>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        fa
+    }
 }
 
-impl<'t, K: CovariantK<'t>> CovariantK<'t> for PinBoxFutureT<K> {
+impl<'t, P: FuturePointerK<'t, Output: CovariantK<'t>>> CovariantK<'t> for PinT<P> {
     fn covariant_convert<'a, 'b, A>(a: Self::F<'a, A>) -> Self::F<'b, A>
     where
         A: 'a,
         'a: 'b,
         't: 'a + 'b,
     {
-        Box::pin(a.map(|a| K::covariant_convert(a)))
+        P::pin_future(P::into_future(a).map(P::Output::covariant_convert))
     }
 }
 
@@ -47,8 +95,8 @@ impl<
     ReqIn: TypeGuard<'t>,
     ReqOut: TypeGuard<'t>,
     ReqF1: OneOf5Hkt<'t>,
-    TInner: Functor<'t, ReqIn, ReqOut, ReqF1>,
-> Functor<'t, ReqIn, ReqOut, ReqF1> for PinBoxFutureT<TInner>
+    P: FuturePointerK<'t, Output: Functor<'t, ReqIn, ReqOut, ReqF1>>,
+> Functor<'t, ReqIn, ReqOut, ReqF1> for PinT<P>
 {
     fn map<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
         clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
@@ -66,28 +114,37 @@ impl<
         F1Copy: 'a + Fn(A) -> B + Copy,
         't: 'a,
     {
-        Box::pin(fa.map(|a| TInner::map(clone_a, clone_b, f, a)))
+        P::pin_future(P::into_future(fa).map(|a| P::Output::map(clone_a, clone_b, f, a)))
     }
 }
 
-impl<'t, ReqIn: TypeGuard<'t>, TInner: Pure<'t, ReqIn>> Pure<'t, ReqIn> for PinBoxFutureT<TInner> {
-    fn pure<'a, A: 'a>(clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone, a: A) -> Self::F<'a, A>
+impl<'t, ReqIn: TypeGuard<'t>, P: FuturePointerK<'t, Output: Pure<'t, ReqIn>>> Pure<'t, ReqIn>
+    for PinT<P>
+{
+    fn pure<'a, A: 'a>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        a: A,
+    ) -> Self::F<'a, A>
     where
         't: 'a,
     {
-        a.pipe(|a| TInner::pure(clone_a, a))
+        a.pipe(|a| P::Output::pure(clone_a, a))
             .pipe(ready)
-            .pipe(Box::pin)
+            .pipe(P::pin_future)
     }
 }
 
+/// `ff` and `fa` are polled concurrently, so traversing e.g. [VecT](crate::hkt::vec::VecT) into this hkt runs all futures at once.
+///
+/// Such a traversal nests one `join` per element, which overflows the stack on long inputs.
+/// [traverse_all](PinT::traverse_all) and [sequence_all](PinT::sequence_all) poll a flat `join_all` instead.
 impl<
     't,
     ReqIn: TypeGuard<'t>,
     ReqOut: TypeGuard<'t>,
     ReqF1: OneOf5Hkt<'t>,
-    TInner: Applicative<'t, ReqIn, ReqOut, ReqF1>,
-> Applicative<'t, ReqIn, ReqOut, ReqF1> for PinBoxFutureT<TInner>
+    P: FuturePointerK<'t, Output: Applicative<'t, ReqIn, ReqOut, ReqF1>>,
+> Applicative<'t, ReqIn, ReqOut, ReqF1> for PinT<P>
 {
     fn apply<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
         clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
@@ -105,12 +162,9 @@ impl<
         F1Copy: 'a + Fn(A) -> B + Copy,
         't: 'a,
     {
-        Box::pin(
-            fa.map(async |a| {
-                TInner::apply(clone_a, clone_b, ff.await, a)
-            })
-            .flatten(),
-        )
+        join(P::into_future(ff), P::into_future(fa))
+            .map(move |(f, a)| P::Output::apply(clone_a, clone_b, f, a))
+            .pipe(P::pin_future)
     }
 }
 
@@ -154,8 +208,13 @@ impl<
 //     }
 // }
 
-impl<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>> Monad<'t, ReqIn, ReqOut, ReqF1>
-    for PinBoxFutureT<IdHkt>
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t>,
+    P: FuturePointerK<'t, Output = IdHkt>,
+> Monad<'t, ReqIn, ReqOut, ReqF1> for PinT<P>
 {
     fn bind<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
         _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
@@ -176,7 +235,482 @@ impl<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>> Mona
         match_one_of_5!(
             ReqF1::into_one_of_5_enum(f),
             f,
-            fa.map(f).flatten().pipe(Box::pin)
+            P::into_future(fa)
+                .then(move |a| P::into_future(f(a)))
+                .pipe(P::pin_future)
+        )
+    }
+}
+
+fn fold_into_vec<'t, 'a, ReqIn, ReqF1, TInner, A, T1, T2, T3, T4, T5>(
+    clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+    tag: &ReqF1::OneOf5F<'a, T1, T2, T3, T4, T5>,
+    fa: TInner::F<'a, A>,
+) -> Vec<A>
+where
+    ReqIn: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t>,
+    TInner: Foldable<'t, ReqIn, ConstBool<false>, ReqF1>,
+    A: 'a,
+    T1: 'a,
+    T2: 'a,
+    T3: 'a,
+    T4: 'a,
+    T5: 'a,
+    't: 'a,
+{
+    let push = ReqF1::create_from(tag, |mut items: Vec<A>, a| {
+        items.push(a);
+        ControlFlow::Continue(items)
+    });
+
+    TInner::fold_while(clone_a, |_| AssertBlankOutput, push, Vec::new(), fa).converge()
+}
+
+fn unfold_from_vec<'t, 'a, ReqIn: TypeGuard<'t>, TInner: Unfoldable<'t, ReqIn>, B: 'a>(
+    clone_b: impl 'a + Fn(&B) -> ReqIn::Output<'a, B> + Clone,
+    outputs: Vec<B>,
+) -> TInner::F<'a, B>
+where
+    't: 'a,
+{
+    TInner::unfold(
+        clone_b,
+        |mut iter: vec::IntoIter<B>| iter.next().map(|b| (b, iter)),
+        outputs.into_iter(),
+    )
+}
+
+impl<TInner> PinT<BoxT<DynFutureT<TInner>>> {
+    /// Collects the elements of `fa` then runs `f` on each of them with at most `limit` futures in flight.
+    ///
+    /// Outputs are rebuilt with [Unfoldable] in the original order, regardless of completion order.
+    pub fn traverse_concurrent<
+        't,
+        'a,
+        ReqIn: TypeGuard<'t>,
+        ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+        A,
+        B,
+        F1Once,
+        F1Mut,
+        F1Fn,
+        F1Clone,
+        F1Copy,
+    >(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqIn::Output<'a, B> + Clone,
+        limit: NonZeroUsize,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: TInner::F<'a, A>,
+    ) -> <Self as Hkt<'t>>::F<'a, B>
+    where
+        TInner: Foldable<'t, ReqIn, ConstBool<false>, ReqF1> + Unfoldable<'t, ReqIn>,
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> <PinBoxFutureT as Hkt<'t>>::F<'a, B>,
+        F1Mut: 'a + FnMut(A) -> <PinBoxFutureT as Hkt<'t>>::F<'a, B>,
+        F1Fn: 'a + Fn(A) -> <PinBoxFutureT as Hkt<'t>>::F<'a, B>,
+        F1Clone: 'a + Fn(A) -> <PinBoxFutureT as Hkt<'t>>::F<'a, B> + Clone,
+        F1Copy: 'a + Fn(A) -> <PinBoxFutureT as Hkt<'t>>::F<'a, B> + Copy,
+        't: 'a,
+    {
+        let items = fold_into_vec::<ReqIn, ReqF1, TInner, _, _, _, _, _, _>(clone_a, &f, fa);
+
+        let f = ReqF1::arbitrary_t1(
+            f,
+            PhantomData::<fn(A) -> <PinBoxFutureT as Hkt<'t>>::F<'a, B>>,
+        );
+
+        let outputs: <PinBoxFutureT as Hkt<'t>>::F<'a, Vec<B>> = match_one_of_5!(
+            ReqF1::into_one_of_5_enum(f),
+            f,
+            stream::iter(items.into_iter().map(f))
+                .buffered(limit.get())
+                .collect::<Vec<_>>()
+                .pipe(Box::pin)
+        );
+
+        outputs
+            .map(move |outputs| unfold_from_vec::<ReqIn, TInner, _>(clone_b, outputs))
+            .pipe(Box::pin)
+    }
+
+    /// Collects the elements of `fa` then runs `f` on all of them at once through [join_all].
+    ///
+    /// Unlike [Traversable::traverse](crate::hkt::Traversable::traverse) into this hkt,
+    /// the depth of the resulting future does not grow with the number of elements.
+    pub fn traverse_all<
+        't,
+        'a,
+        ReqIn: TypeGuard<'t>,
+        ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+        A,
+        B,
+        F1Once,
+        F1Mut,
+        F1Fn,
+        F1Clone,
+        F1Copy,
+    >(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqIn::Output<'a, B> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: TInner::F<'a, A>,
+    ) -> <Self as Hkt<'t>>::F<'a, B>
+    where
+        TInner: Foldable<'t, ReqIn, ConstBool<false>, ReqF1> + Unfoldable<'t, ReqIn>,
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> <PinBoxFutureT as Hkt<'t>>::F<'a, B>,
+        F1Mut: 'a + FnMut(A) -> <PinBoxFutureT as Hkt<'t>>::F<'a, B>,
+        F1Fn: 'a + Fn(A) -> <PinBoxFutureT as Hkt<'t>>::F<'a, B>,
+        F1Clone: 'a + Fn(A) -> <PinBoxFutureT as Hkt<'t>>::F<'a, B> + Clone,
+        F1Copy: 'a + Fn(A) -> <PinBoxFutureT as Hkt<'t>>::F<'a, B> + Copy,
+        't: 'a,
+    {
+        let items = fold_into_vec::<ReqIn, ReqF1, TInner, _, _, _, _, _, _>(clone_a, &f, fa);
+
+        let f = ReqF1::arbitrary_t1(
+            f,
+            PhantomData::<fn(A) -> <PinBoxFutureT as Hkt<'t>>::F<'a, B>>,
+        );
+
+        let outputs: <PinBoxFutureT as Hkt<'t>>::F<'a, Vec<B>> = match_one_of_5!(
+            ReqF1::into_one_of_5_enum(f),
+            f,
+            join_all(items.into_iter().map(f)).pipe(Box::pin)
+        );
+
+        outputs
+            .map(move |outputs| unfold_from_vec::<ReqIn, TInner, _>(clone_b, outputs))
+            .pipe(Box::pin)
+    }
+
+    /// [traverse_all](PinT::traverse_all) with the identity function.
+    pub fn sequence_all<'t, 'a, ReqIn: TypeGuard<'t>, A>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        fa: TInner::F<'a, <PinBoxFutureT as Hkt<'t>>::F<'a, A>>,
+    ) -> <Self as Hkt<'t>>::F<'a, A>
+    where
+        TInner: Foldable<'t, ConstBool<false>, ConstBool<false>, T4Of5Hkt> + Unfoldable<'t, ReqIn>,
+        A: 'a,
+        't: 'a,
+    {
+        type Fut<'t, 'a, A> = <PinBoxFutureT as Hkt<'t>>::F<'a, A>;
+        type Push<'t, 'a, A> =
+            fn(Vec<Fut<'t, 'a, A>>, Fut<'t, 'a, A>) -> ControlFlow<Vec<Fut<'t, 'a, A>>, Vec<Fut<'t, 'a, A>>>;
+
+        let items = TInner::fold_while::<
+            _,
+            _,
+            Push<'t, 'a, A>,
+            Push<'t, 'a, A>,
+            Push<'t, 'a, A>,
+            _,
+            Push<'t, 'a, A>,
+        >(
+            |_| AssertBlankOutput,
+            |_| AssertBlankOutput,
+            |mut items, fut| {
+                items.push(fut);
+                ControlFlow::Continue(items)
+            },
+            Vec::new(),
+            fa,
         )
+        .converge();
+
+        join_all(items)
+            .map(move |outputs| unfold_from_vec::<ReqIn, TInner, _>(clone_a, outputs))
+            .pipe(Box::pin)
+    }
+}
+
+impl<E, TInner> PinT<BoxT<DynFutureT<ResultT<E, TInner>>>> {
+    /// Same as [traverse_concurrent](PinT::traverse_concurrent), but stops polling and outputs the first [Err] in the original order.
+    pub fn try_traverse_concurrent<
+        't,
+        'a,
+        ReqIn: TypeGuard<'t>,
+        ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+        A,
+        B,
+        F1Once,
+        F1Mut,
+        F1Fn,
+        F1Clone,
+        F1Copy,
+    >(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqIn::Output<'a, B> + Clone,
+        limit: NonZeroUsize,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: TInner::F<'a, A>,
+    ) -> <Self as Hkt<'t>>::F<'a, B>
+    where
+        TInner: Foldable<'t, ReqIn, ConstBool<false>, ReqF1> + Unfoldable<'t, ReqIn>,
+        E: 't,
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> <PinBoxFutureT<ResultT<E, IdHkt>> as Hkt<'t>>::F<'a, B>,
+        F1Mut: 'a + FnMut(A) -> <PinBoxFutureT<ResultT<E, IdHkt>> as Hkt<'t>>::F<'a, B>,
+        F1Fn: 'a + Fn(A) -> <PinBoxFutureT<ResultT<E, IdHkt>> as Hkt<'t>>::F<'a, B>,
+        F1Clone: 'a + Fn(A) -> <PinBoxFutureT<ResultT<E, IdHkt>> as Hkt<'t>>::F<'a, B> + Clone,
+        F1Copy: 'a + Fn(A) -> <PinBoxFutureT<ResultT<E, IdHkt>> as Hkt<'t>>::F<'a, B> + Copy,
+        't: 'a,
+    {
+        let items = fold_into_vec::<ReqIn, ReqF1, TInner, _, _, _, _, _, _>(clone_a, &f, fa);
+
+        let f = ReqF1::arbitrary_t1(
+            f,
+            PhantomData::<fn(A) -> <PinBoxFutureT<ResultT<E, IdHkt>> as Hkt<'t>>::F<'a, B>>,
+        );
+
+        let outputs: <PinBoxFutureT<ResultT<E, IdHkt>> as Hkt<'t>>::F<'a, Vec<B>> = match_one_of_5!(
+            ReqF1::into_one_of_5_enum(f),
+            f,
+            stream::iter(items.into_iter().map(f))
+                .buffered(limit.get())
+                .try_collect::<Vec<_>>()
+                .pipe(Box::pin)
+        );
+
+        outputs
+            .map(move |outputs| {
+                outputs.map(|outputs| unfold_from_vec::<ReqIn, TInner, _>(clone_b, outputs))
+            })
+            .pipe(Box::pin)
     }
 }
+
+/// Generates a `dyn Future` layer with extra auto trait bounds.
+///
+/// The instances require closures and values to be [Send]/[Sync] through the `ReqSend`/`ReqSync`
+/// parameters of the traits, which are set to `$req_send`/`$req_sync`.
+/// [Applicative] and [Monad] are only given for [IdHkt] outputs, since an arbitrary `TInner` may not be [Send].
+/// Use `into_local` to reach the remaining instances of [PinBoxFutureT].
+macro_rules! impl_bounded_dyn_future {
+    ($hkt:ident, $alias:ident, [$req_send:ty, $req_sync:ty], $($bound:path),+) => {
+        pub struct $hkt<TInner = IdHkt>(Infallible, PhantomData<TInner>);
+
+        impl<'t, TInner: Hkt<'t>> UnsizedHkt<'t> for $hkt<TInner> {
+            type UnsizedF<'a, A: 'a>
+                = dyn 'a + Future<Output = TInner::F<'a, A>> $(+ $bound)+
+            where
+                't: 'a;
+        }
+
+        impl<'t, TInner: HktUnsized<'t>> UnsizedHktUnsized<'t> for $hkt<TInner> {
+            type UnsizedFUnsized<'a, A: 'a + ?Sized>
+                = dyn 'a + Future<Output = TInner::FUnsized<'a, A>> $(+ $bound)+
+            where
+                't: 'a;
+        }
+
+        pub type $alias<TInner = IdHkt> = PinT<BoxT<$hkt<TInner>>>;
+
+        impl<'t, K: CovariantK<'t>> CovariantK<'t> for PinT<BoxT<$hkt<K>>> {
+            fn covariant_convert<'a, 'b, A>(a: Self::F<'a, A>) -> Self::F<'b, A>
+            where
+                A: 'a,
+                'a: 'b,
+                't: 'a + 'b,
+            {
+                Box::pin(a.map(K::covariant_convert))
+            }
+        }
+
+        impl<TInner> PinT<BoxT<$hkt<TInner>>> {
+            /// Forgets the auto trait bounds.
+            pub fn into_local<'t, 'a, A>(
+                fa: <Self as Hkt<'t>>::F<'a, A>,
+            ) -> <PinBoxFutureT<TInner> as Hkt<'t>>::F<'a, A>
+            where
+                TInner: Hkt<'t>,
+                A: 'a,
+                't: 'a,
+            {
+                fa
+            }
+        }
+
+        impl<
+            't,
+            ReqIn: TypeGuard<'t>,
+            ReqOut: TypeGuard<'t>,
+            ReqF1: OneOf5Hkt<'t>,
+            TInner: Functor<'t, ReqIn, ReqOut, ReqF1>,
+        > Functor<'t, ReqIn, ReqOut, ReqF1, $req_send, $req_sync> for PinT<BoxT<$hkt<TInner>>>
+        {
+            fn map<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                clone_a: impl 'a
+                    + Fn(&A) -> ReqIn::Output<'a, A>
+                    + Clone
+                    + IsSend<$req_send>
+                    + IsSync<$req_sync>,
+                clone_b: impl 'a
+                    + Fn(&B) -> ReqOut::Output<'a, B>
+                    + Clone
+                    + IsSend<$req_send>
+                    + IsSync<$req_sync>,
+                f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+                fa: Self::F<'a, A>,
+            ) -> Self::F<'a, B>
+            where
+                A: 'a,
+                B: 'a,
+                F1Once: 'a + FnOnce(A) -> B,
+                F1Mut: 'a + FnMut(A) -> B,
+                F1Fn: 'a + Fn(A) -> B,
+                F1Clone: 'a + Fn(A) -> B + Clone,
+                F1Copy: 'a + Fn(A) -> B + Copy,
+                ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>:
+                    IsSend<$req_send> + IsSync<$req_sync>,
+                't: 'a,
+            {
+                let captured = (Wrapper::new(clone_a), Wrapper::new(clone_b), Wrapper::new(f));
+
+                Box::pin(fa.map(move |a| {
+                    let (clone_a, clone_b, f) = captured;
+
+                    TInner::map(clone_a.into_inner(), clone_b.into_inner(), f.into_inner(), a)
+                }))
+            }
+        }
+
+        impl<'t, ReqIn: TypeGuard<'t>, TInner: Pure<'t, ReqIn>>
+            Pure<'t, ReqIn, $req_send, $req_sync> for PinT<BoxT<$hkt<TInner>>>
+        {
+            fn pure<'a, A>(
+                clone_a: impl 'a
+                    + Fn(&A) -> ReqIn::Output<'a, A>
+                    + Clone
+                    + IsSend<$req_send>
+                    + IsSync<$req_sync>,
+                a: A,
+            ) -> Self::F<'a, A>
+            where
+                A: 'a + IsSend<$req_send> + IsSync<$req_sync>,
+                't: 'a,
+            {
+                let captured = (Wrapper::new(clone_a), Wrapper::new(a));
+
+                // Built on first poll, so only the captured values need the auto traits.
+                Box::pin(lazy(move |_| {
+                    let (clone_a, a) = captured;
+
+                    TInner::pure(clone_a.into_inner(), a.into_inner())
+                }))
+            }
+        }
+
+        /// `TInner` values are held across the [join], so [AutoTraitsK] proves them [Send]/[Sync].
+        impl<
+            't,
+            ReqIn: TypeGuard<'t>,
+            ReqOut: TypeGuard<'t>,
+            ReqF1: OneOf5Hkt<'t>,
+            TInner: Applicative<'t, ReqIn, ReqOut, ReqF1> + AutoTraitsK<'t>,
+        > Applicative<'t, ReqIn, ReqOut, ReqF1, $req_send, $req_sync> for PinT<BoxT<$hkt<TInner>>>
+        {
+            fn apply<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                clone_a: impl 'a
+                    + Fn(&A) -> ReqIn::Output<'a, A>
+                    + Clone
+                    + IsSend<$req_send>
+                    + IsSync<$req_sync>,
+                clone_b: impl 'a
+                    + Fn(&B) -> ReqOut::Output<'a, B>
+                    + Clone
+                    + IsSend<$req_send>
+                    + IsSync<$req_sync>,
+                ff: Self::F<'a, ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>>,
+                fa: Self::F<'a, A>,
+            ) -> Self::F<'a, B>
+            where
+                A: 'a + IsSend<$req_send> + IsSync<$req_sync>,
+                B: 'a,
+                F1Once: 'a + FnOnce(A) -> B,
+                F1Mut: 'a + FnMut(A) -> B,
+                F1Fn: 'a + Fn(A) -> B,
+                F1Clone: 'a + Fn(A) -> B + Clone,
+                F1Copy: 'a + Fn(A) -> B + Copy,
+                ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>:
+                    IsSend<$req_send> + IsSync<$req_sync>,
+                't: 'a,
+            {
+                let captured = (Wrapper::new(clone_a), Wrapper::new(clone_b));
+
+                // Whichever operand finishes first is held until the other does.
+                join(
+                    ff.map(WrapperK::<TInner, _>::new),
+                    fa.map(WrapperK::<TInner, _>::new),
+                )
+                .map(move |(tf, ta)| {
+                    let (clone_a, clone_b) = captured;
+
+                    TInner::apply(
+                        clone_a.into_inner(),
+                        clone_b.into_inner(),
+                        tf.into_inner(),
+                        ta.into_inner(),
+                    )
+                })
+                .pipe(Box::pin)
+            }
+        }
+
+        impl<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>
+            Monad<'t, ReqIn, ReqOut, ReqF1, $req_send, $req_sync> for PinT<BoxT<$hkt<IdHkt>>>
+        {
+            fn bind<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                _clone_a: impl 'a
+                    + Fn(&A) -> ReqIn::Output<'a, A>
+                    + Clone
+                    + IsSend<$req_send>
+                    + IsSync<$req_sync>,
+                _clone_b: impl 'a
+                    + Fn(&B) -> ReqOut::Output<'a, B>
+                    + Clone
+                    + IsSend<$req_send>
+                    + IsSync<$req_sync>,
+                fa: Self::F<'a, A>,
+                f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+            ) -> Self::F<'a, B>
+            where
+                A: 'a,
+                B: 'a,
+                F1Once: 'a + FnOnce(A) -> Self::F<'a, B>,
+                F1Mut: 'a + FnMut(A) -> Self::F<'a, B>,
+                F1Fn: 'a + Fn(A) -> Self::F<'a, B>,
+                F1Clone: 'a + Fn(A) -> Self::F<'a, B> + Clone,
+                F1Copy: 'a + Fn(A) -> Self::F<'a, B> + Copy,
+                ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>:
+                    IsSend<$req_send> + IsSync<$req_sync>,
+                't: 'a,
+            {
+                let f = Wrapper::new(f);
+
+                fa.then(move |a| {
+                    match_one_of_5!(ReqF1::into_one_of_5_enum(f.into_inner()), f, f(a))
+                })
+                .pipe(Box::pin)
+            }
+        }
+    };
+}
+
+impl_bounded_dyn_future!(
+    DynSendFutureT,
+    PinBoxSendFutureT,
+    [ConstBool<true>, ConstBool<false>],
+    Send
+);
+impl_bounded_dyn_future!(
+    DynSendSyncFutureT,
+    PinBoxSendSyncFutureT,
+    [ConstBool<true>, ConstBool<true>],
+    Send,
+    Sync
+);
@@ -4,18 +4,28 @@ use core::convert::Infallible;
 use crate::hkt::CloneOwnedK;
 use crate::hkt::CovariantK;
 use crate::hkt::CovariantRefK;
+use crate::hkt::DerefHkt;
+use crate::hkt::DerefMutHkt;
+use crate::hkt::MapInPlace;
 use crate::hkt::Rfoldable;
 use crate::hkt::UnsizedHkt;
 use crate::hkt::UnsizedHktUnsized;
+use crate::marker_classification::AutoTraitsK;
 
 use crate::hkt::FoldWhile;
 use crate::hkt::hkt_classification;
 use crate::hkt::hkt_classification::HktClassification;
 use crate::hkt::one_of::OneOf5;
 use crate::hkt::one_of::OneOf5Hkt;
+use crate::hkt::one_of::T4Of5Hkt;
 use crate::hkt::reference::RefMutT;
 use crate::hkt::reference::RefT;
-use crate::hkt::{Applicative, CloneK, Foldable, Functor, Hkt, Monad, Pure, Traversable};
+use crate::hkt::these::These;
+use crate::hkt::{
+    Align, Applicative, CloneK, Comonad, Distributive, Foldable, FoldableWithIndex, Functor,
+    FunctorWithIndex, Hkt, Monad, Pure, Representable, Traversable, TraversableWithIndex, WithIndex,
+    Zip,
+};
 use crate::marker_classification::ConstBool;
 use crate::marker_classification::TypeGuard;
 
@@ -61,10 +71,31 @@ impl<'t> UnsizedHktUnsized<'t> for IdHkt {
         't: 'a;
 }
 
+impl<'t> DerefHkt<'t> for IdHkt {
+    fn deref<'a, 'b: 'a, A: 'a + ?Sized>(fa: &'a A) -> &'a A
+    where
+        't: 'b + 'a,
+    {
+        fa
+    }
+}
+
+impl<'t> DerefMutHkt<'t> for IdHkt {
+    fn deref_mut<'a, 'b: 'a, A: 'a + ?Sized>(fa: &'a mut A) -> &'a mut A
+    where
+        't: 'b + 'a,
+    {
+        fa
+    }
+}
+
 impl HktClassification for IdHkt {
     type Choice = hkt_classification::TransparentHkt;
 }
 
+// SAFETY: `F<'a, A>` is `A`.
+unsafe impl<'t> AutoTraitsK<'t> for IdHkt {}
+
 impl<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>
     Functor<'t, ReqIn, ReqOut, ReqF1> for IdHkt
 {
@@ -121,6 +152,13 @@ impl<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>
     {
         match_one_of_5!(ReqF1::into_one_of_5_enum(f), f, f(init, fb))
     }
+
+    fn size_hint<'a, A>(_s: &A) -> (usize, Option<usize>)
+    where
+        't: 'a,
+    {
+        (1, Some(1))
+    }
 }
 
 impl<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>
@@ -305,6 +343,37 @@ impl<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>
     }
 }
 
+impl<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>
+    Comonad<'t, ReqIn, ReqOut, ReqF1> for IdHkt
+{
+    fn extract<'a, A>(fa: Self::F<'a, A>) -> A
+    where
+        A: 'a,
+        't: 'a,
+    {
+        fa
+    }
+
+    fn extend<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        _clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        fa: Self::F<'a, A>,
+        f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(Self::F<'a, A>) -> B,
+        F1Mut: 'a + FnMut(Self::F<'a, A>) -> B,
+        F1Fn: 'a + Fn(Self::F<'a, A>) -> B,
+        F1Clone: 'a + Fn(Self::F<'a, A>) -> B + Clone,
+        F1Copy: 'a + Fn(Self::F<'a, A>) -> B + Copy,
+        't: 'a,
+    {
+        match_one_of_5!(ReqF1::into_one_of_5_enum(f), f, f(fa))
+    }
+}
+
 impl<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>
     Traversable<'t, ReqIn, ReqOut, ReqF1> for IdHkt
 {
@@ -329,6 +398,158 @@ impl<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>
     }
 }
 
+impl<'t> WithIndex<'t> for IdHkt {
+    type Index = ();
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, G: Functor<'t, ConstBool<false>, ConstBool<false>, T4Of5Hkt>>
+    Distributive<'t, ReqIn, G> for IdHkt
+{
+    fn distribute<'a, A>(
+        _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        gfa: G::F<'a, Self::F<'a, A>>,
+    ) -> Self::F<'a, G::F<'a, A>>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        gfa
+    }
+}
+
+impl<'t> Representable<'t> for IdHkt {
+    fn tabulate<'a, A>(f: impl 'a + Fn(Self::Index) -> A + Clone) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        f(())
+    }
+
+    fn index<'a, A>(fa: Self::F<'a, A>, _index: Self::Index) -> A
+    where
+        A: 'a,
+        't: 'a,
+    {
+        fa
+    }
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>
+    FunctorWithIndex<'t, ReqIn, ReqOut, ReqF1> for IdHkt
+{
+    fn map_with_index<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        _clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce((), A) -> B,
+        F1Mut: 'a + FnMut((), A) -> B,
+        F1Fn: 'a + Fn((), A) -> B,
+        F1Clone: 'a + Fn((), A) -> B + Clone,
+        F1Copy: 'a + Fn((), A) -> B + Copy,
+        't: 'a,
+    {
+        match_one_of_5!(ReqF1::into_one_of_5_enum(f), f, f((), fa))
+    }
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>
+    FoldableWithIndex<'t, ReqIn, ReqOut, ReqF1> for IdHkt
+{
+    fn fold_while_with_index<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        _clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        _clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+        f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: Self::F<'a, A>,
+    ) -> FoldWhile<B>
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, (), A) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, (), A) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, (), A) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, (), A) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, (), A) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        match_one_of_5!(ReqF1::into_one_of_5_enum(f), f, f(init, (), fa))
+    }
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>
+    TraversableWithIndex<'t, ReqIn, ReqOut, ReqF1> for IdHkt
+{
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>
+    Zip<'t, ReqIn, ReqOut, ReqF1> for IdHkt
+{
+    fn zip_with<'a, A, B, C, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        _clone_b: impl 'a + Fn(&B) -> ReqIn::Output<'a, B> + Clone,
+        _clone_c: impl 'a + Fn(&C) -> ReqOut::Output<'a, C> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+        fb: Self::F<'a, B>,
+    ) -> Self::F<'a, C>
+    where
+        A: 'a,
+        B: 'a,
+        C: 'a,
+        F1Once: 'a + FnOnce(A, B) -> C,
+        F1Mut: 'a + FnMut(A, B) -> C,
+        F1Fn: 'a + Fn(A, B) -> C,
+        F1Clone: 'a + Fn(A, B) -> C + Clone,
+        F1Copy: 'a + Fn(A, B) -> C + Copy,
+        't: 'a,
+    {
+        match_one_of_5!(ReqF1::into_one_of_5_enum(f), f, f(fa, fb))
+    }
+
+    fn unzip<'a, A, B>(fab: Self::F<'a, (A, B)>) -> (Self::F<'a, A>, Self::F<'a, B>)
+    where
+        A: 'a,
+        B: 'a,
+        't: 'a,
+    {
+        fab
+    }
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>
+    Align<'t, ReqIn, ReqOut, ReqF1> for IdHkt
+{
+    fn align_with<'a, A, B, C, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        _clone_b: impl 'a + Fn(&B) -> ReqIn::Output<'a, B> + Clone,
+        _clone_c: impl 'a + Fn(&C) -> ReqOut::Output<'a, C> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+        fb: Self::F<'a, B>,
+    ) -> Self::F<'a, C>
+    where
+        A: 'a,
+        B: 'a,
+        C: 'a,
+        F1Once: 'a + FnOnce(These<A, B>) -> C,
+        F1Mut: 'a + FnMut(These<A, B>) -> C,
+        F1Fn: 'a + Fn(These<A, B>) -> C,
+        F1Clone: 'a + Fn(These<A, B>) -> C + Clone,
+        F1Copy: 'a + Fn(These<A, B>) -> C + Copy,
+        't: 'a,
+    {
+        match_one_of_5!(ReqF1::into_one_of_5_enum(f), f, f(These::Both(fa, fb)))
+    }
+}
+
 impl<'t> CloneOwnedK<'t, ConstBool<true>> for IdHkt {
     fn clone_owned<'a, 'b, A>(
         clone_a: impl Fn(&A) -> <ConstBool<true> as TypeGuard<'t>>::Output<'a, A> + Clone,
@@ -355,6 +576,24 @@ impl<'t> CloneK<'t, ConstBool<true>> for IdHkt {
     }
 }
 
+impl<'t, ReqF1: OneOf5Hkt<'t>> MapInPlace<'t, ReqF1> for IdHkt {
+    fn map_in_place<'a, 'f, A, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: &'a mut A,
+    ) where
+        A: 'a,
+        F1Once: 'f + FnOnce(&'a mut A),
+        F1Mut: 'f + FnMut(&'a mut A),
+        F1Fn: 'f + Fn(&'a mut A),
+        F1Clone: 'f + Fn(&'a mut A) + Clone,
+        F1Copy: 'f + Fn(&'a mut A) + Copy,
+        'a: 'f,
+        't: 'a,
+    {
+        match_one_of_5!(ReqF1::into_one_of_5_enum(f), f, f(fa))
+    }
+}
+
 impl<'t> CovariantK<'t> for IdHkt {
     fn covariant_convert<'a, 'b, A>(a: Self::F<'a, A>) -> Self::F<'b, A>
     where
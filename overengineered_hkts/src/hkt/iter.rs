@@ -8,8 +8,8 @@ use tap::Pipe;
 
 use crate::{
     hkt::{
-        Applicative, CloneK, Converge, CovariantK, FoldWhile, Foldable, Functor, Hkt, HktUnsized, Monad, Pure, TCloneableOf5, Traversable, UnsizedHkt, UnsizedHktUnsized, boxed::BoxT, id::IdHkt, one_of::NotT5Of5
-    }, marker_classification::{AssertBlankOutput, ConstBool, TypeGuard}, transmute::unsafe_transmute_id, utils::CloneWrapper
+        Align, Applicative, CloneK, Converge, CovariantK, FoldWhile, Foldable, FoldableWithIndex, Functor, FunctorWithIndex, Hkt, HktUnsized, Monad, Pure, TCloneableOf5, Traversable, TraversableWithIndex, Unfoldable, UnsizedHkt, UnsizedHktUnsized, WithIndex, Zip, boxed::BoxT, id::IdHkt, one_of::{NotT1Of5, NotT5Of5, OneOf5}, these::{These, align_iter}
+    }, marker_classification::{AssertBlankOutput, ConstBool, IsSend, IsSync, TypeGuard, Wrapper}, transmute::unsafe_transmute_id, utils::CloneWrapper
 };
 
 use super::one_of::OneOf5Hkt;
@@ -581,6 +581,212 @@ impl<
     }
 }
 
+/// Lazily unfolds, `step` only runs when the iterator is advanced.
+impl<'t, ReqIn: TypeGuard<'t>> Unfoldable<'t, ReqIn> for BoxT<DynIteratorT> {
+    fn unfold<'a, A, S>(
+        _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        mut step: impl 'a + FnMut(S) -> Option<(A, S)>,
+        seed: S,
+    ) -> Self::F<'a, A>
+    where
+        A: 'a,
+        S: 'a,
+        't: 'a,
+    {
+        let mut seed = Some(seed);
+
+        core::iter::from_fn(move || {
+            let (a, next) = step(seed.take()?)?;
+            seed = Some(next);
+            Some(a)
+        })
+        .pipe(Box::new)
+    }
+}
+
+impl<'t> WithIndex<'t> for BoxT<DynIteratorT> {
+    type Index = usize;
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>>
+    FunctorWithIndex<'t, ReqIn, ReqOut, ReqF1> for BoxT<DynIteratorT>
+{
+    fn map_with_index<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        _clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(usize, A) -> B,
+        F1Mut: 'a + FnMut(usize, A) -> B,
+        F1Fn: 'a + Fn(usize, A) -> B,
+        F1Clone: 'a + Fn(usize, A) -> B + Clone,
+        F1Copy: 'a + Fn(usize, A) -> B + Copy,
+        't: 'a,
+    {
+        let f = ReqF1::arbitrary_t1(f, PhantomData::<fn(usize, A) -> B>);
+
+        match_one_of_5!(
+            ReqF1::into_one_of_5_enum(f),
+            f,
+            Box::new(fa.enumerate().map(move |(i, a)| f(i, a))) as Self::F<'a, B>
+        )
+    }
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>>
+    FoldableWithIndex<'t, ReqIn, ReqOut, ReqF1> for BoxT<DynIteratorT>
+{
+    fn fold_while_with_index<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        _clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        _clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+        f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: Self::F<'a, A>,
+    ) -> FoldWhile<B>
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, usize, A) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, usize, A) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, usize, A) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, usize, A) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, usize, A) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        let f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, usize, A) -> FoldWhile<B>>);
+
+        match_one_of_5!(
+            ReqF1::into_one_of_5_enum(f),
+            f,
+            fa.enumerate().try_fold(init, |b, (i, a)| f(b, i, a))
+        )
+    }
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>
+    TraversableWithIndex<'t, ReqIn, ReqOut, ReqF1> for BoxT<DynIteratorT>
+{
+}
+
+/// Lazily pairs elements positionally and stops at the shorter iterator.
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: TCloneableOf5<'t>,
+    TInner: Zip<'t, ReqIn, ReqOut, ReqF1>,
+> Zip<'t, ReqIn, ReqOut, ReqF1> for BoxT<DynIteratorT<TInner>>
+{
+    fn zip_with<'a, A, B, C, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqIn::Output<'a, B> + Clone,
+        clone_c: impl 'a + Fn(&C) -> ReqOut::Output<'a, C> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+        fb: Self::F<'a, B>,
+    ) -> Self::F<'a, C>
+    where
+        A: 'a,
+        B: 'a,
+        C: 'a,
+        F1Once: 'a + FnOnce(A, B) -> C,
+        F1Mut: 'a + FnMut(A, B) -> C,
+        F1Fn: 'a + Fn(A, B) -> C,
+        F1Clone: 'a + Fn(A, B) -> C + Clone,
+        F1Copy: 'a + Fn(A, B) -> C + Copy,
+        't: 'a,
+    {
+        let f = ReqF1::arbitrary_uncloneable(f, PhantomData::<fn(A, B) -> C>);
+
+        fa.zip(fb)
+            .map(move |(ta, tb)| {
+                TInner::zip_with(
+                    clone_a.clone(),
+                    clone_b.clone(),
+                    clone_c.clone(),
+                    ReqF1::clone_one_of_5(&f),
+                    ta,
+                    tb,
+                )
+            })
+            .pipe(Box::new)
+    }
+
+    fn unzip<'a, A, B>(fab: Self::F<'a, (A, B)>) -> (Self::F<'a, A>, Self::F<'a, B>)
+    where
+        A: 'a,
+        B: 'a,
+        't: 'a,
+    {
+        // Both halves cannot be driven lazily from one source without buffering anyway.
+        let (va, vb): (Vec<_>, Vec<_>) = fab.map(TInner::unzip).unzip();
+
+        (Box::new(va.into_iter()), Box::new(vb.into_iter()))
+    }
+}
+
+/// Lazily pairs elements positionally, the tail of the longer iterator is kept as [These::This] or [These::That].
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: TCloneableOf5<'t>,
+    TInner: Align<'t, ReqIn, ReqOut, ReqF1> + Functor<'t, ReqIn, ReqOut, ReqF1>,
+> Align<'t, ReqIn, ReqOut, ReqF1> for BoxT<DynIteratorT<TInner>>
+{
+    fn align_with<'a, A, B, C, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqIn::Output<'a, B> + Clone,
+        clone_c: impl 'a + Fn(&C) -> ReqOut::Output<'a, C> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+        fb: Self::F<'a, B>,
+    ) -> Self::F<'a, C>
+    where
+        A: 'a,
+        B: 'a,
+        C: 'a,
+        F1Once: 'a + FnOnce(These<A, B>) -> C,
+        F1Mut: 'a + FnMut(These<A, B>) -> C,
+        F1Fn: 'a + Fn(These<A, B>) -> C,
+        F1Clone: 'a + Fn(These<A, B>) -> C + Clone,
+        F1Copy: 'a + Fn(These<A, B>) -> C + Copy,
+        't: 'a,
+    {
+        let f = ReqF1::arbitrary_uncloneable(f, PhantomData::<fn(These<A, B>) -> C>);
+
+        align_iter(fa, fb)
+            .map(move |these| {
+                let f = ReqF1::clone_one_of_5(&f);
+
+                match these {
+                    These::Both(ta, tb) => {
+                        TInner::align_with(clone_a.clone(), clone_b.clone(), clone_c.clone(), f, ta, tb)
+                    }
+                    These::This(ta) => TInner::map(
+                        clone_a.clone(),
+                        clone_c.clone(),
+                        map_one_of_5_with!(ReqF1, f, |f| move |a: A| f(These::This(a))),
+                        ta,
+                    ),
+                    These::That(tb) => TInner::map(
+                        clone_b.clone(),
+                        clone_c.clone(),
+                        map_one_of_5_with!(ReqF1, f, |f| move |b: B| f(These::That(b))),
+                        tb,
+                    ),
+                }
+            })
+            .pipe(Box::new)
+    }
+}
+
 impl<'t, TInner: CovariantK<'t>> CovariantK<'t> for BoxT<DynIteratorT<TInner>> {
     fn covariant_convert<'a, 'b, A>(a: Self::F<'a, A>) -> Self::F<'b, A>
     where
@@ -591,3 +797,162 @@ impl<'t, TInner: CovariantK<'t>> CovariantK<'t> for BoxT<DynIteratorT<TInner>> {
         a.map(TInner::covariant_convert).pipe(Box::new)
     }
 }
+
+/// Generates a `dyn Iterator` layer with extra auto trait bounds.
+///
+/// [Functor] requires the closures to be [Send]/[Sync] through its `ReqSend`/`ReqSync` parameters,
+/// which are set to `$req_send`/`$req_sync`. [Foldable] and [CovariantK] capture no closure.
+/// `into_local` gives access to the remaining instances of [DynIteratorT].
+macro_rules! impl_bounded_dyn_iterator {
+    ($hkt:ident, [$req_send:ty, $req_sync:ty], $($bound:path),+) => {
+        pub struct $hkt<TInner = IdHkt>(Infallible, PhantomData<TInner>);
+
+        impl<'t, TInner: Hkt<'t>> UnsizedHkt<'t> for $hkt<TInner> {
+            type UnsizedF<'a, A: 'a>
+                = dyn 'a + Iterator<Item = TInner::F<'a, A>> $(+ $bound)+
+            where
+                't: 'a;
+        }
+
+        impl<'t, TInner: HktUnsized<'t>> UnsizedHktUnsized<'t> for $hkt<TInner> {
+            type UnsizedFUnsized<'a, A: 'a + ?Sized>
+                = dyn 'a + Iterator<Item = TInner::FUnsized<'a, A>> $(+ $bound)+
+            where
+                't: 'a;
+        }
+
+        impl<
+            't,
+            ReqIn: TypeGuard<'t>,
+            ReqOut: TypeGuard<'t>,
+            ReqF1: TCloneableOf5<'t>,
+            TInner: Foldable<'t, ReqIn, ReqOut, ReqF1>,
+        > Foldable<'t, ReqIn, ReqOut, ReqF1> for BoxT<$hkt<TInner>>
+        {
+            fn fold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+                clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+                f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+                init: B,
+                fb: Self::F<'a, A>,
+            ) -> FoldWhile<B>
+            where
+                A: 'a,
+                B: 'b,
+                F1Once: 'f + FnOnce(B, A) -> FoldWhile<B>,
+                F1Mut: 'f + FnMut(B, A) -> FoldWhile<B>,
+                F1Fn: 'f + Fn(B, A) -> FoldWhile<B>,
+                F1Clone: 'f + Fn(B, A) -> FoldWhile<B> + Clone,
+                F1Copy: 'f + Fn(B, A) -> FoldWhile<B> + Copy,
+                'a: 'f,
+                'b: 'f,
+                't: 'a + 'b,
+            {
+                <BoxT<DynIteratorT<TInner>> as Foldable<'t, ReqIn, ReqOut, ReqF1>>::fold_while(
+                    clone_a,
+                    clone_b,
+                    f,
+                    init,
+                    BoxT::<$hkt<TInner>>::into_local(fb),
+                )
+            }
+        }
+
+        impl<'t, TInner: CovariantK<'t>> CovariantK<'t> for BoxT<$hkt<TInner>> {
+            fn covariant_convert<'a, 'b, A>(a: Self::F<'a, A>) -> Self::F<'b, A>
+            where
+                A: 'a,
+                'a: 'b,
+                't: 'a + 'b,
+            {
+                a.map(TInner::covariant_convert).pipe(Box::new)
+            }
+        }
+
+        impl<TInner> BoxT<$hkt<TInner>> {
+            /// Forgets the auto trait bounds.
+            pub fn into_local<'t, 'a, A>(
+                fa: <Self as Hkt<'t>>::F<'a, A>,
+            ) -> <BoxT<DynIteratorT<TInner>> as Hkt<'t>>::F<'a, A>
+            where
+                TInner: Hkt<'t>,
+                A: 'a,
+                't: 'a,
+            {
+                fa
+            }
+        }
+
+        impl<
+            't,
+            ReqIn: TypeGuard<'t>,
+            ReqOut: TypeGuard<'t>,
+            ReqF1: TCloneableOf5<'t>,
+            TInner: Functor<'t, ReqIn, ReqOut, ReqF1>,
+        > Functor<'t, ReqIn, ReqOut, ReqF1, $req_send, $req_sync> for BoxT<$hkt<TInner>>
+        {
+            fn map<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                clone_a: impl 'a
+                    + Fn(&A) -> ReqIn::Output<'a, A>
+                    + Clone
+                    + IsSend<$req_send>
+                    + IsSync<$req_sync>,
+                clone_b: impl 'a
+                    + Fn(&B) -> ReqOut::Output<'a, B>
+                    + Clone
+                    + IsSend<$req_send>
+                    + IsSync<$req_sync>,
+                f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+                fa: Self::F<'a, A>,
+            ) -> Self::F<'a, B>
+            where
+                A: 'a,
+                B: 'a,
+                F1Once: 'a + FnOnce(A) -> B,
+                F1Mut: 'a + FnMut(A) -> B,
+                F1Fn: 'a + Fn(A) -> B,
+                F1Clone: 'a + Fn(A) -> B + Clone,
+                F1Copy: 'a + Fn(A) -> B + Copy,
+                ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>:
+                    IsSend<$req_send> + IsSync<$req_sync>,
+                't: 'a,
+            {
+                let captured = (Wrapper::new(clone_a), Wrapper::new(clone_b), Wrapper::new(f));
+
+                fa.map(move |x| {
+                    let (clone_a, clone_b, f) = &captured;
+
+                    // Clones through a reference, since only `f` is known to be [Send].
+                    let f = ReqF1::as_ref(f.get())
+                        .pipe(|f| ReqF1::arbitrary_uncloneable(f, PhantomData::<fn(A) -> B>))
+                        .pipe(|f| {
+                            ReqF1::map_one_of_5::<'_, 'a, '_>(
+                                f,
+                                identity,
+                                identity,
+                                identity,
+                                |f| f.clone(),
+                                |f| *f,
+                            )
+                        });
+
+                    TInner::map::<A, B, fn(A) -> B, fn(A) -> B, fn(A) -> B, F1Clone, F1Copy>(
+                        clone_a.get().clone(),
+                        clone_b.get().clone(),
+                        f,
+                        x,
+                    )
+                })
+                .pipe(Box::new)
+            }
+        }
+    };
+}
+
+impl_bounded_dyn_iterator!(DynSendIteratorT, [ConstBool<true>, ConstBool<false>], Send);
+impl_bounded_dyn_iterator!(
+    DynSendSyncIteratorT,
+    [ConstBool<true>, ConstBool<true>],
+    Send,
+    Sync
+);
@@ -0,0 +1,530 @@
+use core::{
+    cell::{Cell, OnceCell},
+    convert::{Infallible, identity},
+    marker::PhantomData,
+};
+
+use alloc::{boxed::Box, rc::Rc};
+use tap::Pipe as _;
+
+use crate::{
+    hkt::{
+        Applicative, CloneK, Functor, Hkt, Monad, Pure, TCloneableOf5,
+        hkt_classification::{self, HktClassification},
+        id::IdHkt,
+        one_of::{OneOf5, OneOf5Hkt},
+    },
+    marker_classification::{ConstBool, TypeGuard},
+};
+
+const POISONED: &str = "the initializer of this lazy value panicked";
+const EVALUATED: &str = "the lazy value was just evaluated";
+
+struct LazyCell<'a, A> {
+    value: OnceCell<A>,
+    init: Cell<Option<Box<dyn 'a + FnOnce() -> A>>>,
+}
+
+/// Deferred computation evaluated at most once, clones share the memoised value.
+pub struct Lazy<'a, A> {
+    cell: Rc<LazyCell<'a, A>>,
+}
+
+impl<'a, A> Clone for Lazy<'a, A> {
+    fn clone(&self) -> Self {
+        Self {
+            cell: self.cell.clone(),
+        }
+    }
+}
+
+impl<'a, A: 'a> Lazy<'a, A> {
+    pub fn new(f: impl 'a + FnOnce() -> A) -> Self {
+        Self {
+            cell: Rc::new(LazyCell {
+                value: OnceCell::new(),
+                init: Cell::new(Some(Box::new(f))),
+            }),
+        }
+    }
+
+    /// Already evaluated.
+    pub fn from_value(a: A) -> Self {
+        Self {
+            cell: Rc::new(LazyCell {
+                value: OnceCell::from(a),
+                init: Cell::new(None),
+            }),
+        }
+    }
+
+    /// Evaluates the computation if no clone has done so yet.
+    ///
+    /// # Panics
+    ///
+    /// If the computation panicked before or calls [force](Lazy::force) on a clone of itself.
+    pub fn force(&self) -> &A {
+        self.cell
+            .value
+            .get_or_init(|| self.cell.init.take().expect(POISONED)())
+    }
+
+    pub fn is_evaluated(&self) -> bool {
+        self.cell.value.get().is_some()
+    }
+
+    /// Forces the value, which is moved out if no clone is alive and cloned with `clone` otherwise.
+    pub fn into_value(self, clone: impl FnOnce(&A) -> A) -> A {
+        self.force();
+
+        match Rc::try_unwrap(self.cell) {
+            Ok(cell) => cell.value.into_inner().expect(EVALUATED),
+            Err(cell) => clone(cell.value.get().expect(EVALUATED)),
+        }
+    }
+}
+
+/// Lazy values that can be cloned without cloning their contents.
+///
+/// Mapping an evaluated value moves it out when no clone is alive, otherwise `clone_a` is used.
+///
+/// [Monad] is only given for `LazyT<IdHkt>` with [`ConstBool<true>`] requirements,
+/// since `bind` may have to clone both the value and the lazy result of `f` out of shared memos.
+pub struct LazyT<TInner = IdHkt>(Infallible, PhantomData<TInner>);
+
+impl<'t, TInner: Hkt<'t>> Hkt<'t> for LazyT<TInner> {
+    type F<'a, A: 'a>
+        = Lazy<'a, TInner::F<'a, A>>
+    where
+        't: 'a;
+}
+
+impl<TInner> HktClassification for LazyT<TInner> {
+    type Choice = hkt_classification::OuterHkt;
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t>,
+    TInner: Functor<'t, ReqIn, ReqOut, ReqF1> + CloneK<'t, ReqIn>,
+> Functor<'t, ReqIn, ReqOut, ReqF1> for LazyT<TInner>
+{
+    fn map<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> B,
+        F1Mut: 'a + FnMut(A) -> B,
+        F1Fn: 'a + Fn(A) -> B,
+        F1Clone: 'a + Fn(A) -> B + Clone,
+        F1Copy: 'a + Fn(A) -> B + Copy,
+        't: 'a,
+    {
+        Lazy::new(move || {
+            let ta = fa.into_value(|ta| TInner::clone(clone_a.clone(), ta));
+
+            TInner::map(clone_a, clone_b, f, ta)
+        })
+    }
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, TInner: Pure<'t, ReqIn>> Pure<'t, ReqIn> for LazyT<TInner> {
+    fn pure<'a, A>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        a: A,
+    ) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        TInner::pure(clone_a, a).pipe(Lazy::from_value)
+    }
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: TCloneableOf5<'t>,
+    TInner: Applicative<'t, ReqIn, ReqOut, ReqF1> + CloneK<'t, ReqIn>,
+> Applicative<'t, ReqIn, ReqOut, ReqF1> for LazyT<TInner>
+{
+    fn apply<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        ff: Self::F<'a, <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> B,
+        F1Mut: 'a + FnMut(A) -> B,
+        F1Fn: 'a + Fn(A) -> B,
+        F1Clone: 'a + Fn(A) -> B + Clone,
+        F1Copy: 'a + Fn(A) -> B + Copy,
+        't: 'a,
+    {
+        Lazy::new(move || {
+            let tf = ff.into_value(|tf| {
+                TInner::clone(
+                    |f| {
+                        // Same as in DynIteratorT: clones &ReqF1::OneOf5F while preserving uncloneable variants
+                        ReqF1::as_ref(f)
+                            .pipe(|f| ReqF1::arbitrary_t1(f, PhantomData::<F1Once>))
+                            .pipe(|f| ReqF1::arbitrary_t2(f, PhantomData::<F1Mut>))
+                            .pipe(|f| ReqF1::arbitrary_t3(f, PhantomData::<F1Fn>))
+                            .pipe(|f| {
+                                ReqF1::map_one_of_5::<'_, 'a, '_>(
+                                    f,
+                                    identity,
+                                    identity,
+                                    identity,
+                                    |f| f.clone(),
+                                    |f| *f,
+                                )
+                            })
+                            .pipe(ReqIn::into_guarded)
+                    },
+                    tf,
+                )
+            });
+            let ta = fa.into_value(|ta| TInner::clone(clone_a.clone(), ta));
+
+            TInner::apply(clone_a, clone_b, tf, ta)
+        })
+    }
+}
+
+impl<'t, ReqF1: TCloneableOf5<'t>> Monad<'t, ConstBool<true>, ConstBool<true>, ReqF1>
+    for LazyT<IdHkt>
+{
+    fn bind<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> A + Clone,
+        clone_b: impl 'a + Fn(&B) -> B + Clone,
+        fa: Self::F<'a, A>,
+        f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> Self::F<'a, B>,
+        F1Mut: 'a + FnMut(A) -> Self::F<'a, B>,
+        F1Fn: 'a + Fn(A) -> Self::F<'a, B>,
+        F1Clone: 'a + Fn(A) -> Self::F<'a, B> + Clone,
+        F1Copy: 'a + Fn(A) -> Self::F<'a, B> + Copy,
+        't: 'a,
+    {
+        Lazy::new(move || {
+            let a = fa.into_value(clone_a);
+
+            match_one_of_5!(ReqF1::into_one_of_5_enum(f), f, f(a)).into_value(clone_b)
+        })
+    }
+}
+
+/// Shares the memo instead of cloning the contents.
+impl<'t, ReqIn: TypeGuard<'t>, TInner: Hkt<'t>> CloneK<'t, ReqIn> for LazyT<TInner> {
+    fn clone<'a, A>(
+        _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        a: &Self::F<'a, A>,
+    ) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        a.clone()
+    }
+}
+
+#[cfg(not(feature = "no-std"))]
+pub use use_std::*;
+
+#[cfg(not(feature = "no-std"))]
+mod use_std {
+    use core::{
+        convert::{Infallible, identity},
+        marker::PhantomData,
+    };
+
+    use alloc::{boxed::Box, sync::Arc};
+    use std::sync::{Mutex, OnceLock, PoisonError};
+
+    use tap::Pipe as _;
+
+    use crate::{
+        hkt::{
+            Applicative, CloneK, Functor, Hkt, Monad, Pure, TCloneableOf5,
+            hkt_classification::{self, HktClassification},
+            id::IdHkt,
+            one_of::{OneOf5, OneOf5Hkt},
+        },
+        marker_classification::{ConstBool, IsConstBool, IsSend, IsSync, TypeGuard, Wrapper},
+    };
+
+    use super::{EVALUATED, POISONED};
+
+    type SendSync = ConstBool<true>;
+
+    type SyncInit<'a, A> = Mutex<Option<Box<dyn 'a + Send + FnOnce() -> A>>>;
+
+    struct SyncLazyCell<'a, A> {
+        value: OnceLock<A>,
+        init: SyncInit<'a, A>,
+    }
+
+    /// [Sync] version of [Lazy](super::Lazy), other threads block while the value is evaluated.
+    pub struct SyncLazy<'a, A> {
+        cell: Arc<SyncLazyCell<'a, A>>,
+    }
+
+    // SAFETY: `IsSend<ConstBool<true>>` and `IsSync<ConstBool<true>>` are only implemented for
+    // `Send` and `Sync` types, so these are exactly the auto impls. Stating them through the
+    // markers lets the instances below capture values only known to be `IsSend`/`IsSync`.
+    unsafe impl<'a, A: IsSend<SendSync> + IsSync<SendSync>> Send for SyncLazy<'a, A> {}
+    unsafe impl<'a, A: IsSend<SendSync> + IsSync<SendSync>> Sync for SyncLazy<'a, A> {}
+
+    impl<'a, A> Clone for SyncLazy<'a, A> {
+        fn clone(&self) -> Self {
+            Self {
+                cell: self.cell.clone(),
+            }
+        }
+    }
+
+    impl<'a, A: 'a> SyncLazy<'a, A> {
+        pub fn new(f: impl 'a + Send + FnOnce() -> A) -> Self {
+            Self {
+                cell: Arc::new(SyncLazyCell {
+                    value: OnceLock::new(),
+                    init: Mutex::new(Some(Box::new(f))),
+                }),
+            }
+        }
+
+        /// Already evaluated.
+        pub fn from_value(a: A) -> Self {
+            Self {
+                cell: Arc::new(SyncLazyCell {
+                    value: OnceLock::from(a),
+                    init: Mutex::new(None),
+                }),
+            }
+        }
+
+        /// Evaluates the computation if no clone has done so yet.
+        ///
+        /// # Panics
+        ///
+        /// If the computation panicked before.
+        pub fn force(&self) -> &A {
+            self.cell.value.get_or_init(|| {
+                self.cell
+                    .init
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .take()
+                    .expect(POISONED)()
+            })
+        }
+
+        pub fn is_evaluated(&self) -> bool {
+            self.cell.value.get().is_some()
+        }
+
+        /// Forces the value, which is moved out if no clone is alive and cloned with `clone` otherwise.
+        pub fn into_value(self, clone: impl FnOnce(&A) -> A) -> A {
+            self.force();
+
+            match Arc::try_unwrap(self.cell) {
+                Ok(cell) => cell.value.into_inner().expect(EVALUATED),
+                Err(cell) => clone(cell.value.get().expect(EVALUATED)),
+            }
+        }
+    }
+
+    /// [Sync] version of [LazyT](super::LazyT).
+    ///
+    /// The deferred computation has to be [Send], so [Functor], [Applicative] and [Monad] are given
+    /// with [`ConstBool<true>`] for both `ReqSend` and `ReqSync`, and only for `SyncLazyT<IdHkt>`
+    /// with [`ConstBool<true>`] clone requirements.
+    pub struct SyncLazyT<TInner = IdHkt>(Infallible, PhantomData<TInner>);
+
+    impl<'t, TInner: Hkt<'t>> Hkt<'t> for SyncLazyT<TInner> {
+        type F<'a, A: 'a>
+            = SyncLazy<'a, TInner::F<'a, A>>
+        where
+            't: 'a;
+    }
+
+    impl<TInner> HktClassification for SyncLazyT<TInner> {
+        type Choice = hkt_classification::OuterHkt;
+    }
+
+    impl<
+        't,
+        ReqIn: TypeGuard<'t>,
+        ReqSend: IsConstBool,
+        ReqSync: IsConstBool,
+        TInner: Pure<'t, ReqIn>,
+    > Pure<'t, ReqIn, ReqSend, ReqSync> for SyncLazyT<TInner>
+    {
+        fn pure<'a, A>(
+            clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+            a: A,
+        ) -> Self::F<'a, A>
+        where
+            A: 'a,
+            't: 'a,
+        {
+            TInner::pure(clone_a, a).pipe(SyncLazy::from_value)
+        }
+    }
+
+    /// Shares the memo instead of cloning the contents.
+    impl<'t, ReqIn: TypeGuard<'t>, TInner: Hkt<'t>> CloneK<'t, ReqIn> for SyncLazyT<TInner> {
+        fn clone<'a, A>(
+            _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+            a: &Self::F<'a, A>,
+        ) -> Self::F<'a, A>
+        where
+            A: 'a,
+            't: 'a,
+        {
+            a.clone()
+        }
+    }
+
+    impl<'t, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>
+        Functor<'t, ConstBool<true>, ReqOut, ReqF1, SendSync, SendSync> for SyncLazyT<IdHkt>
+    {
+        fn map<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+            clone_a: impl 'a + Fn(&A) -> A + Clone + IsSend<SendSync> + IsSync<SendSync>,
+            _clone_b: impl 'a
+            + Fn(&B) -> ReqOut::Output<'a, B>
+            + Clone
+            + IsSend<SendSync>
+            + IsSync<SendSync>,
+            f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+            fa: Self::F<'a, A>,
+        ) -> Self::F<'a, B>
+        where
+            A: 'a + IsSend<SendSync> + IsSync<SendSync>,
+            B: 'a,
+            F1Once: 'a + FnOnce(A) -> B,
+            F1Mut: 'a + FnMut(A) -> B,
+            F1Fn: 'a + Fn(A) -> B,
+            F1Clone: 'a + Fn(A) -> B + Clone,
+            F1Copy: 'a + Fn(A) -> B + Copy,
+            ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>:
+                IsSend<SendSync> + IsSync<SendSync>,
+            't: 'a,
+        {
+            let captured = (Wrapper::new(clone_a), Wrapper::new(f));
+
+            SyncLazy::new(move || {
+                let (clone_a, f) = captured;
+                let a = fa.into_value(clone_a.into_inner());
+
+                match_one_of_5!(ReqF1::into_one_of_5_enum(f.into_inner()), f, f(a))
+            })
+        }
+    }
+
+    impl<'t, ReqOut: TypeGuard<'t>, ReqF1: TCloneableOf5<'t>>
+        Applicative<'t, ConstBool<true>, ReqOut, ReqF1, SendSync, SendSync> for SyncLazyT<IdHkt>
+    {
+        fn apply<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+            clone_a: impl 'a + Fn(&A) -> A + Clone + IsSend<SendSync> + IsSync<SendSync>,
+            _clone_b: impl 'a
+            + Fn(&B) -> ReqOut::Output<'a, B>
+            + Clone
+            + IsSend<SendSync>
+            + IsSync<SendSync>,
+            ff: Self::F<'a, ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>>,
+            fa: Self::F<'a, A>,
+        ) -> Self::F<'a, B>
+        where
+            A: 'a + IsSend<SendSync> + IsSync<SendSync>,
+            B: 'a,
+            F1Once: 'a + FnOnce(A) -> B,
+            F1Mut: 'a + FnMut(A) -> B,
+            F1Fn: 'a + Fn(A) -> B,
+            F1Clone: 'a + Fn(A) -> B + Clone,
+            F1Copy: 'a + Fn(A) -> B + Copy,
+            ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>:
+                IsSend<SendSync> + IsSync<SendSync>,
+            't: 'a,
+        {
+            let clone_a = Wrapper::new(clone_a);
+
+            SyncLazy::new(move || {
+                let f = ff.into_value(|f| {
+                    // Same as in LazyT: clones &ReqF1::OneOf5F preserving uncloneable variants
+                    ReqF1::as_ref(f)
+                        .pipe(|f| ReqF1::arbitrary_t1(f, PhantomData::<F1Once>))
+                        .pipe(|f| ReqF1::arbitrary_t2(f, PhantomData::<F1Mut>))
+                        .pipe(|f| ReqF1::arbitrary_t3(f, PhantomData::<F1Fn>))
+                        .pipe(|f| {
+                            ReqF1::map_one_of_5::<'_, 'a, '_>(
+                                f,
+                                identity,
+                                identity,
+                                identity,
+                                |f| f.clone(),
+                                |f| *f,
+                            )
+                        })
+                });
+                let a = fa.into_value(clone_a.into_inner());
+
+                match_one_of_5!(ReqF1::into_one_of_5_enum(f), f, f(a))
+            })
+        }
+    }
+
+    impl<'t, ReqF1: TCloneableOf5<'t>>
+        Monad<'t, ConstBool<true>, ConstBool<true>, ReqF1, SendSync, SendSync>
+        for SyncLazyT<IdHkt>
+    {
+        fn bind<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+            clone_a: impl 'a + Fn(&A) -> A + Clone + IsSend<SendSync> + IsSync<SendSync>,
+            clone_b: impl 'a + Fn(&B) -> B + Clone + IsSend<SendSync> + IsSync<SendSync>,
+            fa: Self::F<'a, A>,
+            f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        ) -> Self::F<'a, B>
+        where
+            A: 'a + IsSend<SendSync> + IsSync<SendSync>,
+            B: 'a,
+            F1Once: 'a + FnOnce(A) -> Self::F<'a, B>,
+            F1Mut: 'a + FnMut(A) -> Self::F<'a, B>,
+            F1Fn: 'a + Fn(A) -> Self::F<'a, B>,
+            F1Clone: 'a + Fn(A) -> Self::F<'a, B> + Clone,
+            F1Copy: 'a + Fn(A) -> Self::F<'a, B> + Copy,
+            ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>:
+                IsSend<SendSync> + IsSync<SendSync>,
+            't: 'a,
+        {
+            let captured = (
+                Wrapper::new(clone_a),
+                Wrapper::new(clone_b),
+                Wrapper::new(f),
+            );
+
+            SyncLazy::new(move || {
+                let (clone_a, clone_b, f) = captured;
+                let a = fa.into_value(clone_a.into_inner());
+
+                match_one_of_5!(ReqF1::into_one_of_5_enum(f.into_inner()), f, f(a))
+                    .into_value(clone_b.into_inner())
+            })
+        }
+    }
+}
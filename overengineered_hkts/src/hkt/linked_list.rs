@@ -0,0 +1,26 @@
+use core::{convert::Infallible, marker::PhantomData};
+
+use alloc::{collections::LinkedList, vec::Vec};
+use tap::Pipe;
+
+use crate::{
+    hkt::{
+        Applicative, CloneK, CloneOwnedK, CovariantK, FoldWhile, Foldable, Functor, Hkt,
+        HktUnsized, Monad, Pure, Rfoldable, TCloneableOf5, Traversable,
+        hkt_classification::{self, HktClassification},
+        id::IdHkt,
+        one_of::{NotT1Of5, NotT5Of5, OneOf5Hkt},
+        reference::{RefMutT, RefT},
+        vec::VecT,
+    },
+    marker_classification::{AssertBlankOutput, ConstBool, TypeGuard},
+    utils::sum_size_hints,
+};
+
+impl_sequence_hkt!(
+    /// [LinkedList] of `TInner` layers.
+    ///
+    /// For [Applicative], [Monad] and [Traversable] the nodes are collected into a [VecT] and relinked in the same order.
+    LinkedListT,
+    LinkedList
+);
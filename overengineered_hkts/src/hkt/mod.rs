@@ -1,15 +1,19 @@
 // TODO: Ability to Add custom bounds like Debug
 
-#[cfg(false)]
 pub mod array;
+pub mod binary_heap;
 /// TODO
 pub mod bind;
 /// TODO
 pub mod boxed;
+pub mod btree_map;
+pub mod cell;
 #[cfg(false)]
 pub mod dependent_wrapper;
+pub mod eval;
 #[cfg(false)]
 pub mod extensions;
+pub mod fix;
 #[cfg(false)]
 #[deprecated]
 pub mod fn_lifetime_exp;
@@ -18,23 +22,41 @@ pub mod fold_vec;
 #[cfg(false)]
 pub mod free;
 pub mod future;
+#[cfg(not(feature = "no-std"))]
+pub mod hash_map;
 pub mod id;
 pub mod iter;
+pub mod linked_list;
 #[cfg(feature = "laws")]
 pub mod laws;
 #[cfg(not(feature = "laws"))]
 pub(crate) mod laws;
+pub mod lazy;
+pub mod non_empty_vec;
 pub mod nullary;
 pub mod one_of;
+pub mod optics;
 pub mod option;
+pub mod pin;
+pub mod product;
+pub mod rc;
+pub mod reader;
 pub mod reference;
 pub mod result;
 pub mod slice;
+pub mod stream;
+pub mod sum;
 pub mod sync;
+pub mod these;
 pub mod traits;
+pub mod tree;
 #[cfg(false)]
 pub mod tuple;
+pub mod validated;
 pub mod vec;
+pub mod vec_deque;
+pub mod yoneda;
+pub mod zipper;
 
 pub use traits::*;
 
@@ -124,17 +146,63 @@ pub use traits::*;
 
 #[cfg(test)]
 mod test {
-    use core::ops::{BitAnd, Not};
+    use core::{
+        num::NonZeroUsize,
+        ops::{BitAnd, Not},
+    };
 
     use const_random::const_random;
 
     use crate::{
-        hkt::{Monad, id::IdHkt, one_of::T4Of5Hkt, option::OptionT, result::ResultT, vec::VecT},
-        marker_classification::DependentClone,
+        hkt::{
+            Align, Applicative, Bifunctor, Borrown, CloneK, Comonad, Converge, CowT, DerefHkt,
+            DerefMutHkt, Distributive, FoldWhile, Foldable, FoldableWithIndex, Functor, FunctorWithIndex,
+            MapInPlace,
+            Monad, Pure, Representable, Rfoldable, SemigroupK, Traversable, Unfoldable, Zip,
+            array::ArrayT,
+            binary_heap::BinaryHeapT,
+            bind::BindT,
+            boxed::BoxT,
+            btree_map::BTreeMapT,
+            cell::RefCellT,
+            eval::{Eval, EvalT},
+            fix::{Expr, ExprF, Fix, ListF, ana, apo, cata, hylo, para},
+            future::{DynFutureT, PinBoxFutureT, PinBoxSendFutureT, PinBoxSendSyncFutureT},
+            hash_map::HashMapT,
+            id::IdHkt,
+            iter::DynSendIteratorT,
+            lazy::{Lazy, LazyT, SyncLazy, SyncLazyT},
+            linked_list::LinkedListT,
+            non_empty_vec::{NonEmptyVec, NonEmptyVecT},
+            nullary::ConstT,
+            one_of::T4Of5Hkt,
+            optics::{Each, Lens, Traversal},
+            option::OptionT,
+            pin::PinT,
+            product::{PairT, ProductT},
+            rc::RcT,
+            reader::ReaderT,
+            reference::{RefMutT, RefT},
+            result::ResultT,
+            slice::SliceT,
+            stream::PinBoxStreamT,
+            sum::SumT,
+            sync::{ArcT, MutexT, Poisoned, RwLockT},
+            these::{These, TheseT},
+            tree::{Tree, TreeT},
+            validated::{Validated, ValidatedT},
+            vec::VecT,
+            vec_deque::VecDequeT,
+            yoneda::{Coyoneda, CoyonedaT, YonedaT},
+            zipper::{Zipper, ZipperT},
+        },
+        marker_classification::{ConstBool, DependentClone},
+        field_lens, variant_prism,
     };
 
     type Hkt = VecT<VecT>;
     type HktI = Vec<Vec<i32>>;
+    type PinBoxFuture<T> = core::pin::Pin<alloc::boxed::Box<dyn Future<Output = T>>>;
 
     fn get_test_base() -> HktI {
         vec![
@@ -365,4 +433,1809 @@ mod test {
             expected
         );
     }
+
+    #[test]
+    fn test_vect_zip_and_align() {
+        let fa = vec![1, 2, 3];
+        let fb = vec![10, 20];
+
+        assert_eq!(
+            <VecT as Zip<DependentClone, DependentClone, T4Of5Hkt>>::zip_with::<
+                _,
+                _,
+                _,
+                fn(i32, i32) -> i32,
+                fn(i32, i32) -> i32,
+                fn(i32, i32) -> i32,
+                _,
+                fn(i32, i32) -> i32,
+            >(|i| *i, |i| *i, |i| *i, |a, b| a + b, fa.clone(), fb.clone()),
+            vec![11, 22]
+        );
+
+        assert_eq!(
+            <VecT as Align<DependentClone, DependentClone, T4Of5Hkt>>::align_with::<
+                _,
+                _,
+                _,
+                fn(These<i32, i32>) -> These<i32, i32>,
+                fn(These<i32, i32>) -> These<i32, i32>,
+                fn(These<i32, i32>) -> These<i32, i32>,
+                _,
+                fn(These<i32, i32>) -> These<i32, i32>,
+            >(|i| *i, |i| *i, |i| *i, |these| these, fa, fb),
+            vec![These::Both(1, 10), These::Both(2, 20), These::This(3)]
+        );
+    }
+
+    #[test]
+    fn test_bindt_map_with_index() {
+        let input = vec![vec![1, 2], vec![3]];
+        let expected = vec![vec![(0, 0, 1), (0, 1, 2)], vec![(1, 0, 3)]];
+
+        assert_eq!(
+            <BindT<VecT, VecT> as FunctorWithIndex<DependentClone, DependentClone, T4Of5Hkt>>::map_with_index::<
+                _,
+                _,
+                fn((usize, usize), i32) -> (usize, usize, i32),
+                fn((usize, usize), i32) -> (usize, usize, i32),
+                fn((usize, usize), i32) -> (usize, usize, i32),
+                _,
+                fn((usize, usize), i32) -> (usize, usize, i32),
+            >(|i| *i, |t| *t, |(i, j), a| (i, j, a), input),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_unfoldable() {
+        assert_eq!(
+            <BindT<VecT, OptionT> as Unfoldable<DependentClone>>::from_iter(|i| *i, 1..=3),
+            vec![Some(1), Some(2), Some(3)]
+        );
+
+        assert_eq!(
+            <ArrayT<3, OptionT> as Unfoldable<DependentClone>>::replicate(|i| *i, 2, 7),
+            [Some(7), Some(7), None]
+        );
+    }
+
+    #[test]
+    fn test_btreemapt_traverse_and_union() {
+        let input = alloc::collections::BTreeMap::from([("a", 1), ("b", 2)]);
+
+        let traversed =
+            <BTreeMapT<&str> as Traversable<DependentClone, DependentClone, T4Of5Hkt>>::traverse::<
+                _,
+                _,
+                VecT,
+                fn(i32) -> Vec<i32>,
+                fn(i32) -> Vec<i32>,
+                fn(i32) -> Vec<i32>,
+                _,
+                fn(i32) -> Vec<i32>,
+            >(|i| *i, |i| *i, |i| vec![i, -i], input.clone());
+
+        assert_eq!(
+            traversed,
+            vec![
+                alloc::collections::BTreeMap::from([("a", 1), ("b", 2)]),
+                alloc::collections::BTreeMap::from([("a", 1), ("b", -2)]),
+                alloc::collections::BTreeMap::from([("a", -1), ("b", 2)]),
+                alloc::collections::BTreeMap::from([("a", -1), ("b", -2)]),
+            ]
+        );
+
+        assert_eq!(
+            <BTreeMapT<&str> as FunctorWithIndex<DependentClone, DependentClone, T4Of5Hkt>>::map_with_index::<
+                _,
+                _,
+                fn(&str, i32) -> String,
+                fn(&str, i32) -> String,
+                fn(&str, i32) -> String,
+                _,
+                fn(&str, i32) -> String,
+            >(|i| *i, Clone::clone, |k, i| format!("{k}{i}"), input.clone()),
+            alloc::collections::BTreeMap::from([("a", "a1".to_string()), ("b", "b2".to_string())])
+        );
+        assert_eq!(
+            <BTreeMapT<&str> as FoldableWithIndex<DependentClone, DependentClone, T4Of5Hkt>>::fold_with_index::<
+                _,
+                _,
+                fn(String, &str, i32) -> String,
+                fn(String, &str, i32) -> String,
+                fn(String, &str, i32) -> String,
+                _,
+                fn(String, &str, i32) -> String,
+            >(|i| *i, Clone::clone, |s, k, i| format!("{s}{k}{i}"), String::new(), input.clone()),
+            "a1b2"
+        );
+        assert_eq!(
+            <HashMapT<&str> as FunctorWithIndex<DependentClone, DependentClone, T4Of5Hkt>>::map_with_index::<
+                _,
+                _,
+                fn(&str, i32) -> usize,
+                fn(&str, i32) -> usize,
+                fn(&str, i32) -> usize,
+                _,
+                fn(&str, i32) -> usize,
+            >(|i| *i, |i| *i, |k, i| k.len() + i as usize, std::collections::HashMap::from([("ab", 1)])),
+            std::collections::HashMap::from([("ab", 3)])
+        );
+
+        assert_eq!(
+            <BTreeMapT<&str> as SemigroupK<DependentClone>>::combine(
+                input,
+                alloc::collections::BTreeMap::from([("b", 0), ("c", 3)]),
+                |i| *i
+            ),
+            alloc::collections::BTreeMap::from([("a", 1), ("b", 2), ("c", 3)])
+        );
+    }
+
+    #[test]
+    fn test_std_collections() {
+        use alloc::collections::{BinaryHeap, LinkedList, VecDeque};
+
+        assert_eq!(
+            <VecDequeT<OptionT> as Monad<DependentClone, DependentClone, T4Of5Hkt>>::bind::<
+                _,
+                _,
+                fn(i32) -> VecDeque<Option<i32>>,
+                fn(i32) -> VecDeque<Option<i32>>,
+                fn(i32) -> VecDeque<Option<i32>>,
+                _,
+                fn(i32) -> VecDeque<Option<i32>>,
+            >(
+                |i| *i,
+                |i| *i,
+                VecDeque::from([None, Some(0), Some(1), Some(2)]),
+                |i| i32_to_vec_option_2(i).into()
+            ),
+            VecDeque::from([None, Some(0), None, Some(1), None, None, Some(2)])
+        );
+
+        assert_eq!(
+            <LinkedListT as Rfoldable<DependentClone, DependentClone, T4Of5Hkt>>::rfold_while::<
+                _,
+                _,
+                fn(Vec<i32>, i32) -> core::ops::ControlFlow<Vec<i32>, Vec<i32>>,
+                fn(Vec<i32>, i32) -> core::ops::ControlFlow<Vec<i32>, Vec<i32>>,
+                fn(Vec<i32>, i32) -> core::ops::ControlFlow<Vec<i32>, Vec<i32>>,
+                _,
+                fn(Vec<i32>, i32) -> core::ops::ControlFlow<Vec<i32>, Vec<i32>>,
+            >(
+                |i| *i,
+                Clone::clone,
+                |mut sum, i| {
+                    sum.push(i);
+                    core::ops::ControlFlow::Continue(sum)
+                },
+                Vec::new(),
+                LinkedList::from([1, 2, 3])
+            )
+            .converge(),
+            vec![3, 2, 1]
+        );
+
+        assert_eq!(
+            <BinaryHeapT as Foldable<DependentClone, DependentClone, T4Of5Hkt>>::fold_while::<
+                _,
+                _,
+                fn(i32, i32) -> core::ops::ControlFlow<i32, i32>,
+                fn(i32, i32) -> core::ops::ControlFlow<i32, i32>,
+                fn(i32, i32) -> core::ops::ControlFlow<i32, i32>,
+                _,
+                fn(i32, i32) -> core::ops::ControlFlow<i32, i32>,
+            >(
+                |i| *i,
+                |i| *i,
+                |sum, i| core::ops::ControlFlow::Continue(sum + i),
+                0,
+                BinaryHeap::from([3, 1, 2])
+            )
+            .converge(),
+            6
+        );
+
+        let mut heap = BinaryHeap::new();
+        for i in [2, 5, 1, 4, 3] {
+            heap.push(i);
+        }
+        assert_eq!(
+            BinaryHeapT::<IdHkt>::fold_while_by_priority::<
+                _,
+                _,
+                DependentClone,
+                DependentClone,
+                T4Of5Hkt,
+                fn(Vec<i32>, i32) -> core::ops::ControlFlow<Vec<i32>, Vec<i32>>,
+                fn(Vec<i32>, i32) -> core::ops::ControlFlow<Vec<i32>, Vec<i32>>,
+                fn(Vec<i32>, i32) -> core::ops::ControlFlow<Vec<i32>, Vec<i32>>,
+                _,
+                fn(Vec<i32>, i32) -> core::ops::ControlFlow<Vec<i32>, Vec<i32>>,
+            >(
+                |i| *i,
+                Clone::clone,
+                |mut sorted, i| {
+                    sorted.push(i);
+                    core::ops::ControlFlow::Continue(sorted)
+                },
+                Vec::new(),
+                heap
+            )
+            .converge(),
+            vec![5, 4, 3, 2, 1]
+        );
+
+        assert_eq!(
+            <VecDequeT as Foldable<DependentClone, DependentClone, T4Of5Hkt>>::size_hint(
+                &VecDeque::from([1, 2, 3])
+            ),
+            (3, Some(3))
+        );
+        assert_eq!(
+            <LinkedListT<VecDequeT> as Foldable<DependentClone, DependentClone, T4Of5Hkt>>::size_hint(
+                &LinkedList::from([VecDeque::from([1, 2]), VecDeque::new(), VecDeque::from([3])])
+            ),
+            (3, Some(3))
+        );
+        assert_eq!(
+            <VecDequeT<OptionT> as Foldable<DependentClone, DependentClone, T4Of5Hkt>>::size_hint(
+                &VecDeque::from([Some(1), None])
+            ),
+            (0, None)
+        );
+    }
+
+    #[test]
+    fn test_non_empty_vect() {
+        let input = NonEmptyVec {
+            head: 1,
+            tail: vec![2],
+        };
+
+        let bound = <NonEmptyVecT as Monad<DependentClone, DependentClone, T4Of5Hkt>>::bind::<
+            _,
+            _,
+            fn(i32) -> NonEmptyVec<i32>,
+            fn(i32) -> NonEmptyVec<i32>,
+            fn(i32) -> NonEmptyVec<i32>,
+            _,
+            fn(i32) -> NonEmptyVec<i32>,
+        >(|i| *i, |i| *i, input, |i| NonEmptyVec {
+            head: i,
+            tail: vec![-i],
+        });
+
+        assert_eq!(<NonEmptyVecT>::into_vec(bound.clone()), vec![1, -1, 2, -2]);
+        assert_eq!(NonEmptyVecT::reduce(|a, b| a * 10 + b, bound), 918);
+        assert_eq!(<NonEmptyVecT>::from_vec::<i32>(Vec::new()), None);
+    }
+
+    #[test]
+    fn test_treet() {
+        type Collect = fn(Vec<i32>, i32) -> core::ops::ControlFlow<Vec<i32>, Vec<i32>>;
+
+        let push: Collect = |mut sum, i| {
+            sum.push(i);
+            core::ops::ControlFlow::Continue(sum)
+        };
+
+        let input = Tree::new(1, vec![Tree::new(2, vec![Tree::leaf(4)]), Tree::leaf(3)]);
+
+        assert_eq!(
+            <TreeT as Foldable<DependentClone, DependentClone, T4Of5Hkt>>::fold_while::<
+                _,
+                _,
+                Collect,
+                Collect,
+                Collect,
+                _,
+                Collect,
+            >(|i| *i, Clone::clone, push, Vec::new(), input.clone())
+            .converge(),
+            vec![1, 2, 4, 3]
+        );
+
+        assert_eq!(
+            <TreeT>::fold_breadth_first_while::<
+                DependentClone,
+                DependentClone,
+                T4Of5Hkt,
+                _,
+                _,
+                Collect,
+                Collect,
+                Collect,
+                _,
+                Collect,
+            >(|i| *i, Clone::clone, push, Vec::new(), input.clone())
+            .converge(),
+            vec![1, 2, 3, 4]
+        );
+
+        assert_eq!(
+            <TreeT as Monad<DependentClone, DependentClone, T4Of5Hkt>>::bind::<
+                _,
+                _,
+                fn(i32) -> Tree<i32>,
+                fn(i32) -> Tree<i32>,
+                fn(i32) -> Tree<i32>,
+                _,
+                fn(i32) -> Tree<i32>,
+            >(|i| *i, |i| *i, Tree::new(1, vec![Tree::leaf(2)]), |i| {
+                Tree::new(i, vec![Tree::leaf(-i)])
+            }),
+            Tree::new(1, vec![Tree::leaf(-1), Tree::new(2, vec![Tree::leaf(-2)])])
+        );
+
+        assert_eq!(
+            <TreeT as Traversable<DependentClone, DependentClone, T4Of5Hkt>>::traverse::<
+                _,
+                _,
+                OptionT,
+                fn(i32) -> Option<i32>,
+                fn(i32) -> Option<i32>,
+                fn(i32) -> Option<i32>,
+                _,
+                fn(i32) -> Option<i32>,
+            >(|i| *i, |i| *i, |i| i.checked_mul(10), input),
+            Some(Tree::new(10, vec![Tree::new(20, vec![Tree::leaf(40)]), Tree::leaf(30)]))
+        );
+    }
+
+    #[test]
+    fn test_shared_pointers() {
+        use alloc::{rc::Rc, sync::Arc};
+
+        let shared = Arc::new(1);
+        let mapped = <ArcT as Functor<DependentClone, DependentClone, T4Of5Hkt>>::map::<
+            _,
+            _,
+            fn(i32) -> i32,
+            fn(i32) -> i32,
+            fn(i32) -> i32,
+            _,
+            fn(i32) -> i32,
+        >(|i| *i, |i| *i, |i| i + 1, shared.clone());
+
+        assert_eq!((*shared, *mapped), (1, 2));
+
+        let mut unique = Rc::new(1);
+        let mut shared = Rc::new(2);
+        let other = shared.clone();
+
+        for fa in [&mut unique, &mut shared] {
+            let _ = <RcT as Foldable<DependentClone, DependentClone, T4Of5Hkt, RefMutT>>::fold_while::<
+                _,
+                _,
+                fn((), &mut i32) -> core::ops::ControlFlow<(), ()>,
+                fn((), &mut i32) -> core::ops::ControlFlow<(), ()>,
+                fn((), &mut i32) -> core::ops::ControlFlow<(), ()>,
+                _,
+                fn((), &mut i32) -> core::ops::ControlFlow<(), ()>,
+            >(
+                |i| *i,
+                |_| (),
+                |_, i| {
+                    *i *= 10;
+                    core::ops::ControlFlow::Continue(())
+                },
+                (),
+                fa,
+            );
+        }
+
+        assert_eq!((*unique, *shared, *other), (10, 20, 2));
+
+        let borrowed = 3;
+        let mapped = <CowT as Functor<DependentClone, DependentClone, T4Of5Hkt>>::map::<
+            _,
+            _,
+            fn(i32) -> i32,
+            fn(i32) -> i32,
+            fn(i32) -> i32,
+            _,
+            fn(i32) -> i32,
+        >(|i| *i, |i| *i, |i| i * 2, Borrown::Borrow(&borrowed));
+
+        assert_eq!(*mapped, 6);
+    }
+
+    #[test]
+    fn test_borrowed_fold_and_map_in_place() {
+        type Max<'a> = fn(i32, &'a i32) -> core::ops::ControlFlow<i32, i32>;
+
+        let slice: &[i32] = &[3, 7, 5];
+
+        assert_eq!(
+            <RefT<SliceT> as Foldable<DependentClone, DependentClone, T4Of5Hkt, RefT>>::fold_while::<
+                _,
+                _,
+                Max,
+                Max,
+                Max,
+                _,
+                Max,
+            >(
+                |i| *i,
+                |i| *i,
+                |max, i| core::ops::ControlFlow::Continue(max.max(*i)),
+                i32::MIN,
+                &slice
+            )
+            .converge(),
+            7
+        );
+
+        let mut nested = vec![vec![1, 2], vec![3]];
+
+        <VecT<VecT> as MapInPlace<T4Of5Hkt>>::map_in_place::<
+            _,
+            fn(&mut i32),
+            fn(&mut i32),
+            fn(&mut i32),
+            _,
+            fn(&mut i32),
+        >(|i| *i *= -1, &mut nested);
+
+        assert_eq!(nested, vec![vec![-1, -2], vec![-3]]);
+
+        let mut boxed = Box::new(1);
+        *<BoxT as DerefMutHkt>::deref_mut::<i32>(&mut boxed) += 1;
+
+        assert_eq!(*<BoxT as DerefHkt>::deref::<i32>(&boxed), 2);
+    }
+
+    #[test]
+    fn test_locks_and_cells() {
+        use core::cell::RefCell;
+        use std::sync::{Mutex, RwLock};
+
+        let poisoned = Mutex::new(Ok::<i32, Poisoned>(1));
+        let _ = std::thread::scope(|s| {
+            s.spawn(|| {
+                let _guard = poisoned.lock();
+                panic!("poisoning the lock");
+            })
+            .join()
+        });
+
+        let mapped = <MutexT<ResultT<Poisoned, IdHkt>> as Functor<
+            DependentClone,
+            DependentClone,
+            T4Of5Hkt,
+        >>::map::<_, _, fn(i32) -> i32, fn(i32) -> i32, fn(i32) -> i32, _, fn(i32) -> i32>(
+            |i| *i,
+            |i| *i,
+            |i| i + 1,
+            poisoned,
+        );
+
+        assert_eq!(mapped.into_inner().ok(), Some(Err(Poisoned)));
+
+        let traversed = <RwLockT<ResultT<Poisoned, IdHkt>> as Traversable<
+            DependentClone,
+            DependentClone,
+            T4Of5Hkt,
+        >>::traverse::<
+            _,
+            _,
+            OptionT,
+            fn(i32) -> Option<i32>,
+            fn(i32) -> Option<i32>,
+            fn(i32) -> Option<i32>,
+            _,
+            fn(i32) -> Option<i32>,
+        >(|i| *i, |i| *i, |i| Some(i * 2), RwLock::new(Ok(4)));
+
+        assert_eq!(
+            traversed.map(RwLockT::<ResultT<Poisoned, IdHkt>>::into_result),
+            Some(Ok(Ok(8)))
+        );
+
+        assert_eq!(
+            <RefCellT<VecT> as Functor<DependentClone, DependentClone, T4Of5Hkt>>::map::<
+                _,
+                _,
+                fn(i32) -> i32,
+                fn(i32) -> i32,
+                fn(i32) -> i32,
+                _,
+                fn(i32) -> i32,
+            >(|i| *i, |i| *i, |i| i * 3, RefCell::new(vec![1, 2]))
+            .into_inner(),
+            vec![3, 6]
+        );
+    }
+
+    #[test]
+    fn test_pin_box_future_stack() {
+        use core::pin::Pin;
+
+        type Fut = Pin<Box<dyn Future<Output = i32>>>;
+
+        let fa: Fut = Box::pin(async { 20 });
+
+        let bound = <PinT<BoxT<DynFutureT>> as Monad<DependentClone, DependentClone, T4Of5Hkt>>::bind::<
+            _,
+            _,
+            fn(i32) -> Fut,
+            fn(i32) -> Fut,
+            fn(i32) -> Fut,
+            _,
+            fn(i32) -> Fut,
+        >(|i| *i, |i| *i, fa, |i| Box::pin(async move { i + 1 }));
+
+        let mapped = <PinBoxFutureT as Functor<DependentClone, DependentClone, T4Of5Hkt>>::map::<
+            _,
+            _,
+            fn(i32) -> i32,
+            fn(i32) -> i32,
+            fn(i32) -> i32,
+            _,
+            fn(i32) -> i32,
+        >(|i| *i, |i| *i, |i| i * 2, bound);
+
+        assert_eq!(futures::executor::block_on(mapped), 42);
+    }
+
+    #[test]
+    fn test_send_variants() {
+        use core::pin::Pin;
+
+        type Fut = Pin<Box<dyn Future<Output = i32> + Send>>;
+        type SyncFut = Pin<Box<dyn Future<Output = i32> + Send + Sync>>;
+        type SendOnly = ConstBool<true>;
+
+        let fa = <PinBoxSendFutureT as Pure<DependentClone, SendOnly>>::pure(|i| *i, 20);
+        let fa = <PinBoxSendFutureT as Monad<DependentClone, DependentClone, T4Of5Hkt, SendOnly>>::bind::<
+            _,
+            _,
+            fn(i32) -> Fut,
+            fn(i32) -> Fut,
+            fn(i32) -> Fut,
+            _,
+            fn(i32) -> Fut,
+        >(|i| *i, |i| *i, fa, |i| Box::pin(async move { i + 1 }));
+        let fa = <PinBoxSendFutureT as Functor<DependentClone, DependentClone, T4Of5Hkt, SendOnly>>::map::<
+            _,
+            _,
+            fn(i32) -> i32,
+            fn(i32) -> i32,
+            fn(i32) -> i32,
+            _,
+            fn(i32) -> i32,
+        >(|i| *i, |i| *i, |i| i * 2, fa);
+
+        let offset = 2;
+        let fb: SyncFut = Box::pin(async { 40 });
+        let fb = <PinBoxSendSyncFutureT as Applicative<
+            DependentClone,
+            DependentClone,
+            T4Of5Hkt,
+            SendOnly,
+            ConstBool<true>,
+        >>::apply::<_, _, fn(i32) -> i32, fn(i32) -> i32, fn(i32) -> i32, _, fn(i32) -> i32>(
+            |i| *i,
+            |i| *i,
+            Box::pin(async move { move |i| i + offset }),
+            fb,
+        );
+
+        type OptionFut<A> = Pin<Box<dyn Future<Output = Option<A>> + Send>>;
+        let option_apply = |ff: OptionFut<fn(i32) -> i32>, fa: OptionFut<i32>| {
+            <PinBoxSendFutureT<OptionT> as Applicative<
+                DependentClone,
+                DependentClone,
+                T4Of5Hkt,
+                SendOnly,
+            >>::apply::<_, _, fn(i32) -> i32, fn(i32) -> i32, fn(i32) -> i32, _, fn(i32) -> i32>(
+                |i| *i,
+                |i| *i,
+                ff,
+                fa,
+            )
+        };
+        let increment: fn(i32) -> i32 = |i| i + 1;
+        let option_some = option_apply(Box::pin(async move { Some(increment) }), Box::pin(async { Some(41) }));
+        let option_none = option_apply(Box::pin(async move { Some(increment) }), Box::pin(async { None }));
+
+        type ResultFut<A> = Pin<Box<dyn Future<Output = Result<A, i32>> + Send>>;
+        let result_err = <PinBoxSendFutureT<ResultT<i32, IdHkt>> as Applicative<
+            DependentClone,
+            DependentClone,
+            T4Of5Hkt,
+            SendOnly,
+        >>::apply::<_, _, fn(i32) -> i32, fn(i32) -> i32, fn(i32) -> i32, _, fn(i32) -> i32>(
+            |i| *i,
+            |i| *i,
+            Box::pin(async { Err(7) }) as ResultFut<fn(i32) -> i32>,
+            Box::pin(async { Ok(1) }) as ResultFut<i32>,
+        );
+
+        let iter: Box<dyn Iterator<Item = i32> + Send> = Box::new(1..=3);
+        let iter = <BoxT<DynSendIteratorT> as Functor<
+            DependentClone,
+            DependentClone,
+            T4Of5Hkt,
+            SendOnly,
+        >>::map::<_, _, fn(i32) -> i32, fn(i32) -> i32, fn(i32) -> i32, _, fn(i32) -> i32>(
+            |i| *i,
+            |i| *i,
+            |i| i * 10,
+            iter,
+        );
+
+        let (future_output, iter_sum) = std::thread::spawn(move || {
+            let sum = <BoxT<DynSendIteratorT> as Foldable<DependentClone, DependentClone, T4Of5Hkt>>::fold_while::<
+                _,
+                _,
+                fn(i32, i32) -> core::ops::ControlFlow<i32, i32>,
+                fn(i32, i32) -> core::ops::ControlFlow<i32, i32>,
+                fn(i32, i32) -> core::ops::ControlFlow<i32, i32>,
+                _,
+                fn(i32, i32) -> core::ops::ControlFlow<i32, i32>,
+            >(
+                |i| *i,
+                |i| *i,
+                |sum, i| core::ops::ControlFlow::Continue(sum + i),
+                0,
+                iter,
+            )
+            .converge();
+
+            assert_eq!(futures::executor::block_on(option_some), Some(42));
+            assert_eq!(futures::executor::block_on(option_none), None);
+            assert_eq!(futures::executor::block_on(result_err), Err(7));
+
+            (futures::executor::block_on(fa) + futures::executor::block_on(fb), sum)
+        })
+        .join()
+        .expect("thread should not panic");
+
+        assert_eq!(future_output, 84);
+        assert_eq!(iter_sum, 60);
+    }
+
+    #[test]
+    fn test_pin_box_stream() {
+        use core::pin::Pin;
+        use futures::{Stream, StreamExt, stream};
+
+        type S = Pin<Box<dyn Stream<Item = i32>>>;
+
+        let bound = <PinBoxStreamT as Monad<DependentClone, DependentClone, T4Of5Hkt>>::bind::<
+            _,
+            _,
+            fn(i32) -> S,
+            fn(i32) -> S,
+            fn(i32) -> S,
+            _,
+            fn(i32) -> S,
+        >(
+            |i| *i,
+            |i| *i,
+            Box::pin(stream::iter([1, 2])),
+            |i| Box::pin(stream::iter([i, i * 10])),
+        );
+
+        let mapped = <PinBoxStreamT as Functor<DependentClone, DependentClone, T4Of5Hkt>>::map::<
+            _,
+            _,
+            fn(i32) -> i32,
+            fn(i32) -> i32,
+            fn(i32) -> i32,
+            _,
+            fn(i32) -> i32,
+        >(|i| *i, |i| *i, |i| i + 1, bound);
+
+        assert_eq!(
+            futures::executor::block_on(mapped.collect::<Vec<_>>()),
+            vec![2, 11, 3, 21]
+        );
+
+        let sum_until_negative = PinBoxStreamT::<IdHkt>::fold_while::<
+            DependentClone,
+            DependentClone,
+            T4Of5Hkt,
+            _,
+            _,
+            fn(i32, i32) -> core::ops::ControlFlow<i32, i32>,
+            fn(i32, i32) -> core::ops::ControlFlow<i32, i32>,
+            fn(i32, i32) -> core::ops::ControlFlow<i32, i32>,
+            _,
+            fn(i32, i32) -> core::ops::ControlFlow<i32, i32>,
+        >(
+            |i| *i,
+            |i| *i,
+            |sum, i| {
+                if i < 0 {
+                    core::ops::ControlFlow::Break(sum)
+                } else {
+                    core::ops::ControlFlow::Continue(sum + i)
+                }
+            },
+            0,
+            Box::pin(stream::iter([1, 2, -1, 4])),
+        );
+
+        assert_eq!(
+            futures::executor::block_on(sum_until_negative),
+            core::ops::ControlFlow::Break(3)
+        );
+    }
+
+    #[test]
+    fn test_pin_box_future_concurrent_traverse() {
+        use alloc::rc::Rc;
+        use core::{cell::Cell, pin::Pin, task::Poll};
+
+        type Fut = Pin<Box<dyn Future<Output = i32>>>;
+
+        let started = Rc::new(Cell::new(0));
+
+        // Each future records that it started, then yields once before reading how many others have started
+        let f = move |i: i32| -> Fut {
+            let started = started.clone();
+            Box::pin(async move {
+                started.set(started.get() + 1);
+
+                let mut yielded = false;
+                futures::future::poll_fn(|cx| {
+                    if yielded {
+                        Poll::Ready(())
+                    } else {
+                        yielded = true;
+                        cx.waker().wake_by_ref();
+                        Poll::Pending
+                    }
+                })
+                .await;
+
+                started.get() * 10 + i
+            })
+        };
+
+        let traversed = <VecT as Traversable<DependentClone, DependentClone, T4Of5Hkt>>::traverse::<
+            _,
+            _,
+            PinBoxFutureT,
+            fn(i32) -> Fut,
+            fn(i32) -> Fut,
+            fn(i32) -> Fut,
+            _,
+            fn(i32) -> Fut,
+        >(|i| *i, |i| *i, f.clone(), vec![1, 2, 3]);
+
+        assert_eq!(futures::executor::block_on(traversed), vec![31, 32, 33]);
+
+        let traversed = <OptionT as Traversable<DependentClone, DependentClone, T4Of5Hkt>>::traverse::<
+            _,
+            _,
+            PinBoxFutureT,
+            fn(i32) -> Fut,
+            fn(i32) -> Fut,
+            fn(i32) -> Fut,
+            _,
+            fn(i32) -> Fut,
+        >(|i| *i, |i| *i, f, Some(4));
+
+        assert_eq!(futures::executor::block_on(traversed), Some(44));
+    }
+
+    #[test]
+    fn test_pin_box_future_traverse_all() {
+        use core::pin::Pin;
+
+        type Fut = Pin<Box<dyn Future<Output = i32>>>;
+
+        let traversed = PinBoxFutureT::<VecT>::traverse_all::<
+            DependentClone,
+            T4Of5Hkt,
+            _,
+            _,
+            fn(i32) -> Fut,
+            fn(i32) -> Fut,
+            fn(i32) -> Fut,
+            _,
+            fn(i32) -> Fut,
+        >(|i| *i, |i| *i, |i| Box::pin(async move { i * 2 }), (0..20_000).collect());
+
+        let traversed = futures::executor::block_on(traversed);
+        assert_eq!(traversed.len(), 20_000);
+        assert_eq!(traversed[19_999], 39_998);
+
+        let sequenced = PinBoxFutureT::<VecT>::sequence_all::<DependentClone, _>(
+            |i| *i,
+            (0..20_000).map(|i| Box::pin(async move { i }) as Fut).collect(),
+        );
+
+        assert_eq!(
+            futures::executor::block_on(sequenced).into_iter().sum::<i32>(),
+            (0..20_000).sum()
+        );
+    }
+
+    #[test]
+    fn test_traverse_concurrent() {
+        use alloc::rc::Rc;
+        use core::{cell::Cell, pin::Pin, task::Poll};
+
+        type Fut<T> = Pin<Box<dyn Future<Output = T>>>;
+
+        // Yields `n` times, so later elements finish first
+        fn delay(n: i32) -> impl Future<Output = ()> {
+            let mut remaining = n;
+            futures::future::poll_fn(move |cx| {
+                if remaining <= 0 {
+                    Poll::Ready(())
+                } else {
+                    remaining -= 1;
+                    cx.waker().wake_by_ref();
+                    Poll::Pending
+                }
+            })
+        }
+
+        let in_flight = Rc::new(Cell::new(0));
+        let max_in_flight = Rc::new(Cell::new(0));
+
+        let f = {
+            let in_flight = in_flight.clone();
+            let max_in_flight = max_in_flight.clone();
+            move |i: i32| -> Fut<i32> {
+                let in_flight = in_flight.clone();
+                let max_in_flight = max_in_flight.clone();
+                Box::pin(async move {
+                    in_flight.set(in_flight.get() + 1);
+                    max_in_flight.set(max_in_flight.get().max(in_flight.get()));
+                    delay(10 - i).await;
+                    in_flight.set(in_flight.get() - 1);
+                    i * 2
+                })
+            }
+        };
+
+        let traversed = PinBoxFutureT::<VecT>::traverse_concurrent::<
+            DependentClone,
+            T4Of5Hkt,
+            _,
+            _,
+            fn(i32) -> Fut<i32>,
+            fn(i32) -> Fut<i32>,
+            fn(i32) -> Fut<i32>,
+            _,
+            fn(i32) -> Fut<i32>,
+        >(|i| *i, |i| *i, NonZeroUsize::new(2).unwrap(), f, vec![1, 2, 3, 4, 5]);
+
+        assert_eq!(futures::executor::block_on(traversed), vec![2, 4, 6, 8, 10]);
+        assert_eq!(max_in_flight.get(), 2);
+
+        let started = Rc::new(Cell::new(0));
+
+        let try_traversed = PinBoxFutureT::<ResultT<i32, VecT>>::try_traverse_concurrent::<
+            DependentClone,
+            T4Of5Hkt,
+            _,
+            _,
+            fn(i32) -> Fut<Result<i32, i32>>,
+            fn(i32) -> Fut<Result<i32, i32>>,
+            fn(i32) -> Fut<Result<i32, i32>>,
+            _,
+            fn(i32) -> Fut<Result<i32, i32>>,
+        >(
+            |i| *i,
+            |i| *i,
+            NonZeroUsize::MIN,
+            {
+                let started = started.clone();
+                move |i: i32| -> Fut<Result<i32, i32>> {
+                    let started = started.clone();
+                    Box::pin(async move {
+                        started.set(started.get() + 1);
+                        if i < 0 { Err(i) } else { Ok(i) }
+                    })
+                }
+            },
+            vec![1, -2, 3],
+        );
+
+        assert_eq!(futures::executor::block_on(try_traversed), Err(-2));
+        assert_eq!(started.get(), 2);
+    }
+
+    #[test]
+    fn test_traverse_concurrent_min_limit() {
+        let traversed = PinBoxFutureT::<VecT>::traverse_concurrent::<
+            DependentClone,
+            T4Of5Hkt,
+            _,
+            _,
+            fn(i32) -> PinBoxFuture<i32>,
+            fn(i32) -> PinBoxFuture<i32>,
+            fn(i32) -> PinBoxFuture<i32>,
+            _,
+            fn(i32) -> PinBoxFuture<i32>,
+        >(|i| *i, |i| *i, NonZeroUsize::MIN, |i| Box::pin(async move { i }), vec![1, 2, 3]);
+
+        assert_eq!(futures::executor::block_on(traversed), vec![1, 2, 3]);
+
+        let traversed = PinBoxFutureT::<ResultT<i32, VecT>>::try_traverse_concurrent::<
+            DependentClone,
+            T4Of5Hkt,
+            _,
+            _,
+            fn(i32) -> PinBoxFuture<Result<i32, i32>>,
+            fn(i32) -> PinBoxFuture<Result<i32, i32>>,
+            fn(i32) -> PinBoxFuture<Result<i32, i32>>,
+            _,
+            fn(i32) -> PinBoxFuture<Result<i32, i32>>,
+        >(|i| *i, |i| *i, NonZeroUsize::MIN, |i| Box::pin(async move { Ok(i) }), vec![1, 2, 3]);
+
+        assert_eq!(futures::executor::block_on(traversed), Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_eval_stack_safety() {
+        const DEPTH: u64 = 1_000_000;
+
+        let mut fa = Eval::now(0u64);
+        for _ in 0..DEPTH {
+            fa = <EvalT as Monad<DependentClone, DependentClone, T4Of5Hkt>>::bind::<
+                _,
+                _,
+                fn(u64) -> Eval<'static, u64>,
+                fn(u64) -> Eval<'static, u64>,
+                fn(u64) -> Eval<'static, u64>,
+                _,
+                fn(u64) -> Eval<'static, u64>,
+            >(|i| *i, |i| *i, fa, |i| Eval::now(i + 1));
+        }
+
+        assert_eq!(fa.run(), DEPTH);
+
+        fn sum_to(n: u64) -> Eval<'static, u64> {
+            if n == 0 {
+                Eval::now(0)
+            } else {
+                Eval::defer(move || sum_to(n - 1)).map(move |sum| sum + n)
+            }
+        }
+
+        assert_eq!(sum_to(DEPTH).run(), DEPTH * (DEPTH + 1) / 2);
+
+        let calls = core::cell::Cell::new(0);
+        let lazy = Eval::later(|| {
+            calls.set(calls.get() + 1);
+            2
+        });
+
+        let mapped = <EvalT as Functor<DependentClone, DependentClone, T4Of5Hkt>>::map::<
+            _,
+            _,
+            fn(i32) -> i32,
+            fn(i32) -> i32,
+            fn(i32) -> i32,
+            _,
+            fn(i32) -> i32,
+        >(|i| *i, |i| *i, |i| i * 21, lazy);
+
+        assert_eq!(calls.get(), 0);
+        assert_eq!(mapped.run(), 42);
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn test_eval_clone_and_drop() {
+        let calls = core::cell::Cell::new(0);
+        let count = || {
+            calls.set(calls.get() + 1);
+            calls.get()
+        };
+
+        let always = Eval::always(count);
+        assert_eq!(always.clone().run(), 1);
+        assert_eq!(always.run(), 2);
+
+        calls.set(0);
+        let later = Eval::later(count).map(|i| i * 10);
+        assert_eq!(later.clone().run(), 10);
+        assert_eq!(later.clone().run(), 10);
+        assert_eq!(later.run(), 10);
+        assert_eq!(calls.get(), 1);
+
+        let mut unrun = Eval::now(0u64);
+        for _ in 0..1_000_000 {
+            unrun = unrun.flat_map(|i| Eval::now(i + 1));
+        }
+        drop(unrun.clone());
+        drop(unrun);
+    }
+
+    #[test]
+    fn test_eval_t_monad() {
+        fn halve(i: u32) -> Eval<'static, Option<u32>> {
+            Eval::later(move || i.is_multiple_of(2).then_some(i / 2))
+        }
+
+        let bind = |fa| {
+            <EvalT<OptionT> as Monad<DependentClone, DependentClone, T4Of5Hkt>>::bind::<
+                _,
+                _,
+                fn(u32) -> Eval<'static, Option<u32>>,
+                fn(u32) -> Eval<'static, Option<u32>>,
+                fn(u32) -> Eval<'static, Option<u32>>,
+                _,
+                fn(u32) -> Eval<'static, Option<u32>>,
+            >(|i| *i, |i| *i, fa, halve)
+        };
+
+        assert_eq!(bind(Eval::now(Some(8))).run(), Some(4));
+        assert_eq!(bind(bind(Eval::now(Some(6)))).run(), None);
+        assert_eq!(bind(Eval::now(None)).run(), None);
+    }
+
+    #[test]
+    fn test_lazy_shares_memo() {
+        let calls = alloc::rc::Rc::new(core::cell::Cell::new(0));
+        let base = Lazy::new({
+            let calls = calls.clone();
+            move || {
+                calls.set(calls.get() + 1);
+                20
+            }
+        });
+
+        let mapped = <LazyT as Functor<DependentClone, DependentClone, T4Of5Hkt>>::map::<
+            _,
+            _,
+            fn(i32) -> i32,
+            fn(i32) -> i32,
+            fn(i32) -> i32,
+            _,
+            fn(i32) -> i32,
+        >(|i| *i, |i| *i, |i| i + 1, base.clone());
+        let bound = <LazyT as Monad<DependentClone, DependentClone, T4Of5Hkt>>::bind::<
+            _,
+            _,
+            fn(i32) -> Lazy<'static, i32>,
+            fn(i32) -> Lazy<'static, i32>,
+            fn(i32) -> Lazy<'static, i32>,
+            _,
+            fn(i32) -> Lazy<'static, i32>,
+        >(|i| *i, |i| *i, mapped, |i| Lazy::new(move || i * 2));
+        let shared = <LazyT as CloneK<DependentClone>>::clone(|i: &i32| *i, &bound);
+
+        assert_eq!(calls.get(), 0);
+        assert!(!shared.is_evaluated());
+        assert_eq!(*bound.force(), 42);
+        assert!(shared.is_evaluated());
+        assert_eq!(*shared.force(), 42);
+        assert_eq!(*base.force(), 20);
+        assert_eq!(calls.get(), 1);
+
+        type SendSync = ConstBool<true>;
+
+        let sync_base = SyncLazy::new(|| 1);
+        let sync_mapped = <SyncLazyT as Functor<
+            DependentClone,
+            DependentClone,
+            T4Of5Hkt,
+            SendSync,
+            SendSync,
+        >>::map::<_, _, fn(i32) -> i32, fn(i32) -> i32, fn(i32) -> i32, _, fn(i32) -> i32>(
+            |i| *i,
+            |i| *i,
+            |i| i + 1,
+            sync_base,
+        );
+        let sync_applied = <SyncLazyT as Applicative<
+            DependentClone,
+            DependentClone,
+            T4Of5Hkt,
+            SendSync,
+            SendSync,
+        >>::apply::<_, _, fn(i32) -> i32, fn(i32) -> i32, fn(i32) -> i32, _, fn(i32) -> i32>(
+            |i| *i,
+            |i| *i,
+            SyncLazy::from_value(|i| i * 10),
+            sync_mapped.clone(),
+        );
+        let sync_bound = <SyncLazyT as Monad<
+            DependentClone,
+            DependentClone,
+            T4Of5Hkt,
+            SendSync,
+            SendSync,
+        >>::bind::<
+            _,
+            _,
+            fn(i32) -> SyncLazy<'static, i32>,
+            fn(i32) -> SyncLazy<'static, i32>,
+            fn(i32) -> SyncLazy<'static, i32>,
+            _,
+            fn(i32) -> SyncLazy<'static, i32>,
+        >(|i| *i, |i| *i, sync_applied, |i| SyncLazy::new(move || i + 1));
+        let sent = sync_bound.clone();
+
+        assert_eq!(std::thread::spawn(move || *sent.force()).join().unwrap(), 21);
+        assert!(sync_bound.is_evaluated());
+        assert!(sync_mapped.is_evaluated());
+    }
+
+    #[test]
+    fn test_validated_accumulates_errors() {
+        fn check(i: i32) -> Validated<i32, NonEmptyVec<i32>> {
+            if i > 0 {
+                Validated::Valid(i * 2)
+            } else {
+                Validated::Invalid(NonEmptyVec::new(i))
+            }
+        }
+
+        let traverse = |fa: Vec<i32>| {
+            <VecT as Traversable<DependentClone, DependentClone, T4Of5Hkt>>::traverse::<
+                _,
+                _,
+                ValidatedT<NonEmptyVec<i32>, IdHkt>,
+                fn(i32) -> Validated<i32, NonEmptyVec<i32>>,
+                fn(i32) -> Validated<i32, NonEmptyVec<i32>>,
+                fn(i32) -> Validated<i32, NonEmptyVec<i32>>,
+                _,
+                fn(i32) -> Validated<i32, NonEmptyVec<i32>>,
+            >(|i| *i, |i| *i, check, fa)
+        };
+
+        assert_eq!(traverse(vec![1, 2, 3]), Validated::Valid(vec![2, 4, 6]));
+        assert_eq!(
+            traverse(vec![1, -2, 3, 0, -5]),
+            Validated::Invalid(NonEmptyVec {
+                head: -2,
+                tail: vec![0, -5]
+            })
+        );
+
+        let result = <ValidatedT<NonEmptyVec<i32>, IdHkt>>::into_result(traverse(vec![-1]));
+        assert_eq!(result, Err(NonEmptyVec::new(-1)));
+        assert_eq!(
+            <ValidatedT<NonEmptyVec<i32>, VecT>>::from_result(Ok(vec![1])),
+            Validated::Valid(vec![1])
+        );
+
+        let messages = <VecT as Traversable<DependentClone, DependentClone, T4Of5Hkt>>::traverse::<
+            _,
+            _,
+            ValidatedT<Vec<String>, IdHkt>,
+            fn(i32) -> Validated<i32, Vec<String>>,
+            fn(i32) -> Validated<i32, Vec<String>>,
+            fn(i32) -> Validated<i32, Vec<String>>,
+            _,
+            fn(i32) -> Validated<i32, Vec<String>>,
+        >(
+            |i| *i,
+            |i| *i,
+            |i| match check(i) {
+                Validated::Valid(i) => Validated::Valid(i),
+                Validated::Invalid(_) => Validated::Invalid(vec![format!("{i} is not positive")]),
+            },
+            vec![-1, 2, -3],
+        );
+        assert_eq!(
+            messages,
+            Validated::Invalid(vec![
+                "-1 is not positive".to_string(),
+                "-3 is not positive".to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn test_these_accumulates_left() {
+        type Warned = TheseT<NonEmptyVec<&'static str>, IdHkt>;
+
+        fn half(i: i32) -> These<NonEmptyVec<&'static str>, i32> {
+            match i {
+                _ if i % 2 != 0 => These::This(NonEmptyVec::new("odd")),
+                _ if i > 100 => These::Both(NonEmptyVec::new("large"), i / 2),
+                _ => These::That(i / 2),
+            }
+        }
+
+        let bind = |fa| {
+            <Warned as Monad<DependentClone, DependentClone, T4Of5Hkt>>::bind::<
+                _,
+                _,
+                fn(i32) -> These<NonEmptyVec<&'static str>, i32>,
+                fn(i32) -> These<NonEmptyVec<&'static str>, i32>,
+                fn(i32) -> These<NonEmptyVec<&'static str>, i32>,
+                _,
+                fn(i32) -> These<NonEmptyVec<&'static str>, i32>,
+            >(|i| *i, |i| *i, fa, half)
+        };
+
+        assert_eq!(bind(bind(These::That(40))), These::That(10));
+        assert_eq!(
+            bind(bind(These::That(400))),
+            These::Both(
+                NonEmptyVec {
+                    head: "large",
+                    tail: vec!["large"]
+                },
+                100
+            )
+        );
+        assert_eq!(
+            bind(bind(These::That(202))),
+            These::This(NonEmptyVec {
+                head: "large",
+                tail: vec!["odd"]
+            })
+        );
+
+        let traversed = <Warned as Traversable<DependentClone, DependentClone, T4Of5Hkt>>::traverse::<
+            _,
+            _,
+            OptionT,
+            fn(i32) -> Option<i32>,
+            fn(i32) -> Option<i32>,
+            fn(i32) -> Option<i32>,
+            _,
+            fn(i32) -> Option<i32>,
+        >(|i| *i, |i| *i, |i| i.checked_add(1), These::Both(NonEmptyVec::new("w"), 1));
+
+        assert_eq!(traversed, Some(These::Both(NonEmptyVec::new("w"), 2)));
+        let bimapped = <Warned as Bifunctor<DependentClone, DependentClone, T4Of5Hkt>>::bimap::<
+            _,
+            _,
+            _,
+            fn(i32) -> i32,
+            fn(i32) -> i32,
+            fn(i32) -> i32,
+            _,
+            fn(i32) -> i32,
+        >(
+            |i| *i,
+            |i| *i,
+            |l| l.head.len(),
+            |i| i * 2,
+            These::Both(NonEmptyVec::new("abc"), 4),
+        );
+
+        assert_eq!(bimapped, These::Both(3, 8));
+
+        type Logged = TheseT<Vec<String>, IdHkt>;
+
+        let logged = <Logged as Monad<DependentClone, DependentClone, T4Of5Hkt>>::bind::<
+            _,
+            _,
+            fn(i32) -> These<Vec<String>, i32>,
+            fn(i32) -> These<Vec<String>, i32>,
+            fn(i32) -> These<Vec<String>, i32>,
+            _,
+            fn(i32) -> These<Vec<String>, i32>,
+        >(
+            |i| *i,
+            |i| *i,
+            These::Both(vec!["parsed".to_string()], 2),
+            |i| These::Both(vec![format!("doubled {i}")], i * 2),
+        );
+        assert_eq!(
+            <Logged as Bifunctor<DependentClone, DependentClone, T4Of5Hkt>>::map_left(
+                |warnings| warnings.len(),
+                logged.clone()
+            ),
+            These::Both(2, 4)
+        );
+        assert_eq!(
+            logged,
+            These::Both(vec!["parsed".to_string(), "doubled 2".to_string()], 4)
+        );
+    }
+
+    #[test]
+    fn test_functor_combinators() {
+        let summed = <VecT as Traversable<DependentClone, DependentClone, T4Of5Hkt>>::traverse::<
+            i32,
+            (),
+            ConstT<i32>,
+            fn(i32) -> i32,
+            fn(i32) -> i32,
+            fn(i32) -> i32,
+            _,
+            fn(i32) -> i32,
+        >(|i| *i, |_| (), |i| i, vec![1, 2, 3, 4]);
+
+        assert_eq!(summed, 10);
+
+        let collected = <VecT as Traversable<DependentClone, DependentClone, T4Of5Hkt>>::traverse::<
+            i32,
+            (),
+            ConstT<Vec<i32>>,
+            fn(i32) -> Vec<i32>,
+            fn(i32) -> Vec<i32>,
+            fn(i32) -> Vec<i32>,
+            _,
+            fn(i32) -> Vec<i32>,
+        >(|i| *i, |_| (), |i| vec![i; i as usize], vec![1, 2, 3]);
+
+        assert_eq!(collected, vec![1, 2, 2, 3, 3, 3]);
+
+        type Pair = ProductT<VecT, OptionT>;
+
+        let mapped = <Pair as Functor<DependentClone, DependentClone, T4Of5Hkt>>::map::<
+            _,
+            _,
+            fn(i32) -> i32,
+            fn(i32) -> i32,
+            fn(i32) -> i32,
+            _,
+            fn(i32) -> i32,
+        >(|i| *i, |i| *i, |i| i * 10, (vec![1, 2], Some(3)));
+
+        assert_eq!(mapped, (vec![10, 20], Some(30)));
+        let folded = <Pair as Foldable<DependentClone, DependentClone, T4Of5Hkt>>::fold_while::<
+            _,
+            _,
+            fn(i32, i32) -> core::ops::ControlFlow<i32, i32>,
+            fn(i32, i32) -> core::ops::ControlFlow<i32, i32>,
+            fn(i32, i32) -> core::ops::ControlFlow<i32, i32>,
+            _,
+            fn(i32, i32) -> core::ops::ControlFlow<i32, i32>,
+        >(
+            |i| *i,
+            |i| *i,
+            |b, a| core::ops::ControlFlow::Continue(b + a),
+            0,
+            mapped.clone(),
+        );
+
+        assert_eq!(folded, core::ops::ControlFlow::Continue(60));
+
+        let traverse = |fa| {
+            <Pair as Traversable<DependentClone, DependentClone, T4Of5Hkt>>::traverse::<
+                _,
+                _,
+                OptionT,
+                fn(i32) -> Option<i32>,
+                fn(i32) -> Option<i32>,
+                fn(i32) -> Option<i32>,
+                _,
+                fn(i32) -> Option<i32>,
+            >(|i| *i, |i| *i, |i| i.checked_sub(15), fa)
+        };
+
+        assert_eq!(traverse(mapped), Some((vec![-5, 5], Some(15))));
+        assert_eq!(traverse((vec![i32::MIN], None)), None);
+
+        let either = <SumT<VecT, OptionT> as Functor<DependentClone, DependentClone, T4Of5Hkt>>::map::<
+            _,
+            _,
+            fn(i32) -> i32,
+            fn(i32) -> i32,
+            fn(i32) -> i32,
+            _,
+            fn(i32) -> i32,
+        >(|i| *i, |i| *i, |i| i + 1, either::Either::Right(Some(1)));
+
+        assert_eq!(either, either::Either::Right(Some(2)));
+    }
+
+    #[test]
+    fn test_coyoneda_and_yoneda_fuse_maps() {
+        use crate::{
+            hkt::{
+                TCloneableOf5,
+                hkt_classification::{self, HktClassification},
+            },
+            marker_classification::TypeGuard,
+        };
+
+        std::thread_local! {
+            static MAPS: core::cell::Cell<usize> = const { core::cell::Cell::new(0) };
+        }
+
+        /// [VecT] counting its calls to [Functor::map].
+        struct CountingVecT;
+
+        impl HktClassification for CountingVecT {
+            type Choice = hkt_classification::OuterHkt;
+        }
+
+        impl<'t> crate::hkt::Hkt<'t> for CountingVecT {
+            type F<'a, A: 'a>
+                = Vec<A>
+            where
+                't: 'a;
+        }
+
+        impl<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: TCloneableOf5<'t>>
+            Functor<'t, ReqIn, ReqOut, ReqF1> for CountingVecT
+        {
+            fn map<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+                clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+                f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+                fa: Vec<A>,
+            ) -> Vec<B>
+            where
+                A: 'a,
+                B: 'a,
+                F1Once: 'a + FnOnce(A) -> B,
+                F1Mut: 'a + FnMut(A) -> B,
+                F1Fn: 'a + Fn(A) -> B,
+                F1Clone: 'a + Fn(A) -> B + Clone,
+                F1Copy: 'a + Fn(A) -> B + Copy,
+                't: 'a,
+            {
+                MAPS.with(|maps| maps.set(maps.get() + 1));
+
+                <VecT as Functor<'t, ReqIn, ReqOut, ReqF1>>::map(clone_a, clone_b, f, fa)
+            }
+        }
+
+        let map = |fa| {
+            <CoyonedaT<CountingVecT, i32> as Functor<DependentClone, DependentClone, T4Of5Hkt>>::map::<
+                _,
+                _,
+                fn(i32) -> i32,
+                fn(i32) -> i32,
+                fn(i32) -> i32,
+                _,
+                fn(i32) -> i32,
+            >(|i| *i, |i| *i, |i| i + 1, fa)
+        };
+
+        let fa = map(map(map(<CoyonedaT<CountingVecT, i32>>::lift(vec![1, 2, 3]))));
+        assert_eq!(MAPS.get(), 0);
+        assert_eq!(
+            <CoyonedaT<CountingVecT, i32>>::lower::<DependentClone, DependentClone, _>(
+                |i| *i,
+                |i| *i,
+                fa
+            ),
+            vec![4, 5, 6]
+        );
+        assert_eq!(MAPS.get(), 1);
+
+        let heap = Coyoneda::lift(alloc::collections::BinaryHeap::from(vec![3, 1, 2]))
+            .map(|i: i32| i * 10)
+            .map(|i| i.to_string());
+        let (heap, f) = heap.into_parts();
+        let mut mapped = heap.into_sorted_vec().into_iter().map(f).collect::<Vec<_>>();
+        mapped.sort();
+        assert_eq!(mapped, vec!["10", "20", "30"]);
+
+        let lifted = <YonedaT<OptionT, String>>::lift::<DependentClone, DependentClone, _>(
+            |i: &i32| *i,
+            Clone::clone,
+            Some(20),
+        );
+        let fa = <YonedaT<OptionT, String> as Functor<DependentClone, DependentClone, T4Of5Hkt>>::map::<
+            _,
+            _,
+            fn(i32) -> i32,
+            fn(i32) -> i32,
+            fn(i32) -> i32,
+            _,
+            fn(i32) -> i32,
+        >(|i| *i, |i| *i, |i| i + 1, lifted)
+        .map(|i| i * 2)
+        .map(|i| i.to_string());
+
+        assert_eq!(<YonedaT<OptionT, String>>::lower(fa), Some("42".to_string()));
+    }
+
+    #[test]
+    fn test_recursion_schemes() {
+        let list = ana::<ListF<u32>, _>(|n| (n > 0).then(|| (n, n - 1)), 4);
+        let to_vec = |list| {
+            cata::<ListF<u32>, Vec<u32>>(
+                |layer| match layer {
+                    None => vec![],
+                    Some((e, mut rest)) => {
+                        rest.insert(0, e);
+                        rest
+                    }
+                },
+                list,
+            )
+        };
+        assert_eq!(to_vec(list.clone()), vec![4, 3, 2, 1]);
+        assert_eq!(cata::<ListF<u32>, u32>(|l| l.map_or(0, |(e, s)| e + s), list.clone()), 10);
+
+        let factorial = hylo::<ListF<u64>, u64, u64>(
+            |l| l.map_or(1, |(e, p)| e * p),
+            |n| (n > 0).then(|| (n, n - 1)),
+            5,
+        );
+        assert_eq!(factorial, 120);
+
+        let shared_rest = para::<ListF<u32>, Option<Fix<ListF<u32>>>>(
+            |layer| layer.map(|(_, (rest, _))| rest),
+            list.clone(),
+        );
+        let Some((_, rest)) = list.layer() else { unreachable!() };
+        assert!(core::ptr::eq(shared_rest.unwrap().layer(), rest.layer()));
+
+        let suffix_lengths = para::<ListF<u32>, Vec<usize>>(
+            |layer| match layer {
+                None => vec![],
+                Some((_, (rest, mut lengths))) => {
+                    lengths.insert(0, to_vec(rest).len());
+                    lengths
+                }
+            },
+            list,
+        );
+        assert_eq!(suffix_lengths, vec![3, 2, 1, 0]);
+
+        let tail = ana::<ListF<u32>, _>(|n| (n < 3).then(|| (n * 100, n + 1)), 1);
+        let spliced = apo::<ListF<u32>, _>(
+            move |n| {
+                (n > 0).then(|| {
+                    (
+                        n,
+                        if n == 2 {
+                            either::Either::Left(tail.clone())
+                        } else {
+                            either::Either::Right(n + 1)
+                        },
+                    )
+                })
+            },
+            1,
+        );
+        assert_eq!(to_vec(spliced), vec![1, 2, 100, 200]);
+
+        let lit = |i| Fix::<ExprF>::new(Expr::Lit(i));
+        let expr = Fix::new(Expr::Add(
+            lit(2),
+            Fix::new(Expr::Mul(lit(3), Fix::new(Expr::Neg(lit(4))))),
+        ));
+        let eval = cata::<ExprF, i64>(
+            |e| match e {
+                Expr::Lit(i) => i,
+                Expr::Neg(a) => -a,
+                Expr::Add(l, r) => l + r,
+                Expr::Mul(l, r) => l * r,
+            },
+            expr,
+        );
+        assert_eq!(eval, -10);
+    }
+
+    #[test]
+    fn test_fix_drop() {
+        // Built in a loop, since `ana` itself recurses as deep as the list.
+        let mut list = Fix::<ListF<u32>>::new(None);
+        for i in 0..1_000_000 {
+            list = Fix::new(Some((i, list)));
+        }
+
+        let shared_tail = list.clone();
+        let list = Fix::<ListF<u32>>::new(Some((0, list)));
+        drop(list);
+        drop(shared_tail);
+    }
+
+    #[test]
+    fn test_optics() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct Point {
+            x: i32,
+            y: i32,
+        }
+
+        #[derive(Debug, Clone, PartialEq)]
+        struct Line {
+            start: Point,
+            end: Point,
+            tags: Vec<String>,
+        }
+
+        #[derive(Debug, Clone, PartialEq)]
+        enum Shape {
+            Line(Line),
+            Dot(Point),
+        }
+
+        let x = field_lens!(Point, x);
+        let start_x: Lens<Line, i32> = field_lens!(Line, start).then(x.clone());
+        let line = Line {
+            start: Point { x: 1, y: 2 },
+            end: Point { x: 3, y: 4 },
+            tags: vec!["a".to_string()],
+        };
+
+        assert_eq!(start_x.view(line.clone()), 1);
+        assert_eq!(start_x.over(|x| x + 10, line.clone()).start, Point { x: 11, y: 2 });
+        assert_eq!(field_lens!(Line, end).set(Point { x: 0, y: 0 }, line.clone()).end.x, 0);
+
+        assert_eq!(
+            x.traverse_of::<OptionT>(|x| (x > 0).then_some(x * 2), Point { x: 5, y: 6 }),
+            Some(Point { x: 10, y: 6 })
+        );
+        assert_eq!(
+            x.traverse_of::<VecT>(|x| vec![x, -x], Point { x: 5, y: 6 }),
+            vec![Point { x: 5, y: 6 }, Point { x: -5, y: 6 }]
+        );
+
+        let tags = field_lens!(Line, tags).compose(<Each>::new());
+        assert_eq!(
+            tags.over(|t| t.to_uppercase(), line.clone()).tags,
+            vec!["A".to_string()]
+        );
+        assert_eq!(
+            tags.traverse_of::<ResultT<usize, IdHkt>>(|t| Err(t.len()), line.clone()),
+            Err(1)
+        );
+
+        let line_prism = variant_prism!(Shape::Line);
+        let shapes = vec![Shape::Line(line.clone()), Shape::Dot(Point { x: 7, y: 8 })];
+        let every_start_x = <Each>::new()
+            .compose(line_prism.clone())
+            .compose(start_x);
+
+        assert_eq!(every_start_x.fold_map_of(|x| x, shapes.clone()), 1);
+        assert_eq!(
+            every_start_x.fold_map_of(|x| x.to_string(), shapes.clone()),
+            "1"
+        );
+        assert_eq!(
+            every_start_x.over(|x| x * 100, shapes.clone())[0],
+            Shape::Line(Line {
+                start: Point { x: 100, y: 2 },
+                ..line.clone()
+            })
+        );
+        assert_eq!(line_prism.preview(shapes[1].clone()), None);
+        assert_eq!(line_prism.review(line.clone()), shapes[0]);
+    }
+
+    #[test]
+    fn test_zipper() {
+        let mut zipper = ZipperT::<IdHkt>::from_vec(vec![1, 2, 3, 4]).unwrap();
+        assert!(!zipper.left());
+        assert!(zipper.right());
+        assert!(zipper.right());
+        zipper.modify(|i| *i *= 10);
+        assert_eq!(zipper.index(), 2);
+        assert_eq!(zipper.clone().into_vec(), vec![1, 2, 30, 4]);
+
+        let mapped = <ZipperT as Functor<DependentClone, DependentClone, T4Of5Hkt>>::map::<
+            _,
+            _,
+            fn(i32) -> i32,
+            fn(i32) -> i32,
+            fn(i32) -> i32,
+            _,
+            fn(i32) -> i32,
+        >(|i| *i, |i| *i, |i| i + 1, zipper.clone());
+        assert_eq!(mapped.focus, 31);
+        assert_eq!(mapped.iter().copied().collect::<Vec<_>>(), vec![2, 3, 31, 5]);
+
+        let sum = <ZipperT as Foldable<DependentClone, DependentClone, T4Of5Hkt>>::fold_while::<
+            _,
+            _,
+            fn(i32, i32) -> FoldWhile<i32>,
+            fn(i32, i32) -> FoldWhile<i32>,
+            fn(i32, i32) -> FoldWhile<i32>,
+            _,
+            fn(i32, i32) -> FoldWhile<i32>,
+        >(|i| *i, |i| *i, |b, a| FoldWhile::Continue(b + a), 0, zipper.clone());
+        assert_eq!(sum, FoldWhile::Continue(37));
+
+        // Sum of each element with its neighbours.
+        let smoothed = <ZipperT as Comonad<DependentClone, DependentClone, T4Of5Hkt>>::extend::<
+            _,
+            _,
+            fn(Zipper<i32>) -> i32,
+            fn(Zipper<i32>) -> i32,
+            fn(Zipper<i32>) -> i32,
+            _,
+            fn(Zipper<i32>) -> i32,
+        >(
+            |i| *i,
+            |i| *i,
+            zipper.clone(),
+            |z: Zipper<i32>| z.left.last().unwrap_or(&0) + z.focus + z.right.last().unwrap_or(&0),
+        );
+        assert_eq!(smoothed.index(), 2);
+        assert_eq!(smoothed.into_vec(), vec![3, 33, 36, 34]);
+        assert_eq!(
+            <ZipperT as Comonad<DependentClone, DependentClone, T4Of5Hkt>>::extract(zipper.clone()),
+            30
+        );
+
+        let halves = <ZipperT as Traversable<DependentClone, DependentClone, T4Of5Hkt>>::traverse::<
+            _,
+            _,
+            OptionT,
+            fn(i32) -> Option<i32>,
+            fn(i32) -> Option<i32>,
+            fn(i32) -> Option<i32>,
+            _,
+            fn(i32) -> Option<i32>,
+        >(|i| *i, |i| *i, |i| (i % 2 == 0).then_some(i / 2), zipper.clone());
+        assert_eq!(halves, None);
+
+        let from_non_empty = Zipper::from(NonEmptyVec {
+            head: 2,
+            tail: vec![4, 6],
+        });
+        let halves = <ZipperT as Traversable<DependentClone, DependentClone, T4Of5Hkt>>::traverse::<
+            _,
+            _,
+            OptionT,
+            fn(i32) -> Option<i32>,
+            fn(i32) -> Option<i32>,
+            fn(i32) -> Option<i32>,
+            _,
+            fn(i32) -> Option<i32>,
+        >(|i| *i, |i| *i, |i| (i % 2 == 0).then_some(i / 2), from_non_empty);
+        assert_eq!(halves.map(Zipper::into_vec), Some(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_distributive_representable() {
+        let columns = <ArrayT<3> as Distributive<DependentClone, VecT>>::distribute(
+            |i| *i,
+            vec![[1, 2, 3], [4, 5, 6]],
+        );
+        assert_eq!(columns, [vec![1, 4], vec![2, 5], vec![3, 6]]);
+        assert_eq!(
+            <ArrayT<2> as Distributive<DependentClone, OptionT>>::distribute(
+                |i| *i,
+                Some([1, 2])
+            ),
+            [Some(1), Some(2)]
+        );
+        assert_eq!(
+            <IdHkt as Distributive<DependentClone, OptionT>>::distribute(|i| *i, Some(1)),
+            Some(1)
+        );
+        assert_eq!(
+            futures::executor::block_on(
+                <IdHkt as Distributive<DependentClone, PinBoxFutureT>>::distribute(
+                    |i| *i,
+                    Box::pin(async { 1 })
+                )
+            ),
+            1
+        );
+
+        let squares = <ArrayT<4> as Representable>::tabulate(|i| i * i);
+        assert_eq!(squares, [0, 1, 4, 9]);
+        assert_eq!(<ArrayT<4> as Representable>::index(squares, 3), 9);
+
+        let pair = <PairT as Representable>::tabulate(|i: either::Either<(), ()>| i.is_right());
+        assert_eq!(pair, (false, true));
+        assert!(<PairT as Representable>::index(pair, either::Either::Right(())));
+        assert_eq!(
+            <PairT as Distributive<DependentClone, VecT>>::distribute(
+                |i| *i,
+                vec![(1, 2), (3, 4)]
+            ),
+            (vec![1, 3], vec![2, 4])
+        );
+
+        let readers = [ReaderT::from_fn(|r: i32| r + 1), ReaderT::from_fn(|r: i32| r * 2)];
+        let reader = <ReaderT<i32> as Distributive<DependentClone, ArrayT<2>>>::distribute(
+            |i| *i,
+            readers,
+        );
+        assert_eq!(<ReaderT<i32> as Representable>::index(reader.clone(), 5), [6, 10]);
+        assert_eq!(ReaderT::run(&reader, -1), [0, -2]);
+
+        let split = <ArrayT<2> as Distributive<DependentClone, ReaderT<i32>>>::distribute(
+            |i| *i,
+            <ReaderT<i32> as Representable>::tabulate(|r| [r, r * r]),
+        );
+        assert_eq!(split.map(|reader| ReaderT::run(&reader, 3)), [3, 9]);
+
+        let local = ReaderT::local(|r: i32| r + 10, ReaderT::ask());
+        assert_eq!(ReaderT::run(&local, 1), 11);
+    }
 }
@@ -0,0 +1,533 @@
+use core::{convert::Infallible, marker::PhantomData};
+
+use alloc::vec::Vec;
+use tap::Pipe;
+
+use crate::{
+    hkt::{
+        Applicative, CloneK, CloneOwnedK, CovariantK, FoldWhile, Foldable, Functor, Hkt,
+        HktUnsized, Monad, Pure, Rfoldable, Semigroup, TCloneableOf5, Traversable,
+        hkt_classification::{self, HktClassification},
+        id::IdHkt,
+        one_of::{NotT1Of5, NotT5Of5, OneOf5Hkt},
+        reference::{RefMutT, RefT},
+        vec::VecT,
+    },
+    marker_classification::{AssertBlankOutput, ConstBool, TypeGuard},
+    utils::sum_size_hints,
+};
+
+const NON_EMPTY: &str = "VecT preserves non-emptiness";
+
+/// A [Vec] with at least one element.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyVec<T> {
+    pub head: T,
+    pub tail: Vec<T>,
+}
+
+impl<T> NonEmptyVec<T> {
+    pub fn new(head: T) -> Self {
+        Self {
+            head,
+            tail: Vec::new(),
+        }
+    }
+
+    pub fn from_vec(vec: Vec<T>) -> Option<Self> {
+        let mut iter = vec.into_iter();
+
+        iter.next().map(|head| Self {
+            head,
+            tail: iter.collect(),
+        })
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        let mut tail = self.tail;
+        tail.insert(0, self.head);
+        tail
+    }
+
+    pub fn as_ref(&self) -> NonEmptyVec<&T> {
+        NonEmptyVec {
+            head: &self.head,
+            tail: self.tail.iter().collect(),
+        }
+    }
+
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> NonEmptyVec<U> {
+        NonEmptyVec {
+            head: f(self.head),
+            tail: self.tail.into_iter().map(f).collect(),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        core::iter::once(&self.head).chain(self.tail.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        core::iter::once(&mut self.head).chain(self.tail.iter_mut())
+    }
+
+    /// [Iterator::reduce] without the [Option].
+    pub fn reduce(self, f: impl FnMut(T, T) -> T) -> T {
+        self.tail.into_iter().fold(self.head, f)
+    }
+}
+
+impl<T> IntoIterator for NonEmptyVec<T> {
+    type Item = T;
+    type IntoIter = core::iter::Chain<core::iter::Once<T>, alloc::vec::IntoIter<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        core::iter::once(self.head).chain(self.tail)
+    }
+}
+
+/// Concatenation, e.g. to accumulate errors in [ValidatedT](crate::hkt::validated::ValidatedT).
+impl<T> Semigroup for NonEmptyVec<T> {
+    fn combine(mut self, other: Self) -> Self {
+        self.tail.extend(other);
+        self
+    }
+}
+
+/// [NonEmptyVec] of `TInner` layers.
+///
+/// [Applicative], [Monad] and [Traversable] go through [VecT], whose results are non-empty again for non-empty inputs.
+pub struct NonEmptyVecT<TInner = IdHkt>(Infallible, PhantomData<TInner>);
+
+impl<TInner> NonEmptyVecT<TInner> {
+    /// Returns [None] if `fa` is empty.
+    pub fn from_vec<'t, 'a, A>(
+        fa: <VecT<TInner> as Hkt<'t>>::F<'a, A>,
+    ) -> Option<<Self as Hkt<'t>>::F<'a, A>>
+    where
+        TInner: Hkt<'t>,
+        A: 'a,
+        't: 'a,
+    {
+        NonEmptyVec::from_vec(fa)
+    }
+
+    pub fn into_vec<'t, 'a, A>(
+        fa: <Self as Hkt<'t>>::F<'a, A>,
+    ) -> <VecT<TInner> as Hkt<'t>>::F<'a, A>
+    where
+        TInner: Hkt<'t>,
+        A: 'a,
+        't: 'a,
+    {
+        fa.into_vec()
+    }
+}
+
+impl NonEmptyVecT {
+    /// Folds without an initial value.
+    pub fn reduce<'a, A: 'a>(f: impl FnMut(A, A) -> A, fa: NonEmptyVec<A>) -> A {
+        fa.reduce(f)
+    }
+}
+
+impl<'t, TInner: Hkt<'t>> Hkt<'t> for NonEmptyVecT<TInner> {
+    type F<'a, A: 'a>
+        = NonEmptyVec<TInner::F<'a, A>>
+    where
+        't: 'a;
+}
+
+impl<'t, TInner: HktUnsized<'t>> HktUnsized<'t> for NonEmptyVecT<TInner> {
+    type FUnsized<'a, A: 'a + ?Sized>
+        = NonEmptyVec<TInner::FUnsized<'a, A>>
+    where
+        't: 'a;
+}
+
+impl<TInner> HktClassification for NonEmptyVecT<TInner> {
+    type Choice = hkt_classification::OuterHkt;
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: TCloneableOf5<'t>,
+    TInner: Functor<'t, ReqIn, ReqOut, ReqF1>,
+> Functor<'t, ReqIn, ReqOut, ReqF1> for NonEmptyVecT<TInner>
+{
+    fn map<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> B,
+        F1Mut: 'a + FnMut(A) -> B,
+        F1Fn: 'a + Fn(A) -> B,
+        F1Clone: 'a + Fn(A) -> B + Clone,
+        F1Copy: 'a + Fn(A) -> B + Copy,
+        't: 'a,
+    {
+        let f = ReqF1::arbitrary_uncloneable(f, PhantomData::<fn(A) -> B>);
+
+        fa.map(move |x| {
+            TInner::map(
+                clone_a.clone(),
+                clone_b.clone(),
+                ReqF1::clone_one_of_5(&f),
+                x,
+            )
+        })
+    }
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+    TInner: Foldable<'t, ReqIn, ReqOut, ReqF1>,
+> Foldable<'t, ReqIn, ReqOut, ReqF1> for NonEmptyVecT<TInner>
+{
+    fn fold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+        f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: Self::F<'a, A>,
+    ) -> FoldWhile<B>
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, A) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, A) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, A) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, A) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, A) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        let mut f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, A) -> FoldWhile<B>>);
+
+        fa.into_iter().try_fold(init, move |b, ka| {
+            let f_ref = ReqF1::as_mut(&mut f)
+                .pipe(|f| map_one_of_5_with!(ReqF1, f, |mut f| |b, a| f(b, a)));
+
+            TInner::fold_while(clone_a.clone(), clone_b.clone(), f_ref, b, ka)
+        })
+    }
+
+    fn size_hint<'a, A>(s: &Self::F<'a, A>) -> (usize, Option<usize>)
+    where
+        't: 'a,
+    {
+        sum_size_hints(s.iter().map(|x| TInner::size_hint(x)))
+    }
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+    TInner: Foldable<'t, ReqIn, ReqOut, ReqF1, RefT>,
+> Foldable<'t, ReqIn, ReqOut, ReqF1, RefT> for NonEmptyVecT<TInner>
+{
+    fn fold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'f + Fn(&A) -> <ReqIn>::Output<'a, A> + Clone,
+        clone_b: impl 'f + Fn(&B) -> <ReqOut>::Output<'b, B> + Clone,
+        f: <ReqF1>::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: <RefT as Hkt<'t>>::F<'a, Self::F<'a, A>>,
+    ) -> FoldWhile<B>
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        let mut f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, &'a A) -> FoldWhile<B>>);
+
+        fa.iter().try_fold(init, move |b, ka| {
+            let f_ref = ReqF1::as_mut(&mut f)
+                .pipe(|f| map_one_of_5_with!(ReqF1, f, |mut f| |b, a| f(b, a)));
+
+            TInner::fold_while(clone_a.clone(), clone_b.clone(), f_ref, b, ka)
+        })
+    }
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+    TInner: Foldable<'t, ReqIn, ReqOut, ReqF1, RefMutT>,
+> Foldable<'t, ReqIn, ReqOut, ReqF1, RefMutT> for NonEmptyVecT<TInner>
+{
+    fn fold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'f + Fn(&A) -> <ReqIn>::Output<'a, A> + Clone,
+        clone_b: impl 'f + Fn(&B) -> <ReqOut>::Output<'b, B> + Clone,
+        f: <ReqF1>::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: <RefMutT as Hkt<'t>>::F<'a, Self::F<'a, A>>,
+    ) -> FoldWhile<B>
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, <RefMutT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, <RefMutT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, <RefMutT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, <RefMutT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, <RefMutT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        let mut f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, &'a mut A) -> FoldWhile<B>>);
+
+        fa.iter_mut().try_fold(init, move |b, ka| {
+            let f_ref = ReqF1::as_mut(&mut f)
+                .pipe(|f| map_one_of_5_with!(ReqF1, f, |mut f| |b, a| f(b, a)));
+
+            TInner::fold_while(clone_a.clone(), clone_b.clone(), f_ref, b, ka)
+        })
+    }
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+    TInner: Rfoldable<'t, ReqIn, ReqOut, ReqF1>,
+> Rfoldable<'t, ReqIn, ReqOut, ReqF1> for NonEmptyVecT<TInner>
+{
+    fn rfold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+        f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: Self::F<'a, A>,
+    ) -> FoldWhile<B>
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, A) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, A) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, A) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, A) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, A) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        let mut f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, A) -> FoldWhile<B>>);
+
+        fa.into_vec()
+            .into_iter()
+            .rev()
+            .try_fold(init, move |b, ka| {
+                let f_ref = ReqF1::as_mut(&mut f)
+                    .pipe(|f| map_one_of_5_with!(ReqF1, f, |mut f| |b, a| f(b, a)));
+
+                TInner::rfold_while(clone_a.clone(), clone_b.clone(), f_ref, b, ka)
+            })
+    }
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, TInner: Pure<'t, ReqIn>> Pure<'t, ReqIn> for NonEmptyVecT<TInner> {
+    fn pure<'a, A>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        a: A,
+    ) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        TInner::pure(clone_a, a).pipe(NonEmptyVec::new)
+    }
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: TCloneableOf5<'t>,
+    TInner: Applicative<'t, ReqIn, ReqOut, ReqF1> + CloneK<'t, ReqIn>,
+> Applicative<'t, ReqIn, ReqOut, ReqF1> for NonEmptyVecT<TInner>
+{
+    fn apply<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        ff: Self::F<'a, <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> B,
+        F1Mut: 'a + FnMut(A) -> B,
+        F1Fn: 'a + Fn(A) -> B,
+        F1Clone: 'a + Fn(A) -> B + Clone,
+        F1Copy: 'a + Fn(A) -> B + Copy,
+        't: 'a,
+    {
+        <VecT<TInner> as Applicative<'t, ReqIn, ReqOut, ReqF1>>::apply(
+            clone_a,
+            clone_b,
+            ff.into_vec(),
+            fa.into_vec(),
+        )
+        .pipe(NonEmptyVec::from_vec)
+        .expect(NON_EMPTY)
+    }
+}
+
+impl<
+    't,
+    ReqF1: TCloneableOf5<'t> + NotT5Of5<'t>,
+    TInner: Monad<'t, ConstBool<true>, ConstBool<true>, ReqF1>
+        + Traversable<'t, ConstBool<true>, ConstBool<true>, ReqF1>
+        + Functor<'t, ConstBool<true>, ConstBool<false>, ReqF1>
+        + CloneK<'t, ConstBool<true>>,
+> Monad<'t, ConstBool<true>, ConstBool<true>, ReqF1> for NonEmptyVecT<TInner>
+{
+    fn bind<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> A + Clone,
+        clone_b: impl 'a + Fn(&B) -> B + Clone,
+        fa: Self::F<'a, A>,
+        f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> Self::F<'a, B>,
+        F1Mut: 'a + FnMut(A) -> Self::F<'a, B>,
+        F1Fn: 'a + Fn(A) -> Self::F<'a, B>,
+        F1Clone: 'a + Fn(A) -> Self::F<'a, B> + Clone,
+        F1Copy: 'a + Fn(A) -> Self::F<'a, B> + Copy,
+        't: 'a,
+    {
+        let f = map_one_of_5_with!(ReqF1, f, |mut f| move |a: A| f(a).into_vec())
+            .pipe(|f| ReqF1::arbitrary_t5(f, PhantomData::<fn(A) -> Vec<TInner::F<'a, B>>>));
+
+        <VecT<TInner> as Monad<'t, ConstBool<true>, ConstBool<true>, ReqF1>>::bind(
+            clone_a,
+            clone_b,
+            fa.into_vec(),
+            f,
+        )
+        .pipe(NonEmptyVec::from_vec)
+        .expect(NON_EMPTY)
+    }
+}
+
+/// Traverses through [VecT].
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: TCloneableOf5<'t> + NotT5Of5<'t>,
+    TInner: Traversable<'t, ReqIn, ReqOut, ReqF1> + CloneK<'t, ReqOut>,
+> Traversable<'t, ReqIn, ReqOut, ReqF1> for NonEmptyVecT<TInner>
+{
+    fn traverse<'a, A, B, F, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> F::F<'a, Self::F<'a, B>>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> F::F<'a, B>,
+        F1Mut: 'a + FnMut(A) -> F::F<'a, B>,
+        F1Fn: 'a + Fn(A) -> F::F<'a, B>,
+        F1Clone: 'a + Fn(A) -> F::F<'a, B> + Clone,
+        F1Copy: 'a + Fn(A) -> F::F<'a, B> + Copy,
+        F: Applicative<'t, ReqIn, ReqOut, ReqF1> + Functor<'t, ReqIn, ConstBool<false>, ReqF1>,
+        't: 'a,
+    {
+        let tag = ReqF1::create_from(&f, ());
+
+        let values = <VecT<TInner> as Traversable<'t, ReqIn, ReqOut, ReqF1>>::traverse::<
+            A,
+            B,
+            F,
+            _,
+            _,
+            _,
+            _,
+            _,
+        >(clone_a, clone_b.clone(), f, fa.into_vec());
+
+        <F as Functor<'t, ReqIn, ConstBool<false>, ReqF1>>::map(
+            move |vec: &Vec<TInner::F<'a, B>>| {
+                vec.iter()
+                    .map(|b| TInner::clone(clone_b.clone(), b))
+                    .collect::<Vec<_>>()
+                    .pipe(ReqIn::into_guarded)
+            },
+            |_| AssertBlankOutput,
+            ReqF1::create_from(&tag, |vec: Vec<TInner::F<'a, B>>| {
+                NonEmptyVec::from_vec(vec).expect(NON_EMPTY)
+            }),
+            values,
+        )
+    }
+}
+
+impl<'t, TInner: CovariantK<'t>> CovariantK<'t> for NonEmptyVecT<TInner> {
+    fn covariant_convert<'a, 'b, A>(a: Self::F<'a, A>) -> Self::F<'b, A>
+    where
+        A: 'a,
+        'a: 'b,
+        't: 'a + 'b,
+    {
+        a.map(TInner::covariant_convert)
+    }
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, TInner: CloneOwnedK<'t, ReqIn>> CloneOwnedK<'t, ReqIn>
+    for NonEmptyVecT<TInner>
+{
+    fn clone_owned<'a, 'b, A>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'b, A> + Clone,
+        a: &Self::F<'a, A>,
+    ) -> Self::F<'b, A>
+    where
+        A: 'a + 'b,
+        't: 'a + 'b,
+    {
+        a.as_ref()
+            .map(|ta| TInner::clone_owned(clone_a.clone(), ta))
+    }
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, TInner: CloneK<'t, ReqIn>> CloneK<'t, ReqIn>
+    for NonEmptyVecT<TInner>
+{
+    fn clone<'a, A>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        a: &Self::F<'a, A>,
+    ) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        a.as_ref().map(|ta| TInner::clone(clone_a.clone(), ta))
+    }
+}
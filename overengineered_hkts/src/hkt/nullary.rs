@@ -1,14 +1,22 @@
 use core::convert::Infallible;
 
+use tap::Pipe as _;
+
 use crate::{hkt::{
-    CloneK, CloneOwnedK, CovariantK, Functor, Hkt, PhantomMarker, UnsizedHkt, UnsizedHktUnsized,
+    Applicative, CloneK, CloneOwnedK, CovariantK, FoldWhile, Foldable, Functor, Hkt, Monoid, PhantomMarker, Pure,
+    Traversable, UnsizedHkt, UnsizedHktUnsized,
     hkt_classification::{self, HktClassification},
     one_of::OneOf5Hkt,
-}, marker_classification::TypeGuard};
+}, marker_classification::{ConstBool, TypeGuard}};
 
 /// A Hkt wrapper around `T` that is not actually higher kinded over anything.
 pub struct NullaryHkt<T = ()>(Infallible, PhantomMarker<T>);
 
+/// The constant functor: ignores `A` and carries a `C` instead.
+///
+/// With `C: Monoid` it is [Applicative], [Pure] returns [Monoid::empty] and `apply` combines both operands.
+pub type ConstT<C> = NullaryHkt<C>;
+
 impl<'t, T: 't> UnsizedHkt<'t> for NullaryHkt<T> {
     type UnsizedF<'a, A: 'a>
         = T
@@ -57,6 +65,97 @@ impl<'t, T: 't, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t
     }
 }
 
+impl<'t, T: 't, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>
+    Foldable<'t, ReqIn, ReqOut, ReqF1> for NullaryHkt<T>
+{
+    fn fold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        _clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        _clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+        _f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        _fa: Self::F<'a, A>,
+    ) -> FoldWhile<B>
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, A) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, A) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, A) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, A) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, A) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        FoldWhile::Continue(init)
+    }
+}
+
+impl<'t, T: 't + Monoid, ReqIn: TypeGuard<'t>> Pure<'t, ReqIn> for NullaryHkt<T> {
+    fn pure<'a, A>(
+        _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        _a: A,
+    ) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        T::empty()
+    }
+}
+
+impl<
+    't,
+    T: 't + Monoid,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t>,
+> Applicative<'t, ReqIn, ReqOut, ReqF1> for NullaryHkt<T>
+{
+    fn apply<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        _clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        ff: Self::F<'a, <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> B,
+        F1Mut: 'a + FnMut(A) -> B,
+        F1Fn: 'a + Fn(A) -> B,
+        F1Clone: 'a + Fn(A) -> B + Clone,
+        F1Copy: 'a + Fn(A) -> B + Copy,
+        't: 'a,
+    {
+        ff.combine(fa)
+    }
+}
+
+impl<'t, T: 't + Clone, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>
+    Traversable<'t, ReqIn, ReqOut, ReqF1> for NullaryHkt<T>
+{
+    fn traverse<'a, A, B, F, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        _clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        _f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> F::F<'a, Self::F<'a, B>>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> F::F<'a, B>,
+        F1Mut: 'a + FnMut(A) -> F::F<'a, B>,
+        F1Fn: 'a + Fn(A) -> F::F<'a, B>,
+        F1Clone: 'a + Fn(A) -> F::F<'a, B> + Clone,
+        F1Copy: 'a + Fn(A) -> F::F<'a, B> + Copy,
+        F: Applicative<'t, ReqIn, ReqOut, ReqF1> + Functor<'t, ReqIn, ConstBool<false>, ReqF1>,
+        't: 'a,
+    {
+        F::pure(|t: &T| t.clone().pipe(ReqIn::into_guarded), fa)
+    }
+}
+
 // impl<'t, T: 't, ReqIn: CloneK<'t, ReqIn> + CloneFnHkt<'t>> CloneK<'t, ReqIn> for UnitHkt<T> {
 //     fn clone<'a, 'b, A: 'a + 'b>(requirements: impl 'a + Into<<ReqIn>::F<'a, A>>, a: &Self::F<'a, A>) -> Self::F<'b, A>
 //     where
@@ -0,0 +1,314 @@
+use core::{cell::Cell, convert::identity, marker::PhantomData};
+
+use alloc::{boxed::Box, rc::Rc};
+
+use crate::{
+    hkt::{
+        Applicative, Functor, Monoid, Pure, Traversable, id::IdHkt, nullary::ConstT,
+        one_of::T1Of5Hkt, one_of::T4Of5Hkt, vec::VecT,
+    },
+    marker_classification::{AssertBlankOutput, ConstBool, DependentClone},
+};
+
+type Split<'a, S, A> = Rc<dyn 'a + Fn(S) -> (A, Box<dyn 'a + FnOnce(A) -> S>)>;
+
+/// Focus on exactly one `A` inside `S`, stored as a function splitting `S` into the focus and a way to rebuild it.
+///
+/// [field_lens](crate::field_lens) creates one for a struct field.
+pub struct Lens<'a, S, A> {
+    split: Split<'a, S, A>,
+}
+
+impl<'a, S: 'a, A: 'a> Lens<'a, S, A> {
+    pub fn new<R: 'a + FnOnce(A) -> S>(split: impl 'a + Fn(S) -> (A, R)) -> Self {
+        Self {
+            split: Rc::new(move |s| {
+                let (a, rebuild) = split(s);
+                (a, Box::new(rebuild) as Box<dyn 'a + FnOnce(A) -> S>)
+            }),
+        }
+    }
+
+    /// The van Laarhoven form: runs `f` on the focus and rebuilds `S` inside any [Functor] accepting [FnOnce].
+    pub fn modify_f<F>(&self, f: impl FnOnce(A) -> F::F<'a, A>, s: S) -> F::F<'a, S>
+    where
+        F: Functor<'a, ConstBool<false>, ConstBool<false>, T1Of5Hkt>,
+    {
+        let (a, rebuild) = (self.split)(s);
+
+        F::map::<A, S, _, fn(A) -> S, fn(A) -> S, fn(A) -> S, fn(A) -> S>(
+            |_| AssertBlankOutput,
+            |_| AssertBlankOutput,
+            rebuild,
+            f(a),
+        )
+    }
+
+    /// [modify_f](Lens::modify_f) with [ConstT], which keeps the focus and never rebuilds.
+    pub fn view(&self, s: S) -> A {
+        self.modify_f::<ConstT<A>>(identity, s)
+    }
+
+    /// [modify_f](Lens::modify_f) with [IdHkt]. Unlike [Traversal::over] neither `f`, `S` nor `A` need to be [Clone].
+    pub fn over(&self, f: impl FnOnce(A) -> A, s: S) -> S {
+        self.modify_f::<IdHkt>(f, s)
+    }
+
+    pub fn set(&self, a: A, s: S) -> S {
+        self.over(|_| a, s)
+    }
+
+    /// Focus further into `A`.
+    pub fn then<B: 'a>(self, inner: Lens<'a, A, B>) -> Lens<'a, S, B> {
+        Lens::new(move |s| {
+            let (a, rebuild_s) = (self.split)(s);
+            let (b, rebuild_a) = (inner.split)(a);
+
+            (b, move |b| rebuild_s(rebuild_a(b)))
+        })
+    }
+}
+
+impl<'a, S, A> Clone for Lens<'a, S, A> {
+    fn clone(&self) -> Self {
+        Self {
+            split: self.split.clone(),
+        }
+    }
+}
+
+/// Focus on the `A` inside one case of `S`, e.g. an enum variant.
+///
+/// [variant_prism](crate::variant_prism) creates one for a single-field tuple variant.
+pub struct Prism<'a, S, A> {
+    matching: Rc<dyn 'a + Fn(S) -> Result<A, S>>,
+    review: Rc<dyn 'a + Fn(A) -> S>,
+}
+
+impl<'a, S: 'a, A: 'a> Prism<'a, S, A> {
+    pub fn new(matching: impl 'a + Fn(S) -> Result<A, S>, review: impl 'a + Fn(A) -> S) -> Self {
+        Self {
+            matching: Rc::new(matching),
+            review: Rc::new(review),
+        }
+    }
+
+    /// Returns `s` back if it is another case.
+    pub fn matching(&self, s: S) -> Result<A, S> {
+        (self.matching)(s)
+    }
+
+    pub fn preview(&self, s: S) -> Option<A> {
+        self.matching(s).ok()
+    }
+
+    pub fn review(&self, a: A) -> S {
+        (self.review)(a)
+    }
+}
+
+impl<'a, S, A> Clone for Prism<'a, S, A> {
+    fn clone(&self) -> Self {
+        Self {
+            matching: self.matching.clone(),
+            review: self.review.clone(),
+        }
+    }
+}
+
+/// Every `A` in a `TInner::F<'a, A>`, via [Traversable::traverse].
+pub struct Each<TInner = VecT>(PhantomData<TInner>);
+
+impl<TInner> Each<TInner> {
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<TInner> Default for Each<TInner> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<TInner> Clone for Each<TInner> {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+/// `TOuter` then `TInner`, see [Traversal::compose].
+pub struct Composed<TOuter, TInner, A> {
+    outer: TOuter,
+    inner: TInner,
+    _a: PhantomData<fn() -> A>,
+}
+
+impl<TOuter: Clone, TInner: Clone, A> Clone for Composed<TOuter, TInner, A> {
+    fn clone(&self) -> Self {
+        Self {
+            outer: self.outer.clone(),
+            inner: self.inner.clone(),
+            _a: PhantomData,
+        }
+    }
+}
+
+/// Van Laarhoven traversal: zero or more `A`s inside `S`, visited by any [Applicative].
+///
+/// Since `F` may run the rebuilding function any number of times, e.g. [VecT], the foci and `S` are cloned with [DependentClone].
+pub trait Traversal<'a, S: 'a, A: 'a> {
+    fn traverse_of<F>(&self, f: impl 'a + Fn(A) -> F::F<'a, A> + Clone, s: S) -> F::F<'a, S>
+    where
+        F: Applicative<'a, DependentClone, DependentClone, T4Of5Hkt>
+            + Functor<'a, DependentClone, ConstBool<false>, T4Of5Hkt>;
+
+    /// [traverse_of](Traversal::traverse_of) with [IdHkt].
+    fn over(&self, f: impl 'a + Fn(A) -> A + Clone, s: S) -> S {
+        self.traverse_of::<IdHkt>(f, s)
+    }
+
+    /// [traverse_of](Traversal::traverse_of) with [ConstT], combining the foci with [Monoid].
+    fn fold_map_of<M: 'a + Monoid>(&self, f: impl 'a + Fn(A) -> M + Clone, s: S) -> M {
+        self.traverse_of::<ConstT<M>>(f, s)
+    }
+
+    fn compose<B: 'a, TInner: 'a + Clone + Traversal<'a, A, B>>(
+        self,
+        inner: TInner,
+    ) -> Composed<Self, TInner, A>
+    where
+        Self: Sized,
+    {
+        Composed {
+            outer: self,
+            inner,
+            _a: PhantomData,
+        }
+    }
+}
+
+impl<'a, S: 'a + Clone, A: 'a + Clone> Traversal<'a, S, A> for Lens<'a, S, A> {
+    fn traverse_of<F>(&self, f: impl 'a + Fn(A) -> F::F<'a, A> + Clone, s: S) -> F::F<'a, S>
+    where
+        F: Applicative<'a, DependentClone, DependentClone, T4Of5Hkt>
+            + Functor<'a, DependentClone, ConstBool<false>, T4Of5Hkt>,
+    {
+        let split = self.split.clone();
+        let (a, rebuild) = split(s.clone());
+        // Most functors rebuild once and reuse this split, only further rebuilds split `s` again.
+        let rebuild = Rc::new(Cell::new(Some(rebuild)));
+
+        <F as Functor<'a, DependentClone, DependentClone, T4Of5Hkt>>::map::<
+            A,
+            S,
+            fn(A) -> S,
+            fn(A) -> S,
+            fn(A) -> S,
+            _,
+            fn(A) -> S,
+        >(
+            Clone::clone,
+            Clone::clone,
+            move |a| match rebuild.take() {
+                Some(rebuild) => rebuild(a),
+                None => (split(s.clone()).1)(a),
+            },
+            f(a),
+        )
+    }
+}
+
+impl<'a, S: 'a + Clone, A: 'a + Clone> Traversal<'a, S, A> for Prism<'a, S, A> {
+    fn traverse_of<F>(&self, f: impl 'a + Fn(A) -> F::F<'a, A> + Clone, s: S) -> F::F<'a, S>
+    where
+        F: Applicative<'a, DependentClone, DependentClone, T4Of5Hkt>
+            + Functor<'a, DependentClone, ConstBool<false>, T4Of5Hkt>,
+    {
+        match self.matching(s) {
+            Ok(a) => {
+                let review = self.review.clone();
+
+                <F as Functor<'a, DependentClone, DependentClone, T4Of5Hkt>>::map::<
+                    A,
+                    S,
+                    fn(A) -> S,
+                    fn(A) -> S,
+                    fn(A) -> S,
+                    _,
+                    fn(A) -> S,
+                >(Clone::clone, Clone::clone, move |a| review(a), f(a))
+            }
+            Err(s) => <F as Pure<'a, DependentClone>>::pure(Clone::clone, s),
+        }
+    }
+}
+
+impl<'a, A: 'a + Clone, TInner: Traversable<'a, DependentClone, DependentClone, T4Of5Hkt>>
+    Traversal<'a, TInner::F<'a, A>, A> for Each<TInner>
+{
+    fn traverse_of<F>(
+        &self,
+        f: impl 'a + Fn(A) -> F::F<'a, A> + Clone,
+        s: TInner::F<'a, A>,
+    ) -> F::F<'a, TInner::F<'a, A>>
+    where
+        F: Applicative<'a, DependentClone, DependentClone, T4Of5Hkt>
+            + Functor<'a, DependentClone, ConstBool<false>, T4Of5Hkt>,
+    {
+        TInner::traverse::<
+            A,
+            A,
+            F,
+            fn(A) -> F::F<'a, A>,
+            fn(A) -> F::F<'a, A>,
+            fn(A) -> F::F<'a, A>,
+            _,
+            fn(A) -> F::F<'a, A>,
+        >(Clone::clone, Clone::clone, f, s)
+    }
+}
+
+impl<'a, S: 'a, A: 'a, B: 'a, TOuter: Traversal<'a, S, A>, TInner: 'a + Clone + Traversal<'a, A, B>>
+    Traversal<'a, S, B> for Composed<TOuter, TInner, A>
+{
+    fn traverse_of<F>(&self, f: impl 'a + Fn(B) -> F::F<'a, B> + Clone, s: S) -> F::F<'a, S>
+    where
+        F: Applicative<'a, DependentClone, DependentClone, T4Of5Hkt>
+            + Functor<'a, DependentClone, ConstBool<false>, T4Of5Hkt>,
+    {
+        let inner = self.inner.clone();
+
+        self.outer
+            .traverse_of::<F>(move |a| inner.traverse_of::<F>(f.clone(), a), s)
+    }
+}
+
+/// Creates a [Lens](crate::hkt::optics::Lens) on a field of a struct or tuple struct, e.g. `field_lens!(Point, x)` or `field_lens!(Meters, 0)`.
+///
+/// The struct is rebuilt with update syntax, so it cannot implement [Drop].
+#[macro_export]
+macro_rules! field_lens {
+    ($($struct:ident)::+, $field:tt) => {
+        $crate::hkt::optics::Lens::<$($struct)::+, _>::new(|s: $($struct)::+| {
+            let a = s.$field;
+
+            (a, move |a| $($struct)::+ { $field: a, ..s })
+        })
+    };
+}
+
+/// Creates a [Prism](crate::hkt::optics::Prism) on a tuple variant with a single field, e.g. `variant_prism!(Shape::Circle)`.
+#[macro_export]
+macro_rules! variant_prism {
+    ($($variant:ident)::+) => {
+        $crate::hkt::optics::Prism::new(
+            |s| match s {
+                $($variant)::+(a) => Ok(a),
+                #[allow(unreachable_patterns)]
+                s => Err(s),
+            },
+            $($variant)::+,
+        )
+    };
+}
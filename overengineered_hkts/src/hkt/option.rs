@@ -8,14 +8,16 @@ use tap::Pipe as _;
 
 use crate::{
     hkt::{
-        Applicative, CloneK, CloneOwnedK, Converge, CovariantK, FoldWhile, Foldable, Functor, Hkt,
-        HktUnsized, Monad, PhantomMarker, Pure, Rfoldable, Traversable,
+        Align, Applicative, CloneK, CloneOwnedK, Converge, CovariantK, FoldWhile, Foldable,
+        FoldableWithIndex, Functor, FunctorWithIndex, Hkt, HktUnsized, Monad, PhantomMarker, Pure,
+        Rfoldable, Traversable, TraversableWithIndex, Unfoldable, WithIndex, Zip,
         hkt_classification::{self, HktClassification},
         id::IdHkt,
-        one_of::{NotT5Of5, OneOf5Hkt},
+        one_of::{NotT5Of5, OneOf5, OneOf5Hkt},
         reference::{RefMutT, RefT},
+        these::These,
     },
-    marker_classification::{ConstBool, TypeGuard},
+    marker_classification::{AutoTraitsK, ConstBool, TypeGuard},
     transmute::unsafe_transmute_id,
     utils::CloneWrapper,
 };
@@ -40,6 +42,9 @@ impl<TInner> HktClassification for OptionT<TInner> {
     type Choice = hkt_classification::OuterHkt;
 }
 
+// SAFETY: an [Option] only adds a discriminant around `TInner::F<'a, A>`.
+unsafe impl<'t, TInner: AutoTraitsK<'t>> AutoTraitsK<'t> for OptionT<TInner> {}
+
 impl<
     't,
     ReqIn: TypeGuard<'t>,
@@ -558,6 +563,179 @@ impl<
     }
 }
 
+/// Keeps only the first element.
+impl<'t, ReqIn: TypeGuard<'t>> Unfoldable<'t, ReqIn> for OptionT {
+    fn unfold<'a, A, S>(
+        _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        mut step: impl 'a + FnMut(S) -> Option<(A, S)>,
+        seed: S,
+    ) -> Self::F<'a, A>
+    where
+        A: 'a,
+        S: 'a,
+        't: 'a,
+    {
+        step(seed).map(|(a, _)| a)
+    }
+}
+
+/// At most one element so there is no position to tell apart.
+impl<'t> WithIndex<'t> for OptionT {
+    type Index = ();
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>
+    FunctorWithIndex<'t, ReqIn, ReqOut, ReqF1> for OptionT
+{
+    fn map_with_index<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        _clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce((), A) -> B,
+        F1Mut: 'a + FnMut((), A) -> B,
+        F1Fn: 'a + Fn((), A) -> B,
+        F1Clone: 'a + Fn((), A) -> B + Clone,
+        F1Copy: 'a + Fn((), A) -> B + Copy,
+        't: 'a,
+    {
+        fa.map(move |a| match_one_of_5!(ReqF1::into_one_of_5_enum(f), f, f((), a)))
+    }
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>
+    FoldableWithIndex<'t, ReqIn, ReqOut, ReqF1> for OptionT
+{
+    fn fold_while_with_index<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        _clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        _clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+        f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: Self::F<'a, A>,
+    ) -> FoldWhile<B>
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, (), A) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, (), A) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, (), A) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, (), A) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, (), A) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        match fa {
+            Some(a) => match_one_of_5!(ReqF1::into_one_of_5_enum(f), f, f(init, (), a)),
+            None => FoldWhile::Break(init),
+        }
+    }
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>
+    TraversableWithIndex<'t, ReqIn, ReqOut, ReqF1> for OptionT
+{
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t>,
+    TInner: Zip<'t, ReqIn, ReqOut, ReqF1>,
+> Zip<'t, ReqIn, ReqOut, ReqF1> for OptionT<TInner>
+{
+    fn zip_with<'a, A, B, C, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqIn::Output<'a, B> + Clone,
+        clone_c: impl 'a + Fn(&C) -> ReqOut::Output<'a, C> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+        fb: Self::F<'a, B>,
+    ) -> Self::F<'a, C>
+    where
+        A: 'a,
+        B: 'a,
+        C: 'a,
+        F1Once: 'a + FnOnce(A, B) -> C,
+        F1Mut: 'a + FnMut(A, B) -> C,
+        F1Fn: 'a + Fn(A, B) -> C,
+        F1Clone: 'a + Fn(A, B) -> C + Clone,
+        F1Copy: 'a + Fn(A, B) -> C + Copy,
+        't: 'a,
+    {
+        fa.zip(fb)
+            .map(move |(ta, tb)| TInner::zip_with(clone_a, clone_b, clone_c, f, ta, tb))
+    }
+
+    fn unzip<'a, A, B>(fab: Self::F<'a, (A, B)>) -> (Self::F<'a, A>, Self::F<'a, B>)
+    where
+        A: 'a,
+        B: 'a,
+        't: 'a,
+    {
+        match fab.map(TInner::unzip) {
+            Some((ta, tb)) => (Some(ta), Some(tb)),
+            None => (None, None),
+        }
+    }
+}
+
+/// `TInner` only aligns when both sides are [Some], otherwise the present side is mapped.
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t>,
+    TInner: Align<'t, ReqIn, ReqOut, ReqF1> + Functor<'t, ReqIn, ReqOut, ReqF1>,
+> Align<'t, ReqIn, ReqOut, ReqF1> for OptionT<TInner>
+{
+    fn align_with<'a, A, B, C, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqIn::Output<'a, B> + Clone,
+        clone_c: impl 'a + Fn(&C) -> ReqOut::Output<'a, C> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+        fb: Self::F<'a, B>,
+    ) -> Self::F<'a, C>
+    where
+        A: 'a,
+        B: 'a,
+        C: 'a,
+        F1Once: 'a + FnOnce(These<A, B>) -> C,
+        F1Mut: 'a + FnMut(These<A, B>) -> C,
+        F1Fn: 'a + Fn(These<A, B>) -> C,
+        F1Clone: 'a + Fn(These<A, B>) -> C + Clone,
+        F1Copy: 'a + Fn(These<A, B>) -> C + Copy,
+        't: 'a,
+    {
+        match (fa, fb) {
+            (Some(ta), Some(tb)) => {
+                TInner::align_with(clone_a, clone_b, clone_c, f, ta, tb).pipe(Some)
+            }
+            (Some(ta), None) => TInner::map(
+                clone_a,
+                clone_c,
+                map_one_of_5_with!(ReqF1, f, |mut f| move |a: A| f(These::This(a))),
+                ta,
+            )
+            .pipe(Some),
+            (None, Some(tb)) => TInner::map(
+                clone_b,
+                clone_c,
+                map_one_of_5_with!(ReqF1, f, |mut f| move |b: B| f(These::That(b))),
+                tb,
+            )
+            .pipe(Some),
+            (None, None) => None,
+        }
+    }
+}
+
 impl<'t, TInner: CovariantK<'t>> CovariantK<'t> for OptionT<TInner> {
     fn covariant_convert<'a, 'b, A>(a: Self::F<'a, A>) -> Self::F<'b, A>
     where
@@ -0,0 +1,31 @@
+use core::{convert::Infallible, marker::PhantomData, pin::Pin};
+
+use crate::hkt::{
+    Hkt, HktUnsized,
+    hkt_classification::{self, HktClassification},
+    id::IdHkt,
+};
+
+/// Pins the pointer produced by `TInner`, e.g. `PinT<BoxT<TInner>>` is `Pin<Box<_>>`.
+///
+/// Instances are given over pointer layers that can pin a future, see [FuturePointerK](crate::hkt::future::FuturePointerK),
+/// since pinning guarantees depend on the pointer and its pointee.
+pub struct PinT<TInner = IdHkt>(Infallible, PhantomData<TInner>);
+
+impl<'t, TInner: Hkt<'t>> Hkt<'t> for PinT<TInner> {
+    type F<'a, A: 'a>
+        = Pin<TInner::F<'a, A>>
+    where
+        't: 'a;
+}
+
+impl<'t, TInner: HktUnsized<'t>> HktUnsized<'t> for PinT<TInner> {
+    type FUnsized<'a, A: 'a + ?Sized>
+        = Pin<TInner::FUnsized<'a, A>>
+    where
+        't: 'a;
+}
+
+impl<TInner> HktClassification for PinT<TInner> {
+    type Choice = hkt_classification::OuterHkt;
+}
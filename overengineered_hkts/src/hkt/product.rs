@@ -0,0 +1,386 @@
+use core::{convert::Infallible, marker::PhantomData};
+
+use either::Either;
+use tap::Pipe as _;
+
+use crate::{
+    hkt::{
+        Applicative, CloneK, CovariantK, Distributive, FoldWhile, Foldable, Functor, Hkt, Pure,
+        Representable, TCloneableOf5, Traversable, WithIndex,
+        hkt_classification::{self, HktClassification},
+        id::IdHkt,
+        one_of::{NotT1Of5, NotT5Of5, OneOf5Hkt, T4Of5Hkt},
+    },
+    marker_classification::{AssertBlankOutput, ConstBool, TypeGuard},
+    utils::{CloneWrapper, sum_size_hints},
+};
+
+/// Both layers side by side over the same `A`, `f` is applied to each of them.
+///
+/// [Pure] needs two copies of `A`, so [Pure] and [Applicative] only exist for [DependentClone](crate::marker_classification::DependentClone) inputs.
+pub struct ProductT<TLeft, TRight>(Infallible, PhantomData<(TLeft, TRight)>);
+
+/// Two layers of the same kind, e.g. `(A, A)` by default.
+pub type PairT<TInner = IdHkt> = ProductT<TInner, TInner>;
+
+impl<'t, TLeft: Hkt<'t>, TRight: Hkt<'t>> Hkt<'t> for ProductT<TLeft, TRight> {
+    type F<'a, A: 'a>
+        = (TLeft::F<'a, A>, TRight::F<'a, A>)
+    where
+        't: 'a;
+}
+
+impl<TLeft, TRight> HktClassification for ProductT<TLeft, TRight> {
+    type Choice = hkt_classification::OuterHkt;
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: TCloneableOf5<'t>,
+    TLeft: Functor<'t, ReqIn, ReqOut, ReqF1>,
+    TRight: Functor<'t, ReqIn, ReqOut, ReqF1>,
+> Functor<'t, ReqIn, ReqOut, ReqF1> for ProductT<TLeft, TRight>
+{
+    fn map<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> B,
+        F1Mut: 'a + FnMut(A) -> B,
+        F1Fn: 'a + Fn(A) -> B,
+        F1Clone: 'a + Fn(A) -> B + Clone,
+        F1Copy: 'a + Fn(A) -> B + Copy,
+        't: 'a,
+    {
+        let f = ReqF1::arbitrary_uncloneable(f, PhantomData::<fn(A) -> B>);
+        let (left, right) = fa;
+
+        (
+            TLeft::map(
+                clone_a.clone(),
+                clone_b.clone(),
+                ReqF1::clone_one_of_5(&f),
+                left,
+            ),
+            TRight::map(clone_a, clone_b, f, right),
+        )
+    }
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+    TLeft: Foldable<'t, ReqIn, ReqOut, ReqF1>,
+    TRight: Foldable<'t, ReqIn, ReqOut, ReqF1>,
+> Foldable<'t, ReqIn, ReqOut, ReqF1> for ProductT<TLeft, TRight>
+{
+    fn fold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+        f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: Self::F<'a, A>,
+    ) -> FoldWhile<B>
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, A) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, A) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, A) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, A) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, A) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        let mut f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, A) -> FoldWhile<B>>);
+        let (left, right) = fa;
+
+        let b = {
+            let f_ref = ReqF1::as_mut(&mut f)
+                .pipe(|f| map_one_of_5_with!(ReqF1, f, |mut f| |b, a| f(b, a)));
+
+            TLeft::fold_while(clone_a.clone(), clone_b.clone(), f_ref, init, left)?
+        };
+
+        TRight::fold_while(clone_a, clone_b, f, b, right)
+    }
+
+    fn size_hint<'a, A>(s: &Self::F<'a, A>) -> (usize, Option<usize>)
+    where
+        't: 'a,
+    {
+        sum_size_hints([TLeft::size_hint(&s.0), TRight::size_hint(&s.1)])
+    }
+}
+
+impl<'t, TLeft: Pure<'t, ConstBool<true>>, TRight: Pure<'t, ConstBool<true>>>
+    Pure<'t, ConstBool<true>> for ProductT<TLeft, TRight>
+{
+    fn pure<'a, A>(clone_a: impl 'a + Fn(&A) -> A + Clone, a: A) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        (
+            TLeft::pure(clone_a.clone(), clone_a(&a)),
+            TRight::pure(clone_a, a),
+        )
+    }
+}
+
+impl<
+    't,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: TCloneableOf5<'t>,
+    TLeft: Applicative<'t, ConstBool<true>, ReqOut, ReqF1>,
+    TRight: Applicative<'t, ConstBool<true>, ReqOut, ReqF1>,
+> Applicative<'t, ConstBool<true>, ReqOut, ReqF1> for ProductT<TLeft, TRight>
+{
+    fn apply<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> A + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        ff: Self::F<'a, <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> B,
+        F1Mut: 'a + FnMut(A) -> B,
+        F1Fn: 'a + Fn(A) -> B,
+        F1Clone: 'a + Fn(A) -> B + Clone,
+        F1Copy: 'a + Fn(A) -> B + Copy,
+        't: 'a,
+    {
+        (
+            TLeft::apply(clone_a.clone(), clone_b.clone(), ff.0, fa.0),
+            TRight::apply(clone_a, clone_b, ff.1, fa.1),
+        )
+    }
+}
+
+/// Both layers are traversed, then paired inside `F` with `map` and `apply`.
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: TCloneableOf5<'t> + NotT5Of5<'t>,
+    TLeft: Traversable<'t, ReqIn, ReqOut, ReqF1> + CloneK<'t, ReqOut>,
+    TRight: Traversable<'t, ReqIn, ReqOut, ReqF1> + CloneK<'t, ReqOut>,
+> Traversable<'t, ReqIn, ReqOut, ReqF1> for ProductT<TLeft, TRight>
+{
+    fn traverse<'a, A, B, F, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> F::F<'a, Self::F<'a, B>>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> F::F<'a, B>,
+        F1Mut: 'a + FnMut(A) -> F::F<'a, B>,
+        F1Fn: 'a + Fn(A) -> F::F<'a, B>,
+        F1Clone: 'a + Fn(A) -> F::F<'a, B> + Clone,
+        F1Copy: 'a + Fn(A) -> F::F<'a, B> + Copy,
+        F: Applicative<'t, ReqIn, ReqOut, ReqF1> + Functor<'t, ReqIn, ConstBool<false>, ReqF1>,
+        't: 'a,
+    {
+        let f = ReqF1::arbitrary_uncloneable(f, PhantomData::<fn(A) -> F::F<'a, B>>);
+        let tag = ReqF1::create_from(&f, ());
+        let (left, right) = fa;
+
+        let left: F::F<'a, TLeft::F<'a, B>> = TLeft::traverse::<_, _, F, _, _, _, _, _>(
+            clone_a.clone(),
+            clone_b.clone(),
+            ReqF1::clone_one_of_5(&f),
+            left,
+        );
+        let right: F::F<'a, TRight::F<'a, B>> =
+            TRight::traverse::<_, _, F, _, _, _, _, _>(clone_a, clone_b.clone(), f, right);
+
+        let pair_with = {
+            let clone_b = clone_b.clone();
+            let tag = CloneWrapper(ReqF1::clone_one_of_5(&tag), |tag: &_| {
+                ReqF1::clone_one_of_5(tag)
+            });
+
+            move |tl: TLeft::F<'a, B>| {
+                let tl = CloneWrapper(tl, {
+                    let clone_b = clone_b.clone();
+                    move |tl: &TLeft::F<'a, B>| TLeft::clone(clone_b.clone(), tl)
+                });
+
+                ReqF1::create_from(tag.get_ref_t(), move |tr| (tl.clone().0, tr)).pipe(|f| {
+                    ReqF1::arbitrary_t5(
+                        f,
+                        PhantomData::<fn(TRight::F<'a, B>) -> (TLeft::F<'a, B>, TRight::F<'a, B>)>,
+                    )
+                })
+            }
+        };
+
+        let func = <F as Functor<'t, ReqIn, ConstBool<false>, ReqF1>>::map(
+            {
+                let clone_b = clone_b.clone();
+                move |tl| TLeft::clone(clone_b.clone(), tl).pipe(ReqIn::into_guarded)
+            },
+            |_| AssertBlankOutput,
+            ReqF1::create_from(&tag, pair_with)
+                .pipe(|f| ReqF1::arbitrary_t5(f, PhantomData::<fn(TLeft::F<'a, B>) -> _>)),
+            left,
+        );
+
+        F::apply(
+            {
+                let clone_b = clone_b.clone();
+                move |tr| TRight::clone(clone_b.clone(), tr).pipe(ReqIn::into_guarded)
+            },
+            move |(tl, tr): &(TLeft::F<'a, B>, TRight::F<'a, B>)| {
+                (
+                    TLeft::clone(clone_b.clone(), tl),
+                    TRight::clone(clone_b.clone(), tr),
+                )
+                    .pipe(ReqOut::into_guarded)
+            },
+            func,
+            right,
+        )
+    }
+}
+
+impl<
+    't,
+    G: Functor<'t, ConstBool<false>, ConstBool<false>, T4Of5Hkt> + CloneK<'t, ConstBool<true>>,
+    TLeft: Distributive<'t, ConstBool<true>, G> + CloneK<'t, ConstBool<true>>,
+    TRight: Distributive<'t, ConstBool<true>, G> + CloneK<'t, ConstBool<true>>,
+> Distributive<'t, ConstBool<true>, G> for ProductT<TLeft, TRight>
+{
+    fn distribute<'a, A>(
+        clone_a: impl 'a + Fn(&A) -> A + Clone,
+        gfa: G::F<'a, Self::F<'a, A>>,
+    ) -> Self::F<'a, G::F<'a, A>>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        let clone_pair = {
+            let clone_a = clone_a.clone();
+            move |(left, right): &(TLeft::F<'a, A>, TRight::F<'a, A>)| {
+                (
+                    TLeft::clone(clone_a.clone(), left),
+                    TRight::clone(clone_a.clone(), right),
+                )
+            }
+        };
+
+        let left = G::map::<
+            Self::F<'a, A>,
+            TLeft::F<'a, A>,
+            fn(_) -> _,
+            fn(_) -> _,
+            fn(_) -> _,
+            _,
+            fn(_) -> _,
+        >(
+            |_| AssertBlankOutput,
+            |_| AssertBlankOutput,
+            |(left, _)| left,
+            G::clone(clone_pair, &gfa),
+        );
+        let right = G::map::<
+            Self::F<'a, A>,
+            TRight::F<'a, A>,
+            fn(_) -> _,
+            fn(_) -> _,
+            fn(_) -> _,
+            _,
+            fn(_) -> _,
+        >(
+            |_| AssertBlankOutput,
+            |_| AssertBlankOutput,
+            |(_, right)| right,
+            gfa,
+        );
+
+        (
+            TLeft::distribute(clone_a.clone(), left),
+            TRight::distribute(clone_a, right),
+        )
+    }
+}
+
+impl<'t, TLeft: WithIndex<'t>, TRight: WithIndex<'t>> WithIndex<'t> for ProductT<TLeft, TRight> {
+    type Index = Either<TLeft::Index, TRight::Index>;
+}
+
+impl<'t, TLeft: Representable<'t>, TRight: Representable<'t>> Representable<'t>
+    for ProductT<TLeft, TRight>
+{
+    fn tabulate<'a, A>(f: impl 'a + Fn(Self::Index) -> A + Clone) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        (
+            TLeft::tabulate({
+                let f = f.clone();
+                move |i| f(Either::Left(i))
+            }),
+            TRight::tabulate(move |i| f(Either::Right(i))),
+        )
+    }
+
+    fn index<'a, A>(fa: Self::F<'a, A>, index: Self::Index) -> A
+    where
+        A: 'a,
+        't: 'a,
+    {
+        match index {
+            Either::Left(i) => TLeft::index(fa.0, i),
+            Either::Right(i) => TRight::index(fa.1, i),
+        }
+    }
+}
+
+impl<'t, TLeft: CovariantK<'t>, TRight: CovariantK<'t>> CovariantK<'t> for ProductT<TLeft, TRight> {
+    fn covariant_convert<'a, 'b, A>(a: Self::F<'a, A>) -> Self::F<'b, A>
+    where
+        A: 'a,
+        'a: 'b,
+        't: 'a + 'b,
+    {
+        (
+            TLeft::covariant_convert(a.0),
+            TRight::covariant_convert(a.1),
+        )
+    }
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, TLeft: CloneK<'t, ReqIn>, TRight: CloneK<'t, ReqIn>>
+    CloneK<'t, ReqIn> for ProductT<TLeft, TRight>
+{
+    fn clone<'a, A>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        a: &Self::F<'a, A>,
+    ) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        (
+            TLeft::clone(clone_a.clone(), &a.0),
+            TRight::clone(clone_a, &a.1),
+        )
+    }
+}
@@ -0,0 +1,18 @@
+use core::{convert::Infallible, marker::PhantomData, ops::Deref as _};
+
+use alloc::rc::Rc;
+use tap::Pipe as _;
+
+use crate::{
+    hkt::{
+        CloneK, DerefHkt, FoldWhile, Foldable, Functor, Hkt, HktUnsized, UnsizedHkt,
+        UnsizedHktUnsized,
+        hkt_classification::{self, HktClassification},
+        id::IdHkt,
+        one_of::{OneOf5, OneOf5Hkt},
+        reference::{RefMutT, RefT},
+    },
+    marker_classification::{ConstBool, TyEq, TypeGuard},
+};
+
+impl_shared_pointer_hkt!(RcT, Rc);
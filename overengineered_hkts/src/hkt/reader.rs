@@ -0,0 +1,238 @@
+use core::{convert::Infallible, marker::PhantomData};
+
+use alloc::rc::Rc;
+use tap::Pipe as _;
+
+use crate::{
+    hkt::{
+        Applicative, CloneK, CovariantK, Distributive, Functor, Hkt, Monad, PhantomMarker, Pure,
+        Representable, TCloneableOf5, WithIndex,
+        hkt_classification::{self, HktClassification},
+        one_of::{OneOf5, T4Of5Hkt},
+    },
+    marker_classification::{AssertBlankOutput, ConstBool, TypeGuard},
+};
+
+/// Computation reading a shared environment `R`.
+pub type Reader<'a, R, A> = Rc<dyn 'a + Fn(R) -> A>;
+
+/// [Reader] with environment `R`. As a [Representable] it is indexed by `R` itself,
+/// so fixed-shape objects of readers can be turned into readers of fixed-shape objects with [Distributive].
+pub struct ReaderT<R>(Infallible, PhantomMarker<R>);
+
+impl<'t, R: 't> Hkt<'t> for ReaderT<R> {
+    type F<'a, A: 'a>
+        = Reader<'a, R, A>
+    where
+        't: 'a;
+}
+
+impl<R> HktClassification for ReaderT<R> {
+    type Choice = hkt_classification::OuterHkt;
+}
+
+impl<'a, R: 'a> ReaderT<R> {
+    pub fn from_fn<A>(f: impl 'a + Fn(R) -> A) -> Reader<'a, R, A> {
+        Rc::new(f)
+    }
+
+    pub fn run<A>(fa: &Reader<'a, R, A>, r: R) -> A {
+        fa(r)
+    }
+
+    /// Returns the environment itself.
+    pub fn ask() -> Reader<'a, R, R> {
+        Rc::new(|r| r)
+    }
+
+    /// Runs `fa` in an environment modified by `f`.
+    pub fn local<A: 'a>(f: impl 'a + Fn(R) -> R, fa: Reader<'a, R, A>) -> Reader<'a, R, A> {
+        Rc::new(move |r| fa(f(r)))
+    }
+}
+
+fn into_rc<'t, 'a, ReqF1: TCloneableOf5<'t>, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+    f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+) -> Rc<dyn 'a + Fn(A) -> B>
+where
+    A: 'a,
+    B: 'a,
+    F1Once: 'a + FnOnce(A) -> B,
+    F1Mut: 'a + FnMut(A) -> B,
+    F1Fn: 'a + Fn(A) -> B,
+    F1Clone: 'a + Fn(A) -> B + Clone,
+    F1Copy: 'a + Fn(A) -> B + Copy,
+    't: 'a,
+{
+    let f = ReqF1::arbitrary_uncloneable(f, PhantomData::<fn(A) -> B>);
+
+    match_one_of_5!(
+        ReqF1::into_one_of_5_enum(f),
+        f,
+        Rc::new(f) as Rc<dyn 'a + Fn(A) -> B>
+    )
+}
+
+impl<'t, R: 't, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: TCloneableOf5<'t>>
+    Functor<'t, ReqIn, ReqOut, ReqF1> for ReaderT<R>
+{
+    fn map<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        _clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> B,
+        F1Mut: 'a + FnMut(A) -> B,
+        F1Fn: 'a + Fn(A) -> B,
+        F1Clone: 'a + Fn(A) -> B + Clone,
+        F1Copy: 'a + Fn(A) -> B + Copy,
+        't: 'a,
+    {
+        let f = into_rc::<ReqF1, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(f);
+
+        Rc::new(move |r| f(fa(r)))
+    }
+}
+
+/// Ignores the environment, cloning `a` on every run.
+impl<'t, R: 't> Pure<'t, ConstBool<true>> for ReaderT<R> {
+    fn pure<'a, A>(clone_a: impl 'a + Fn(&A) -> A + Clone, a: A) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        Rc::new(move |_| clone_a(&a))
+    }
+}
+
+impl<'t, R: 't + Clone, ReqOut: TypeGuard<'t>, ReqF1: TCloneableOf5<'t>>
+    Applicative<'t, ConstBool<true>, ReqOut, ReqF1> for ReaderT<R>
+{
+    fn apply<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        _clone_a: impl 'a + Fn(&A) -> A + Clone,
+        _clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        ff: Self::F<'a, ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> B,
+        F1Mut: 'a + FnMut(A) -> B,
+        F1Fn: 'a + Fn(A) -> B,
+        F1Clone: 'a + Fn(A) -> B + Clone,
+        F1Copy: 'a + Fn(A) -> B + Copy,
+        't: 'a,
+    {
+        Rc::new(move |r: R| {
+            let a = fa(r.clone());
+
+            match_one_of_5!(ReqF1::into_one_of_5_enum(ff(r)), f, f(a))
+        })
+    }
+}
+
+impl<'t, R: 't + Clone, ReqOut: TypeGuard<'t>, ReqF1: TCloneableOf5<'t>>
+    Monad<'t, ConstBool<true>, ReqOut, ReqF1> for ReaderT<R>
+{
+    fn bind<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        _clone_a: impl 'a + Fn(&A) -> A + Clone,
+        _clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        fa: Self::F<'a, A>,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> Self::F<'a, B>,
+        F1Mut: 'a + FnMut(A) -> Self::F<'a, B>,
+        F1Fn: 'a + Fn(A) -> Self::F<'a, B>,
+        F1Clone: 'a + Fn(A) -> Self::F<'a, B> + Clone,
+        F1Copy: 'a + Fn(A) -> Self::F<'a, B> + Copy,
+        't: 'a,
+    {
+        let f = into_rc::<ReqF1, A, Self::F<'a, B>, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(f);
+
+        Rc::new(move |r: R| f(fa(r.clone()))(r))
+    }
+}
+
+/// Runs every reader in `gfa` with the same environment.
+impl<
+    't,
+    R: 't + Clone,
+    ReqIn: TypeGuard<'t>,
+    G: Functor<'t, ConstBool<false>, ConstBool<false>, T4Of5Hkt> + CloneK<'t, ReqIn>,
+> Distributive<'t, ReqIn, G> for ReaderT<R>
+{
+    fn distribute<'a, A>(
+        _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        gfa: G::F<'a, Self::F<'a, A>>,
+    ) -> Self::F<'a, G::F<'a, A>>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        Rc::new(move |r: R| {
+            G::map::<Self::F<'a, A>, A, fn(_) -> _, fn(_) -> _, fn(_) -> _, _, fn(_) -> _>(
+                |_| AssertBlankOutput,
+                |_| AssertBlankOutput,
+                move |reader| reader(r.clone()),
+                G::clone(
+                    |reader: &Self::F<'a, A>| reader.clone().pipe(ReqIn::into_guarded),
+                    &gfa,
+                ),
+            )
+        })
+    }
+}
+
+impl<'t, R: 't> WithIndex<'t> for ReaderT<R> {
+    type Index = R;
+}
+
+impl<'t, R: 't> Representable<'t> for ReaderT<R> {
+    fn tabulate<'a, A>(f: impl 'a + Fn(Self::Index) -> A + Clone) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        Rc::new(f)
+    }
+
+    fn index<'a, A>(fa: Self::F<'a, A>, index: Self::Index) -> A
+    where
+        A: 'a,
+        't: 'a,
+    {
+        fa(index)
+    }
+}
+
+impl<'t, R: 't> CovariantK<'t> for ReaderT<R> {
+    fn covariant_convert<'a, 'b, A>(a: Self::F<'a, A>) -> Self::F<'b, A>
+    where
+        A: 'a,
+        'a: 'b,
+        't: 'a + 'b,
+    {
+        a
+    }
+}
+
+impl<'t, R: 't, ReqIn: TypeGuard<'t>> CloneK<'t, ReqIn> for ReaderT<R> {
+    fn clone<'a, A>(
+        _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        a: &Self::F<'a, A>,
+    ) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        a.clone()
+    }
+}
@@ -1,6 +1,6 @@
 use core::{convert::Infallible, marker::PhantomData};
 
-use crate::{hkt::{Hkt, HktUnsized, UnsizedHkt, UnsizedHktUnsized, hkt_classification::{self, HktClassification}, id::IdHkt}, transmute::unsafe_transmute_id};
+use crate::{hkt::{DerefHkt, DerefMutHkt, Hkt, HktUnsized, UnsizedHkt, UnsizedHktUnsized, hkt_classification::{self, HktClassification}, id::IdHkt}, transmute::unsafe_transmute_id};
 
 pub struct RefT<TInner = IdHkt>(Infallible, PhantomData<TInner>);
 
@@ -16,6 +16,15 @@ impl<TInner> HktClassification for RefT<TInner> {
     type Choice = hkt_classification::OuterHkt;
 }
 
+impl<'t, TInner: DerefHkt<'t>> DerefHkt<'t> for RefT<TInner> {
+    fn deref<'a, 'b: 'a, A: 'a + ?Sized>(fa: &'a Self::UnsizedFUnsized<'b, A>) -> &'a A
+    where
+        't: 'b + 'a,
+    {
+        TInner::deref(*fa)
+    }
+}
+
 #[cfg(false)]
 unsafe impl<'t, TInner: CovariantTransmute<'t>> CovariantTransmute<'t> for RefT<TInner> {
     fn covariant_transmute<'a, 'f, 'b, A, F: CovariantTransmute<'t>>(s: 
@@ -42,3 +51,21 @@ impl<'t, TInner: UnsizedHktUnsized<'t>> HktUnsized<'t> for RefMutT<TInner> {
 impl<TInner> HktClassification for RefMutT<TInner> {
     type Choice = hkt_classification::OuterHkt;
 }
+
+impl<'t, TInner: DerefHkt<'t>> DerefHkt<'t> for RefMutT<TInner> {
+    fn deref<'a, 'b: 'a, A: 'a + ?Sized>(fa: &'a Self::UnsizedFUnsized<'b, A>) -> &'a A
+    where
+        't: 'b + 'a,
+    {
+        TInner::deref(&**fa)
+    }
+}
+
+impl<'t, TInner: DerefMutHkt<'t>> DerefMutHkt<'t> for RefMutT<TInner> {
+    fn deref_mut<'a, 'b: 'a, A: 'a + ?Sized>(fa: &'a mut Self::UnsizedFUnsized<'b, A>) -> &'a mut A
+    where
+        't: 'b + 'a,
+    {
+        TInner::deref_mut(&mut **fa)
+    }
+}
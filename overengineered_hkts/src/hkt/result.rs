@@ -5,7 +5,7 @@ use tap::Pipe as _;
 use crate::{
     hkt::{
         Applicative, CloneK, CloneOwnedK, CovariantK, FoldWhile, Foldable, Functor, Hkt, HktUnsized, Monad, PhantomMarker, Pure, Rfoldable, Traversable, hkt_classification::{self, HktClassification}, id::IdHkt, one_of::{OneOf5Hkt, OneOf5}
-    }, marker_classification::{ConstBool, TypeGuard}
+    }, marker_classification::{AutoTraitsK, ConstBool, TypeGuard}
 };
 
 pub struct ResultT<E, TInner>(Infallible, PhantomMarker<(TInner, E)>);
@@ -22,6 +22,9 @@ impl<TInner, E> HktClassification for ResultT<E, TInner> {
     type Choice = hkt_classification::OuterHkt;
 }
 
+// SAFETY: `E` is itself required to be [Send] and [Sync].
+unsafe impl<'t, E: 't + Send + Sync, TInner: AutoTraitsK<'t>> AutoTraitsK<'t> for ResultT<E, TInner> {}
+
 impl<
     't,
     E: 't,
@@ -1,6 +1,18 @@
 use core::{convert::Infallible, marker::PhantomData};
 
-use crate::hkt::{Hkt, HktUnsized, UnsizedHkt, UnsizedHktUnsized, hkt_classification::{self, HktClassification}, id::IdHkt};
+use tap::Pipe as _;
+
+use crate::{
+    hkt::{
+        FoldWhile, Foldable, Hkt, HktUnsized, MapInPlace, Rfoldable, UnsizedHkt,
+        UnsizedHktUnsized,
+        hkt_classification::{self, HktClassification},
+        id::IdHkt,
+        one_of::{NotT1Of5, OneOf5Hkt},
+        reference::{RefMutT, RefT},
+    },
+    marker_classification::{TyEq, TypeGuard},
+};
 
 pub struct SliceT<TInner = IdHkt>(Infallible, PhantomData<TInner>);
 
@@ -15,3 +27,111 @@ impl<'t, TInner: HktUnsized<'t>> UnsizedHktUnsized<'t> for SliceT<TInner> {
 impl<TInner> HktClassification for SliceT<TInner> {
     type Choice = hkt_classification::OuterHkt;
 }
+
+/// Folds `&[A]` by reference.
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+    TInner: Foldable<'t, ReqIn, ReqOut, ReqF1, RefT>,
+> Foldable<'t, ReqIn, ReqOut, ReqF1, RefT> for RefT<SliceT<TInner>>
+{
+    fn fold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'f + Fn(&A) -> <ReqIn>::Output<'a, A> + Clone,
+        clone_b: impl 'f + Fn(&B) -> <ReqOut>::Output<'b, B> + Clone,
+        f: <ReqF1>::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: <RefT as Hkt<'t>>::F<'a, Self::F<'a, A>>,
+    ) -> FoldWhile<B>
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        let mut f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, &'a A) -> FoldWhile<B>>);
+
+        fa.iter().try_fold(init, move |b, ka| {
+            let f_ref = ReqF1::as_mut(&mut f)
+                .pipe(|f| map_one_of_5_with!(ReqF1, f, |mut f| |b, a| f(b, a)));
+
+            TInner::fold_while(clone_a.clone(), clone_b.clone(), f_ref, b, ka)
+        })
+    }
+}
+
+/// Folds `&[A]` by reference.
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+    TInner: Rfoldable<'t, ReqIn, ReqOut, ReqF1, RefT>,
+> Rfoldable<'t, ReqIn, ReqOut, ReqF1, RefT> for RefT<SliceT<TInner>>
+{
+    fn rfold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'f + Fn(&A) -> <ReqIn>::Output<'a, A> + Clone,
+        clone_b: impl 'f + Fn(&B) -> <ReqOut>::Output<'b, B> + Clone,
+        f: <ReqF1>::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: <RefT as Hkt<'t>>::F<'a, Self::F<'a, A>>,
+    ) -> FoldWhile<B>
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        let mut f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, &'a A) -> FoldWhile<B>>);
+
+        fa.iter().rev().try_fold(init, move |b, ka| {
+            let f_ref = ReqF1::as_mut(&mut f)
+                .pipe(|f| map_one_of_5_with!(ReqF1, f, |mut f| |b, a| f(b, a)));
+
+            TInner::rfold_while(clone_a.clone(), clone_b.clone(), f_ref, b, ka)
+        })
+    }
+}
+
+impl<'t, ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>, TInner: Hkt<'t> + MapInPlace<'t, ReqF1>>
+    MapInPlace<'t, ReqF1> for SliceT<TInner>
+{
+    fn map_in_place<'a, 'f, A, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: <RefMutT<Self> as Hkt<'t>>::F<'a, A>,
+    ) where
+        A: 'a,
+        F1Once: 'f + FnOnce(&'a mut A),
+        F1Mut: 'f + FnMut(&'a mut A),
+        F1Fn: 'f + Fn(&'a mut A),
+        F1Clone: 'f + Fn(&'a mut A) + Clone,
+        F1Copy: 'f + Fn(&'a mut A) + Copy,
+        'a: 'f,
+        't: 'a,
+    {
+        let mut f = ReqF1::arbitrary_t1(f, PhantomData::<fn(&'a mut A)>);
+
+        fa.iter_mut().for_each(move |ka| {
+            let f_ref = ReqF1::as_mut(&mut f)
+                .pipe(|f| map_one_of_5_with!(ReqF1, f, |mut f| |a| f(a)));
+
+            TInner::map_in_place(
+                f_ref,
+                ka.pipe(TInner::F::transmute_hkt_into::<RefMutT>),
+            )
+        })
+    }
+}
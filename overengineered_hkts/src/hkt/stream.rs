@@ -0,0 +1,256 @@
+use core::{
+    convert::{Infallible, identity},
+    future::ready,
+    marker::PhantomData,
+    ops::ControlFlow,
+};
+
+use alloc::{boxed::Box, vec::Vec};
+use futures::{FutureExt as _, Stream, StreamExt as _, stream};
+use tap::Pipe as _;
+
+use crate::{
+    hkt::{
+        Applicative, CloneK, CovariantK, FoldWhile, Foldable, Functor, Hkt, HktUnsized, Monad,
+        Pure, TCloneableOf5, UnsizedHkt, UnsizedHktUnsized,
+        boxed::BoxT,
+        future::PinBoxFutureT,
+        id::IdHkt,
+        one_of::{NotT1Of5, OneOf5, OneOf5Hkt},
+        pin::PinT,
+    },
+    marker_classification::{ConstBool, TypeGuard},
+};
+
+/// Asynchronous counterpart of [DynIteratorT](crate::hkt::iter::DynIteratorT).
+pub struct DynStreamT<TInner = IdHkt>(Infallible, PhantomData<TInner>);
+
+impl<'t, TInner: Hkt<'t>> UnsizedHkt<'t> for DynStreamT<TInner> {
+    type UnsizedF<'a, A: 'a>
+        = dyn 'a + Stream<Item = TInner::F<'a, A>>
+    where
+        't: 'a;
+}
+
+impl<'t, TInner: HktUnsized<'t>> UnsizedHktUnsized<'t> for DynStreamT<TInner> {
+    type UnsizedFUnsized<'a, A: 'a + ?Sized>
+        = dyn 'a + Stream<Item = TInner::FUnsized<'a, A>>
+    where
+        't: 'a;
+}
+
+pub type PinBoxStreamT<TInner = IdHkt> = PinT<BoxT<DynStreamT<TInner>>>;
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: TCloneableOf5<'t>,
+    TInner: Functor<'t, ReqIn, ReqOut, ReqF1>,
+> Functor<'t, ReqIn, ReqOut, ReqF1> for PinT<BoxT<DynStreamT<TInner>>>
+{
+    fn map<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> B,
+        F1Mut: 'a + FnMut(A) -> B,
+        F1Fn: 'a + Fn(A) -> B,
+        F1Clone: 'a + Fn(A) -> B + Clone,
+        F1Copy: 'a + Fn(A) -> B + Copy,
+        't: 'a,
+    {
+        let f = ReqF1::arbitrary_uncloneable(f, PhantomData::<fn(A) -> B>);
+
+        fa.map(move |x| {
+            TInner::map(
+                clone_a.clone(),
+                clone_b.clone(),
+                ReqF1::clone_one_of_5(&f),
+                x,
+            )
+        })
+        .pipe(Box::pin)
+    }
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, TInner: Pure<'t, ReqIn>> Pure<'t, ReqIn>
+    for PinT<BoxT<DynStreamT<TInner>>>
+{
+    fn pure<'a, A>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        a: A,
+    ) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        TInner::pure(clone_a, a)
+            .pipe(ready)
+            .pipe(stream::once)
+            .pipe(Box::pin)
+    }
+}
+
+/// `fa` is collected before the first function of `ff` is polled, since each function is applied to all of its items.
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: TCloneableOf5<'t>,
+    TInner: Applicative<'t, ReqIn, ReqOut, ReqF1> + CloneK<'t, ReqIn>,
+> Applicative<'t, ReqIn, ReqOut, ReqF1> for PinT<BoxT<DynStreamT<TInner>>>
+{
+    fn apply<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        ff: Self::F<'a, <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> B,
+        F1Mut: 'a + FnMut(A) -> B,
+        F1Fn: 'a + Fn(A) -> B,
+        F1Clone: 'a + Fn(A) -> B + Clone,
+        F1Copy: 'a + Fn(A) -> B + Copy,
+        't: 'a,
+    {
+        fa.collect::<Vec<_>>()
+            .map(move |fa| {
+                ff.flat_map(move |f| {
+                    fa.iter()
+                        .map(|ta| {
+                            TInner::apply(
+                                clone_a.clone(),
+                                clone_b.clone(),
+                                TInner::clone(
+                                    |tf| {
+                                        // Same as in DynIteratorT: clones &ReqF1::OneOf5F while preserving uncloneable variants
+                                        ReqF1::as_ref(tf)
+                                            .pipe(|f| ReqF1::arbitrary_t1(f, PhantomData::<F1Once>))
+                                            .pipe(|f| ReqF1::arbitrary_t2(f, PhantomData::<F1Mut>))
+                                            .pipe(|f| ReqF1::arbitrary_t3(f, PhantomData::<F1Fn>))
+                                            .pipe(|f| {
+                                                ReqF1::map_one_of_5::<'_, 'a, '_>(
+                                                    f,
+                                                    identity,
+                                                    identity,
+                                                    identity,
+                                                    |f| f.clone(),
+                                                    |f| *f,
+                                                )
+                                            })
+                                            .pipe(ReqIn::into_guarded)
+                                    },
+                                    &f,
+                                ),
+                                TInner::clone(clone_a.clone(), ta),
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .pipe(stream::iter)
+                })
+            })
+            .flatten_stream()
+            .pipe(Box::pin)
+    }
+}
+
+/// Flattens with [flat_map](futures::StreamExt::flat_map): each inner stream is drained before the next item of `fa` is polled.
+impl<'t, ReqOut: TypeGuard<'t>, ReqF1: TCloneableOf5<'t>> Monad<'t, ConstBool<true>, ReqOut, ReqF1>
+    for PinT<BoxT<DynStreamT<IdHkt>>>
+{
+    fn bind<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        _clone_a: impl 'a + Fn(&A) -> A + Clone,
+        _clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        fa: Self::F<'a, A>,
+        f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> Self::F<'a, B>,
+        F1Mut: 'a + FnMut(A) -> Self::F<'a, B>,
+        F1Fn: 'a + Fn(A) -> Self::F<'a, B>,
+        F1Clone: 'a + Fn(A) -> Self::F<'a, B> + Clone,
+        F1Copy: 'a + Fn(A) -> Self::F<'a, B> + Copy,
+        't: 'a,
+    {
+        let f = ReqF1::arbitrary_uncloneable(f, PhantomData::<fn(A) -> Self::F<'a, B>>);
+
+        match_one_of_5!(
+            ReqF1::into_one_of_5_enum(f),
+            f,
+            fa.flat_map(f).pipe(Box::pin)
+        )
+    }
+}
+
+impl<'t, TInner: CovariantK<'t>> CovariantK<'t> for PinT<BoxT<DynStreamT<TInner>>> {
+    fn covariant_convert<'a, 'b, A>(a: Self::F<'a, A>) -> Self::F<'b, A>
+    where
+        A: 'a,
+        'a: 'b,
+        't: 'a + 'b,
+    {
+        a.map(TInner::covariant_convert).pipe(Box::pin)
+    }
+}
+
+impl<TInner> PinT<BoxT<DynStreamT<TInner>>> {
+    /// Asynchronous [Foldable::fold_while], items are polled only until `f` breaks.
+    pub fn fold_while<
+        't,
+        'a,
+        ReqIn: TypeGuard<'t>,
+        ReqOut: TypeGuard<'t>,
+        ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+        A,
+        B,
+        F1Once,
+        F1Mut,
+        F1Fn,
+        F1Clone,
+        F1Copy,
+    >(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: <Self as Hkt<'t>>::F<'a, A>,
+    ) -> <PinBoxFutureT as Hkt<'t>>::F<'a, FoldWhile<B>>
+    where
+        TInner: Foldable<'t, ReqIn, ReqOut, ReqF1>,
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(B, A) -> FoldWhile<B>,
+        F1Mut: 'a + FnMut(B, A) -> FoldWhile<B>,
+        F1Fn: 'a + Fn(B, A) -> FoldWhile<B>,
+        F1Clone: 'a + Fn(B, A) -> FoldWhile<B> + Clone,
+        F1Copy: 'a + Fn(B, A) -> FoldWhile<B> + Copy,
+        't: 'a,
+    {
+        let mut f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, A) -> FoldWhile<B>>);
+
+        Box::pin(async move {
+            let mut fa = fa;
+            let mut b = init;
+
+            while let Some(ka) = fa.next().await {
+                let f_ref = ReqF1::as_mut(&mut f)
+                    .pipe(|f| map_one_of_5_with!(ReqF1, f, |mut f| |b, a| f(b, a)));
+
+                b = TInner::fold_while(clone_a.clone(), clone_b.clone(), f_ref, b, ka)?;
+            }
+
+            ControlFlow::Continue(b)
+        })
+    }
+}
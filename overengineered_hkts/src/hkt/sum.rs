@@ -0,0 +1,197 @@
+use core::{convert::Infallible, marker::PhantomData};
+
+use either::Either;
+use tap::Pipe as _;
+
+use crate::{
+    hkt::{
+        Applicative, CloneK, CovariantK, FoldWhile, Foldable, Functor, Hkt, Traversable,
+        hkt_classification::{self, HktClassification},
+        one_of::OneOf5Hkt,
+    },
+    marker_classification::{ConstBool, TypeGuard},
+};
+
+/// One of two layers over the same `A`.
+///
+/// There is no [Pure](crate::hkt::Pure) since neither side is preferred, and no [Applicative](crate::hkt::Applicative) since the two sides cannot be combined.
+pub struct SumT<TLeft, TRight>(Infallible, PhantomData<(TLeft, TRight)>);
+
+impl<'t, TLeft: Hkt<'t>, TRight: Hkt<'t>> Hkt<'t> for SumT<TLeft, TRight> {
+    type F<'a, A: 'a>
+        = Either<TLeft::F<'a, A>, TRight::F<'a, A>>
+    where
+        't: 'a;
+}
+
+impl<TLeft, TRight> HktClassification for SumT<TLeft, TRight> {
+    type Choice = hkt_classification::OuterHkt;
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t>,
+    TLeft: Functor<'t, ReqIn, ReqOut, ReqF1>,
+    TRight: Functor<'t, ReqIn, ReqOut, ReqF1>,
+> Functor<'t, ReqIn, ReqOut, ReqF1> for SumT<TLeft, TRight>
+{
+    fn map<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> B,
+        F1Mut: 'a + FnMut(A) -> B,
+        F1Fn: 'a + Fn(A) -> B,
+        F1Clone: 'a + Fn(A) -> B + Clone,
+        F1Copy: 'a + Fn(A) -> B + Copy,
+        't: 'a,
+    {
+        match fa {
+            Either::Left(tl) => Either::Left(TLeft::map(clone_a, clone_b, f, tl)),
+            Either::Right(tr) => Either::Right(TRight::map(clone_a, clone_b, f, tr)),
+        }
+    }
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t>,
+    TLeft: Foldable<'t, ReqIn, ReqOut, ReqF1>,
+    TRight: Foldable<'t, ReqIn, ReqOut, ReqF1>,
+> Foldable<'t, ReqIn, ReqOut, ReqF1> for SumT<TLeft, TRight>
+{
+    fn fold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+        f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: Self::F<'a, A>,
+    ) -> FoldWhile<B>
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, A) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, A) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, A) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, A) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, A) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        match fa {
+            Either::Left(tl) => TLeft::fold_while(clone_a, clone_b, f, init, tl),
+            Either::Right(tr) => TRight::fold_while(clone_a, clone_b, f, init, tr),
+        }
+    }
+
+    fn size_hint<'a, A>(s: &Self::F<'a, A>) -> (usize, Option<usize>)
+    where
+        't: 'a,
+    {
+        match s {
+            Either::Left(tl) => TLeft::size_hint(tl),
+            Either::Right(tr) => TRight::size_hint(tr),
+        }
+    }
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t>,
+    TLeft: Traversable<'t, ReqIn, ReqOut, ReqF1> + CloneK<'t, ReqOut>,
+    TRight: Traversable<'t, ReqIn, ReqOut, ReqF1> + CloneK<'t, ReqOut>,
+> Traversable<'t, ReqIn, ReqOut, ReqF1> for SumT<TLeft, TRight>
+{
+    fn traverse<'a, A, B, F, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> F::F<'a, Self::F<'a, B>>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> F::F<'a, B>,
+        F1Mut: 'a + FnMut(A) -> F::F<'a, B>,
+        F1Fn: 'a + Fn(A) -> F::F<'a, B>,
+        F1Clone: 'a + Fn(A) -> F::F<'a, B> + Clone,
+        F1Copy: 'a + Fn(A) -> F::F<'a, B> + Copy,
+        F: Applicative<'t, ReqIn, ReqOut, ReqF1> + Functor<'t, ReqIn, ConstBool<false>, ReqF1>,
+        't: 'a,
+    {
+        let clone_either = {
+            let clone_b = clone_b.clone();
+            move |either: &Either<TLeft::F<'a, B>, TRight::F<'a, B>>| match either {
+                Either::Left(tl) => Either::Left(TLeft::clone(clone_b.clone(), tl)),
+                Either::Right(tr) => Either::Right(TRight::clone(clone_b.clone(), tr)),
+            }
+        };
+
+        match fa {
+            Either::Left(tl) => {
+                let tag = ReqF1::create_from(&f, Either::Left);
+                let tl = TLeft::traverse::<_, _, F, _, _, _, _, _>(clone_a, clone_b.clone(), f, tl);
+
+                <F as Functor<'t, ReqIn, ReqOut, ReqF1>>::map(
+                    move |tl| TLeft::clone(clone_b.clone(), tl).pipe(ReqIn::into_guarded),
+                    move |either| clone_either(either).pipe(ReqOut::into_guarded),
+                    tag,
+                    tl,
+                )
+            }
+            Either::Right(tr) => {
+                let tag = ReqF1::create_from(&f, Either::Right);
+                let tr =
+                    TRight::traverse::<_, _, F, _, _, _, _, _>(clone_a, clone_b.clone(), f, tr);
+
+                <F as Functor<'t, ReqIn, ReqOut, ReqF1>>::map(
+                    move |tr| TRight::clone(clone_b.clone(), tr).pipe(ReqIn::into_guarded),
+                    move |either| clone_either(either).pipe(ReqOut::into_guarded),
+                    tag,
+                    tr,
+                )
+            }
+        }
+    }
+}
+
+impl<'t, TLeft: CovariantK<'t>, TRight: CovariantK<'t>> CovariantK<'t> for SumT<TLeft, TRight> {
+    fn covariant_convert<'a, 'b, A>(a: Self::F<'a, A>) -> Self::F<'b, A>
+    where
+        A: 'a,
+        'a: 'b,
+        't: 'a + 'b,
+    {
+        a.map_either(TLeft::covariant_convert, TRight::covariant_convert)
+    }
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, TLeft: CloneK<'t, ReqIn>, TRight: CloneK<'t, ReqIn>>
+    CloneK<'t, ReqIn> for SumT<TLeft, TRight>
+{
+    fn clone<'a, A>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        a: &Self::F<'a, A>,
+    ) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        match a {
+            Either::Left(tl) => Either::Left(TLeft::clone(clone_a, tl)),
+            Either::Right(tr) => Either::Right(TRight::clone(clone_a, tr)),
+        }
+    }
+}
@@ -1,41 +1,21 @@
-use core::{convert::Infallible, marker::PhantomData};
+use core::{convert::Infallible, marker::PhantomData, ops::Deref as _};
 
 use alloc::sync::Arc;
+use tap::Pipe as _;
 
-use crate::hkt::{
-    DerefHkt, Hkt, HktUnsized, UnsizedHkt, UnsizedHktUnsized,
-    hkt_classification::{self, HktClassification},
-    id::IdHkt,
+use crate::{
+    hkt::{
+        CloneK, DerefHkt, FoldWhile, Foldable, Functor, Hkt, HktUnsized, UnsizedHkt,
+        UnsizedHktUnsized,
+        hkt_classification::{self, HktClassification},
+        id::IdHkt,
+        one_of::{OneOf5, OneOf5Hkt},
+        reference::{RefMutT, RefT},
+    },
+    marker_classification::{ConstBool, TyEq, TypeGuard},
 };
 
-pub struct ArcT<TInner = IdHkt>(Infallible, PhantomData<TInner>);
-
-impl<'t, TInner: UnsizedHkt<'t>> Hkt<'t> for ArcT<TInner> {
-    type F<'a, A: 'a>
-        = Arc<TInner::UnsizedF<'a, A>>
-    where
-        't: 'a;
-}
-
-impl<'t, TInner: UnsizedHktUnsized<'t>> HktUnsized<'t> for ArcT<TInner> {
-    type FUnsized<'a, A: 'a + ?Sized>
-        = Arc<TInner::UnsizedFUnsized<'a, A>>
-    where
-        't: 'a;
-}
-
-impl<TInner> HktClassification for ArcT<TInner> {
-    type Choice = hkt_classification::OuterHkt;
-}
-
-impl<'t, TInner: DerefHkt<'t>> DerefHkt<'t> for ArcT<TInner> {
-    fn deref<'a, 'b: 'a, A: 'a + ?Sized>(fa: &'a Self::UnsizedFUnsized<'b, A>) -> &'a A
-    where
-        't: 'b + 'a,
-    {
-        TInner::deref(fa)
-    }
-}
+impl_shared_pointer_hkt!(ArcT, Arc);
 
 // // Doesn't work:
 // pub struct ArcLT<'a, TInner>(Infallible, PhantomData<&'a TInner>);
@@ -51,15 +31,56 @@ pub use use_std::*;
 
 #[cfg(not(feature = "no-std"))]
 mod use_std {
-    use core::{convert::Infallible, marker::PhantomData};
+    use core::{convert::Infallible, marker::PhantomData, ops::Add};
 
-    use std::sync::Mutex;
+    use std::sync::{LockResult, Mutex, RwLock};
 
-    use crate::hkt::{
-        Hkt, HktUnsized, hkt_classification::{self, HktClassification},
-        id::IdHkt,
+    use tap::Pipe as _;
+
+    use crate::{
+        hkt::{
+            Applicative, CloneK, FoldWhile, Foldable, Functor, Hkt, HktUnsized, Monoid, Semigroup,
+            Traversable,
+            hkt_classification::{self, HktClassification},
+            id::IdHkt,
+            one_of::OneOf5Hkt,
+            result::ResultT,
+        },
+        marker_classification::{AssertBlankOutput, ConstBool, TypeGuard},
     };
 
+    /// Error recorded in place of the contents of a poisoned lock.
+    ///
+    /// Use [ValidatedT](crate::hkt::validated::ValidatedT) to accumulate it across several locks,
+    /// combining two [Poisoned] values with [Semigroup] yields [Poisoned].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Poisoned;
+
+    /// Required by the [Applicative] of [ResultT].
+    impl Add for Poisoned {
+        type Output = Poisoned;
+
+        fn add(self, _: Self) -> Self::Output {
+            Poisoned
+        }
+    }
+
+    impl Semigroup for Poisoned {
+        fn combine(self, _: Self) -> Self {
+            Poisoned
+        }
+    }
+
+    impl Monoid for Poisoned {
+        fn empty() -> Self {
+            Poisoned
+        }
+    }
+
+    fn flatten_poisoned<T>(result: LockResult<Result<T, Poisoned>>) -> Result<T, Poisoned> {
+        result.unwrap_or(Err(Poisoned))
+    }
+
     ///
     /// # Safety
     ///
@@ -83,4 +104,176 @@ mod use_std {
     impl<TInner> HktClassification for MutexT<TInner> {
         type Choice = hkt_classification::OuterHkt;
     }
+
+    pub struct RwLockT<TInner = IdHkt>(Infallible, PhantomData<TInner>);
+
+    impl<'t, TInner: Hkt<'t>> Hkt<'t> for RwLockT<TInner> {
+        type F<'a, A: 'a>
+            = RwLock<TInner::F<'a, A>>
+        where
+            't: 'a;
+    }
+
+    impl<'t, TInner: HktUnsized<'t>> HktUnsized<'t> for RwLockT<TInner> {
+        type FUnsized<'a, A: 'a + ?Sized>
+            = RwLock<TInner::FUnsized<'a, A>>
+        where
+            't: 'a;
+    }
+
+    impl<TInner> HktClassification for RwLockT<TInner> {
+        type Choice = hkt_classification::OuterHkt;
+    }
+
+    /// Instances consume the lock with `into_inner`.
+    ///
+    /// They are only given for locks over a [`ResultT<Poisoned, _>`](ResultT) layer:
+    /// a poisoned lock is read as [`Err(Poisoned)`](Poisoned) and the operation continues as for [ResultT],
+    /// so poisoning shows up in the output instead of panicking.
+    macro_rules! impl_lock_hkt {
+        ($hkt:ident, $lock:ident) => {
+            impl<TInner> $hkt<TInner> {
+                /// Consumes the lock, replacing the contents of a poisoned lock with [Poisoned].
+                pub fn into_result<'t, 'a, A>(
+                    fa: <Self as Hkt<'t>>::F<'a, A>,
+                ) -> <ResultT<Poisoned, TInner> as Hkt<'t>>::F<'a, A>
+                where
+                    TInner: Hkt<'t>,
+                    A: 'a,
+                    't: 'a,
+                {
+                    fa.into_inner().map_err(|_| Poisoned)
+                }
+            }
+
+            impl<
+                't,
+                ReqIn: TypeGuard<'t>,
+                ReqOut: TypeGuard<'t>,
+                ReqF1: OneOf5Hkt<'t>,
+                TInner: Functor<'t, ReqIn, ReqOut, ReqF1>,
+            > Functor<'t, ReqIn, ReqOut, ReqF1> for $hkt<ResultT<Poisoned, TInner>>
+            {
+                fn map<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                    clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+                    clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+                    f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+                    fa: Self::F<'a, A>,
+                ) -> Self::F<'a, B>
+                where
+                    A: 'a,
+                    B: 'a,
+                    F1Once: 'a + FnOnce(A) -> B,
+                    F1Mut: 'a + FnMut(A) -> B,
+                    F1Fn: 'a + Fn(A) -> B,
+                    F1Clone: 'a + Fn(A) -> B + Clone,
+                    F1Copy: 'a + Fn(A) -> B + Copy,
+                    't: 'a,
+                {
+                    <ResultT<Poisoned, TInner> as Functor<'t, ReqIn, ReqOut, ReqF1>>::map(
+                        clone_a,
+                        clone_b,
+                        f,
+                        fa.into_inner().pipe(flatten_poisoned),
+                    )
+                    .pipe($lock::new)
+                }
+            }
+
+            impl<
+                't,
+                ReqIn: TypeGuard<'t>,
+                ReqOut: TypeGuard<'t>,
+                ReqF1: OneOf5Hkt<'t>,
+                TInner: Foldable<'t, ReqIn, ReqOut, ReqF1>,
+            > Foldable<'t, ReqIn, ReqOut, ReqF1> for $hkt<ResultT<Poisoned, TInner>>
+            {
+                fn fold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                    clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+                    clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+                    f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+                    init: B,
+                    fa: Self::F<'a, A>,
+                ) -> FoldWhile<B>
+                where
+                    A: 'a,
+                    B: 'b,
+                    F1Once: 'f + FnOnce(B, A) -> FoldWhile<B>,
+                    F1Mut: 'f + FnMut(B, A) -> FoldWhile<B>,
+                    F1Fn: 'f + Fn(B, A) -> FoldWhile<B>,
+                    F1Clone: 'f + Fn(B, A) -> FoldWhile<B> + Clone,
+                    F1Copy: 'f + Fn(B, A) -> FoldWhile<B> + Copy,
+                    'a: 'f,
+                    'b: 'f,
+                    't: 'a + 'b,
+                {
+                    <ResultT<Poisoned, TInner> as Foldable<'t, ReqIn, ReqOut, ReqF1>>::fold_while(
+                        clone_a,
+                        clone_b,
+                        f,
+                        init,
+                        fa.into_inner().pipe(flatten_poisoned),
+                    )
+                }
+            }
+
+            impl<
+                't,
+                ReqIn: TypeGuard<'t>,
+                ReqOut: TypeGuard<'t>,
+                ReqF1: OneOf5Hkt<'t>,
+                TInner: Traversable<'t, ReqIn, ReqOut, ReqF1> + CloneK<'t, ReqOut>,
+            > Traversable<'t, ReqIn, ReqOut, ReqF1> for $hkt<ResultT<Poisoned, TInner>>
+            {
+                fn traverse<'a, A, B, F, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                    clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+                    clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+                    f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+                    fa: Self::F<'a, A>,
+                ) -> F::F<'a, Self::F<'a, B>>
+                where
+                    A: 'a,
+                    B: 'a,
+                    F1Once: 'a + FnOnce(A) -> F::F<'a, B>,
+                    F1Mut: 'a + FnMut(A) -> F::F<'a, B>,
+                    F1Fn: 'a + Fn(A) -> F::F<'a, B>,
+                    F1Clone: 'a + Fn(A) -> F::F<'a, B> + Clone,
+                    F1Copy: 'a + Fn(A) -> F::F<'a, B> + Copy,
+                    F: Applicative<'t, ReqIn, ReqOut, ReqF1>
+                        + Functor<'t, ReqIn, ConstBool<false>, ReqF1>,
+                    't: 'a,
+                {
+                    let f_lock = ReqF1::create_from(&f, $lock::new);
+
+                    let result = <ResultT<Poisoned, TInner> as Traversable<
+                        't,
+                        ReqIn,
+                        ReqOut,
+                        ReqF1,
+                    >>::traverse::<A, B, F, _, _, _, _, _>(
+                        clone_a,
+                        clone_b.clone(),
+                        f,
+                        fa.into_inner().pipe(flatten_poisoned),
+                    );
+
+                    <F as Functor<'t, ReqIn, ConstBool<false>, ReqF1>>::map(
+                        move |tb| {
+                            <ResultT<Poisoned, TInner> as CloneK<'t, ReqOut>>::clone(
+                                clone_b.clone(),
+                                tb,
+                            )
+                            .pipe(ReqIn::into_guarded)
+                        },
+                        |_| AssertBlankOutput,
+                        f_lock,
+                        result,
+                    )
+                }
+            }
+        };
+    }
+
+    impl_lock_hkt!(MutexT, Mutex);
+    impl_lock_hkt!(RwLockT, RwLock);
 }
@@ -0,0 +1,402 @@
+use core::{convert::Infallible, marker::PhantomData};
+
+use tap::Pipe as _;
+
+use crate::{
+    hkt::{
+        Applicative, Bifunctor, CloneK, CovariantK, FoldWhile, Foldable, Functor, Hkt, Monad,
+        PhantomMarker, Pure, Semigroup, Traversable,
+        hkt_classification::{self, HktClassification},
+        id::IdHkt,
+        one_of::{NotT5Of5, OneOf5, OneOf5Hkt},
+    },
+    marker_classification::{AssertBlankOutput, ConstBool, TypeGuard},
+};
+
+const HAS_LEFT: &str = "a missing right value implies a left value";
+
+/// Either a left value, a right value, or both.
+///
+/// Produced by [Align](crate::hkt::Align) when the two sides have different shapes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum These<L, R> {
+    This(L),
+    That(R),
+    Both(L, R),
+}
+
+impl<L, R> These<L, R> {
+    pub fn as_ref(&self) -> These<&L, &R> {
+        match self {
+            These::This(l) => These::This(l),
+            These::That(r) => These::That(r),
+            These::Both(l, r) => These::Both(l, r),
+        }
+    }
+
+    pub fn left(self) -> Option<L> {
+        match self {
+            These::This(l) | These::Both(l, _) => Some(l),
+            These::That(_) => None,
+        }
+    }
+
+    pub fn right(self) -> Option<R> {
+        match self {
+            These::That(r) | These::Both(_, r) => Some(r),
+            These::This(_) => None,
+        }
+    }
+
+    /// Returns [None] unless both values are present.
+    pub fn both(self) -> Option<(L, R)> {
+        match self {
+            These::Both(l, r) => Some((l, r)),
+            _ => None,
+        }
+    }
+
+    pub fn swap(self) -> These<R, L> {
+        match self {
+            These::This(l) => These::That(l),
+            These::That(r) => These::This(r),
+            These::Both(l, r) => These::Both(r, l),
+        }
+    }
+
+    pub fn into_options(self) -> (Option<L>, Option<R>) {
+        match self {
+            These::This(l) => (Some(l), None),
+            These::That(r) => (None, Some(r)),
+            These::Both(l, r) => (Some(l), Some(r)),
+        }
+    }
+
+    pub fn from_options(left: Option<L>, right: Option<R>) -> Option<Self> {
+        match (left, right) {
+            (Some(l), Some(r)) => Some(These::Both(l, r)),
+            (Some(l), None) => Some(These::This(l)),
+            (None, Some(r)) => Some(These::That(r)),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Zips two iterators until both are exhausted.
+pub(crate) fn align_iter<L, R>(
+    left: impl IntoIterator<Item = L>,
+    right: impl IntoIterator<Item = R>,
+) -> impl Iterator<Item = These<L, R>> {
+    let mut left = left.into_iter().fuse();
+    let mut right = right.into_iter().fuse();
+
+    core::iter::from_fn(move || These::from_options(left.next(), right.next()))
+}
+
+fn combine<L: Semigroup>(l1: Option<L>, l2: Option<L>) -> Option<L> {
+    match (l1, l2) {
+        (Some(l1), Some(l2)) => Some(l1.combine(l2)),
+        (l1, l2) => l1.or(l2),
+    }
+}
+
+/// [These] with the `TInner` layer on the right, e.g. warnings alongside an optional result.
+///
+/// [Applicative] and [Monad] combine the left values with [Semigroup], e.g. a [Vec] of warnings, and only drop the right value when it is missing.
+pub struct TheseT<L, TInner>(Infallible, PhantomMarker<(TInner, L)>);
+
+impl<'t, L: 't, TInner: Hkt<'t>> Hkt<'t> for TheseT<L, TInner> {
+    type F<'a, A: 'a>
+        = These<L, TInner::F<'a, A>>
+    where
+        't: 'a;
+}
+
+impl<L, TInner> HktClassification for TheseT<L, TInner> {
+    type Choice = hkt_classification::OuterHkt;
+}
+
+impl<
+    't,
+    L: 't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t>,
+    TInner: Functor<'t, ReqIn, ReqOut, ReqF1>,
+> Functor<'t, ReqIn, ReqOut, ReqF1> for TheseT<L, TInner>
+{
+    fn map<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> B,
+        F1Mut: 'a + FnMut(A) -> B,
+        F1Fn: 'a + Fn(A) -> B,
+        F1Clone: 'a + Fn(A) -> B + Clone,
+        F1Copy: 'a + Fn(A) -> B + Copy,
+        't: 'a,
+    {
+        match fa {
+            These::This(l) => These::This(l),
+            These::That(ta) => These::That(TInner::map(clone_a, clone_b, f, ta)),
+            These::Both(l, ta) => These::Both(l, TInner::map(clone_a, clone_b, f, ta)),
+        }
+    }
+}
+
+impl<
+    't,
+    L: 't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t>,
+    TInner: Functor<'t, ReqIn, ReqOut, ReqF1>,
+> Bifunctor<'t, ReqIn, ReqOut, ReqF1> for TheseT<L, TInner>
+{
+    type Left = L;
+    type WithLeft<L2: 't> = TheseT<L2, TInner>;
+
+    fn map_left<'a, A, L2: 't>(
+        f_left: impl FnOnce(L) -> L2,
+        fa: Self::F<'a, A>,
+    ) -> These<L2, TInner::F<'a, A>>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        match fa {
+            These::This(l) => These::This(f_left(l)),
+            These::That(ta) => These::That(ta),
+            These::Both(l, ta) => These::Both(f_left(l), ta),
+        }
+    }
+}
+
+impl<
+    't,
+    L: 't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t>,
+    TInner: Foldable<'t, ReqIn, ReqOut, ReqF1>,
+> Foldable<'t, ReqIn, ReqOut, ReqF1> for TheseT<L, TInner>
+{
+    fn fold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+        f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: Self::F<'a, A>,
+    ) -> FoldWhile<B>
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, A) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, A) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, A) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, A) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, A) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        match fa {
+            These::That(ta) | These::Both(_, ta) => {
+                TInner::fold_while(clone_a, clone_b, f, init, ta)
+            }
+            These::This(_) => FoldWhile::Break(init),
+        }
+    }
+}
+
+impl<'t, L: 't, ReqIn: TypeGuard<'t>, TInner: Pure<'t, ReqIn>> Pure<'t, ReqIn>
+    for TheseT<L, TInner>
+{
+    fn pure<'a, A>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        a: A,
+    ) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        TInner::pure(clone_a, a).pipe(These::That)
+    }
+}
+
+impl<
+    't,
+    L: 't + Semigroup,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t>,
+    TInner: Applicative<'t, ReqIn, ReqOut, ReqF1>,
+> Applicative<'t, ReqIn, ReqOut, ReqF1> for TheseT<L, TInner>
+{
+    fn apply<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        ff: Self::F<'a, <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> B,
+        F1Mut: 'a + FnMut(A) -> B,
+        F1Fn: 'a + Fn(A) -> B,
+        F1Clone: 'a + Fn(A) -> B + Clone,
+        F1Copy: 'a + Fn(A) -> B + Copy,
+        't: 'a,
+    {
+        let (lf, tf) = ff.into_options();
+        let (la, ta) = fa.into_options();
+
+        let tb = match (tf, ta) {
+            (Some(tf), Some(ta)) => Some(TInner::apply(clone_a, clone_b, tf, ta)),
+            _ => None,
+        };
+
+        These::from_options(combine(lf, la), tb).expect(HAS_LEFT)
+    }
+}
+
+impl<'t, L: 't + Semigroup, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>
+    Monad<'t, ReqIn, ReqOut, ReqF1> for TheseT<L, IdHkt>
+{
+    fn bind<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        _clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        fa: Self::F<'a, A>,
+        f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> Self::F<'a, B>,
+        F1Mut: 'a + FnMut(A) -> Self::F<'a, B>,
+        F1Fn: 'a + Fn(A) -> Self::F<'a, B>,
+        F1Clone: 'a + Fn(A) -> Self::F<'a, B> + Clone,
+        F1Copy: 'a + Fn(A) -> Self::F<'a, B> + Copy,
+        't: 'a,
+    {
+        let (l, a) = match fa {
+            These::This(l) => return These::This(l),
+            These::That(a) => (None, a),
+            These::Both(l, a) => (Some(l), a),
+        };
+
+        let (l2, b) = match_one_of_5!(ReqF1::into_one_of_5_enum(f), f, f(a)).into_options();
+
+        These::from_options(combine(l, l2), b).expect(HAS_LEFT)
+    }
+}
+
+/// The left value is cloned into the result of `F`.
+impl<
+    't,
+    L: 't + Clone,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t> + NotT5Of5<'t>,
+    TInner: Traversable<'t, ReqIn, ReqOut, ReqF1> + CloneK<'t, ReqOut>,
+> Traversable<'t, ReqIn, ReqOut, ReqF1> for TheseT<L, TInner>
+{
+    fn traverse<'a, A, B, F, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> F::F<'a, Self::F<'a, B>>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> F::F<'a, B>,
+        F1Mut: 'a + FnMut(A) -> F::F<'a, B>,
+        F1Fn: 'a + Fn(A) -> F::F<'a, B>,
+        F1Clone: 'a + Fn(A) -> F::F<'a, B> + Clone,
+        F1Copy: 'a + Fn(A) -> F::F<'a, B> + Copy,
+        F: Applicative<'t, ReqIn, ReqOut, ReqF1> + Functor<'t, ReqIn, ConstBool<false>, ReqF1>,
+        't: 'a,
+    {
+        let tag = ReqF1::create_from(&f, ());
+
+        let (l, ta) = match fa {
+            These::This(l) => {
+                return F::pure(
+                    move |these: &These<L, TInner::F<'a, B>>| {
+                        match these.as_ref() {
+                            These::This(l) => These::This(l.clone()),
+                            These::That(tb) => These::That(TInner::clone(clone_b.clone(), tb)),
+                            These::Both(l, tb) => {
+                                These::Both(l.clone(), TInner::clone(clone_b.clone(), tb))
+                            }
+                        }
+                        .pipe(ReqIn::into_guarded)
+                    },
+                    These::This(l),
+                );
+            }
+            These::That(ta) => (None, ta),
+            These::Both(l, ta) => (Some(l), ta),
+        };
+
+        let tb = TInner::traverse::<_, _, F, _, _, _, _, _>(clone_a, clone_b.clone(), f, ta);
+
+        let with_left = ReqF1::create_from(&tag, move |tb| {
+            These::from_options(l.clone(), Some(tb)).expect("the right value is present")
+        })
+        .pipe(|f| {
+            ReqF1::arbitrary_t5(
+                f,
+                PhantomData::<fn(TInner::F<'a, B>) -> These<L, TInner::F<'a, B>>>,
+            )
+        });
+
+        <F as Functor<'t, ReqIn, ConstBool<false>, ReqF1>>::map(
+            move |tb| TInner::clone(clone_b.clone(), tb).pipe(ReqIn::into_guarded),
+            |_| AssertBlankOutput,
+            with_left,
+            tb,
+        )
+    }
+}
+
+impl<'t, L: 't, TInner: CovariantK<'t>> CovariantK<'t> for TheseT<L, TInner> {
+    fn covariant_convert<'a, 'b, A>(a: Self::F<'a, A>) -> Self::F<'b, A>
+    where
+        A: 'a,
+        'a: 'b,
+        't: 'a + 'b,
+    {
+        match a {
+            These::This(l) => These::This(l),
+            These::That(ta) => These::That(TInner::covariant_convert(ta)),
+            These::Both(l, ta) => These::Both(l, TInner::covariant_convert(ta)),
+        }
+    }
+}
+
+impl<'t, L: 't + Clone, ReqIn: TypeGuard<'t>, TInner: CloneK<'t, ReqIn>> CloneK<'t, ReqIn>
+    for TheseT<L, TInner>
+{
+    fn clone<'a, A>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        a: &Self::F<'a, A>,
+    ) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        match a {
+            These::This(l) => These::This(l.clone()),
+            These::That(ta) => These::That(TInner::clone(clone_a, ta)),
+            These::Both(l, ta) => These::Both(l.clone(), TInner::clone(clone_a, ta)),
+        }
+    }
+}
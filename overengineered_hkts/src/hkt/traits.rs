@@ -6,7 +6,7 @@ use core::{
     panic::{RefUnwindSafe, UnwindSafe},
 };
 
-use alloc::sync::Arc;
+use alloc::{string::String, sync::Arc, vec::Vec};
 
 use dyn_clone::DynClone;
 use tap::Pipe as _;
@@ -16,9 +16,13 @@ use crate::{
         hkt_classification::HktClassification,
         id::IdHkt,
         nullary::NullaryHkt,
-        one_of::{NotT1Of5, NotT2Of5, NotT3Of5, OneOf5Hkt},
+        one_of::{NotT1Of5, NotT2Of5, NotT3Of5, OneOf5Hkt, T4Of5Hkt},
+        reference::RefMutT,
+        these::These,
+    },
+    marker_classification::{
+        AssertBlankOutput, ConstBool, IsConstBool, IsSend, IsSync, TyEq, TypeGuard,
     },
-    marker_classification::{AssertBlankOutput, ConstBool, TypeGuard, TyEq},
 };
 
 /// Alias for all auto traits
@@ -286,8 +290,17 @@ impl<T> Converge for Result<T, T> {
 /// Usecases: Eager & Lazy evaluation.
 ///
 /// `F1` usually needs cloning.
-pub trait Functor<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>:
-    Hkt<'t>
+///
+/// `ReqSend`/`ReqSync` require the closures and values to be [Send]/[Sync], which hkts like
+/// [PinBoxSendFutureT](crate::hkt::future::PinBoxSendFutureT) need to capture them.
+pub trait Functor<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t>,
+    ReqSend: IsConstBool = ConstBool<false>,
+    ReqSync: IsConstBool = ConstBool<false>,
+>: Hkt<'t>
 {
     // Clone-agnostic => clone transitivity proof:
     // If not Clone => Use cloneless
@@ -297,19 +310,29 @@ pub trait Functor<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5
     // If Clone => call produce cloned
     // If unknown => match
     fn map<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
-        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
-        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        clone_a: impl 'a
+            + Fn(&A) -> ReqIn::Output<'a, A>
+            + Clone
+            + IsSend<ReqSend>
+            + IsSync<ReqSync>,
+        clone_b: impl 'a
+            + Fn(&B) -> ReqOut::Output<'a, B>
+            + Clone
+            + IsSend<ReqSend>
+            + IsSync<ReqSync>,
         f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
         fa: Self::F<'a, A>,
     ) -> Self::F<'a, B>
     where
-        A: 'a,
+        A: 'a + IsSend<ReqSend> + IsSync<ReqSync>,
         B: 'a,
         F1Once: 'a + FnOnce(A) -> B,
         F1Mut: 'a + FnMut(A) -> B,
         F1Fn: 'a + Fn(A) -> B,
         F1Clone: 'a + Fn(A) -> B + Clone,
         F1Copy: 'a + Fn(A) -> B + Copy,
+        ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>:
+            IsSend<ReqSend> + IsSync<ReqSync>,
         't: 'a;
 
     // /// Perform map but short-circuits if break is returned.
@@ -360,6 +383,45 @@ pub trait Cofunctor<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneO
         't: 'a;
 }
 
+/// [Functor] whose objects also hold at most one value of a second type [Bifunctor::Left], e.g. the warnings of [TheseT](crate::hkt::these::TheseT).
+///
+/// [Functor::map] maps the right side, [Bifunctor::map_left] changes the hkt to [Bifunctor::WithLeft].
+pub trait Bifunctor<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>:
+    Functor<'t, ReqIn, ReqOut, ReqF1>
+{
+    type Left: 't;
+    type WithLeft<L2: 't>: Bifunctor<'t, ReqIn, ReqOut, ReqF1, Left = L2>;
+
+    fn map_left<'a, A, L2: 't>(
+        f_left: impl FnOnce(Self::Left) -> L2,
+        fa: Self::F<'a, A>,
+    ) -> <Self::WithLeft<L2> as Hkt<'t>>::F<'a, A>
+    where
+        A: 'a,
+        't: 'a;
+
+    /// Maps both sides, `f_left` is only called if the left value is present.
+    fn bimap<'a, A, B, L2: 't, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f_left: impl FnOnce(Self::Left) -> L2,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> <Self::WithLeft<L2> as Hkt<'t>>::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> B,
+        F1Mut: 'a + FnMut(A) -> B,
+        F1Fn: 'a + Fn(A) -> B,
+        F1Clone: 'a + Fn(A) -> B + Clone,
+        F1Copy: 'a + Fn(A) -> B + Copy,
+        't: 'a,
+    {
+        Self::WithLeft::<L2>::map(clone_a, clone_b, f, Self::map_left(f_left, fa))
+    }
+}
+
 /// TODO: Need traits for transformer, transmutation is a necessity.
 pub mod hkt_classification {
     use core::convert::Infallible;
@@ -513,55 +575,113 @@ pub trait Rfoldable<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneO
         't: 'a + 'b;
 }
 
-pub trait Pure<'t, ReqIn: TypeGuard<'t>>: Hkt<'t> {
+/// Mutates every element behind a [RefMutT]`<Self>`.
+pub trait MapInPlace<'t, ReqF1: OneOf5Hkt<'t>>: UnsizedHkt<'t> + Sized {
+    fn map_in_place<'a, 'f, A, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: <RefMutT<Self> as Hkt<'t>>::F<'a, A>,
+    ) where
+        A: 'a,
+        F1Once: 'f + FnOnce(&'a mut A),
+        F1Mut: 'f + FnMut(&'a mut A),
+        F1Fn: 'f + Fn(&'a mut A),
+        F1Clone: 'f + Fn(&'a mut A) + Clone,
+        F1Copy: 'f + Fn(&'a mut A) + Copy,
+        'a: 'f,
+        't: 'a;
+}
+
+pub trait Pure<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqSend: IsConstBool = ConstBool<false>,
+    ReqSync: IsConstBool = ConstBool<false>,
+>: Hkt<'t>
+{
     fn pure<'a, A>(
-        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_a: impl 'a
+            + Fn(&A) -> ReqIn::Output<'a, A>
+            + Clone
+            + IsSend<ReqSend>
+            + IsSync<ReqSync>,
         a: A,
     ) -> Self::F<'a, A>
     where
-        A: 'a,
+        A: 'a + IsSend<ReqSend> + IsSync<ReqSync>,
         't: 'a;
 }
 
 /// `A` and `F1` usually needs cloning.
-pub trait Applicative<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>:
-    Functor<'t, ReqIn, ReqOut, ReqF1> + Pure<'t, ReqIn>
+pub trait Applicative<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t>,
+    ReqSend: IsConstBool = ConstBool<false>,
+    ReqSync: IsConstBool = ConstBool<false>,
+>: Functor<'t, ReqIn, ReqOut, ReqF1, ReqSend, ReqSync> + Pure<'t, ReqIn, ReqSend, ReqSync>
 {
     fn apply<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
-        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
-        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        clone_a: impl 'a
+            + Fn(&A) -> ReqIn::Output<'a, A>
+            + Clone
+            + IsSend<ReqSend>
+            + IsSync<ReqSync>,
+        clone_b: impl 'a
+            + Fn(&B) -> ReqOut::Output<'a, B>
+            + Clone
+            + IsSend<ReqSend>
+            + IsSync<ReqSync>,
         ff: Self::F<'a, ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>>,
         fa: Self::F<'a, A>,
     ) -> Self::F<'a, B>
     where
-        A: 'a,
+        A: 'a + IsSend<ReqSend> + IsSync<ReqSync>,
         B: 'a,
         F1Once: 'a + FnOnce(A) -> B,
         F1Mut: 'a + FnMut(A) -> B,
         F1Fn: 'a + Fn(A) -> B,
         F1Clone: 'a + Fn(A) -> B + Clone,
         F1Copy: 'a + Fn(A) -> B + Copy,
+        ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>:
+            IsSend<ReqSend> + IsSync<ReqSync>,
         't: 'a;
 }
 
 /// `B` and `F1` usually needs cloning.
-pub trait Monad<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>:
-    Applicative<'t, ReqIn, ReqOut, ReqF1>
+pub trait Monad<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t>,
+    ReqSend: IsConstBool = ConstBool<false>,
+    ReqSync: IsConstBool = ConstBool<false>,
+>: Applicative<'t, ReqIn, ReqOut, ReqF1, ReqSend, ReqSync>
 {
     fn bind<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
-        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
-        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        clone_a: impl 'a
+            + Fn(&A) -> ReqIn::Output<'a, A>
+            + Clone
+            + IsSend<ReqSend>
+            + IsSync<ReqSync>,
+        clone_b: impl 'a
+            + Fn(&B) -> ReqOut::Output<'a, B>
+            + Clone
+            + IsSend<ReqSend>
+            + IsSync<ReqSync>,
         fa: Self::F<'a, A>,
         f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
     ) -> Self::F<'a, B>
     where
-        A: 'a,
+        A: 'a + IsSend<ReqSend> + IsSync<ReqSync>,
         B: 'a,
         F1Once: 'a + FnOnce(A) -> Self::F<'a, B>,
         F1Mut: 'a + FnMut(A) -> Self::F<'a, B>,
         F1Fn: 'a + Fn(A) -> Self::F<'a, B>,
         F1Clone: 'a + Fn(A) -> Self::F<'a, B> + Clone,
         F1Copy: 'a + Fn(A) -> Self::F<'a, B> + Copy,
+        ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>:
+            IsSend<ReqSend> + IsSync<ReqSync>,
         't: 'a;
 }
 
@@ -584,6 +704,34 @@ pub(crate) trait MonadT<
         't: 'a;
 }
 
+/// Dual of [Monad]: `extend` runs `f` at every position with the object focused there.
+///
+/// `A` usually needs cloning, once per position.
+pub trait Comonad<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>:
+    Functor<'t, ReqIn, ReqOut, ReqF1>
+{
+    fn extract<'a, A>(fa: Self::F<'a, A>) -> A
+    where
+        A: 'a,
+        't: 'a;
+
+    fn extend<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        fa: Self::F<'a, A>,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(Self::F<'a, A>) -> B,
+        F1Mut: 'a + FnMut(Self::F<'a, A>) -> B,
+        F1Fn: 'a + Fn(Self::F<'a, A>) -> B,
+        F1Clone: 'a + Fn(Self::F<'a, A>) -> B + Clone,
+        F1Copy: 'a + Fn(Self::F<'a, A>) -> B + Copy,
+        't: 'a;
+}
+
 /// `B`, `F1` and `F` usually needs cloning.
 pub trait Traversable<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>:
     Functor<'t, ReqIn, ReqOut, ReqF1> + Foldable<'t, ReqIn, ReqOut, ReqF1>
@@ -606,17 +754,407 @@ pub trait Traversable<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: On
         't: 'a;
 }
 
-#[cfg(false)]
-pub(crate) trait SemigroupK<'t, Req: TypeGuard<'t>>: Hkt<'t> {
+/// Position of each element of an object.
+///
+/// `usize` for sequences, the key type for maps and `()` for objects holding at most one element. [BindT](crate::hkt::bind::BindT) combines indices as tuples.
+pub trait WithIndex<'t>: Hkt<'t> {
+    type Index;
+}
+
+/// [Functor] also passing the [WithIndex::Index] of each element.
+pub trait FunctorWithIndex<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>:
+    WithIndex<'t>
+{
+    fn map_with_index<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(Self::Index, A) -> B,
+        F1Mut: 'a + FnMut(Self::Index, A) -> B,
+        F1Fn: 'a + Fn(Self::Index, A) -> B,
+        F1Clone: 'a + Fn(Self::Index, A) -> B + Clone,
+        F1Copy: 'a + Fn(Self::Index, A) -> B + Copy,
+        't: 'a;
+}
+
+/// [Foldable] also passing the [WithIndex::Index] of each element.
+pub trait FoldableWithIndex<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t>,
+>: WithIndex<'t>
+{
+    fn fold_while_with_index<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+        f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: Self::F<'a, A>,
+    ) -> FoldWhile<B>
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, Self::Index, A) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, Self::Index, A) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, Self::Index, A) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, Self::Index, A) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, Self::Index, A) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b;
+
+    /// [FoldableWithIndex::fold_while_with_index] without short circuiting.
+    fn fold_with_index<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+        f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: Self::F<'a, A>,
+    ) -> B
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, Self::Index, A) -> B,
+        F1Mut: 'f + FnMut(B, Self::Index, A) -> B,
+        F1Fn: 'f + Fn(B, Self::Index, A) -> B,
+        F1Clone: 'f + Fn(B, Self::Index, A) -> B + Clone,
+        F1Copy: 'f + Fn(B, Self::Index, A) -> B + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        let f = map_one_of_5_with!(ReqF1, f, |mut f| move |b, i: Self::Index, a: A| {
+            FoldWhile::Continue(f(b, i, a))
+        });
+
+        Self::fold_while_with_index(clone_a, clone_b, f, init, fa).converge()
+    }
+}
+
+/// [Traversable] also passing the [WithIndex::Index] of each element.
+///
+/// Implemented by pairing each element with its index through [FunctorWithIndex] first.
+pub trait TraversableWithIndex<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t>,
+>: WithIndex<'t>
+{
+    fn traverse_with_index<'a, A, B, F, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> F::F<'a, Self::F<'a, B>>
+    where
+        Self: FunctorWithIndex<'t, ReqIn, ConstBool<false>, ReqF1>
+            + Traversable<'t, ReqIn, ReqOut, ReqF1>,
+        Self::Index: 'a + Clone,
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(Self::Index, A) -> F::F<'a, B>,
+        F1Mut: 'a + FnMut(Self::Index, A) -> F::F<'a, B>,
+        F1Fn: 'a + Fn(Self::Index, A) -> F::F<'a, B>,
+        F1Clone: 'a + Fn(Self::Index, A) -> F::F<'a, B> + Clone,
+        F1Copy: 'a + Fn(Self::Index, A) -> F::F<'a, B> + Copy,
+        F: Applicative<'t, ReqIn, ReqOut, ReqF1> + Functor<'t, ReqIn, ConstBool<false>, ReqF1>,
+        't: 'a,
+    {
+        let pair = ReqF1::create_from(&f, |i: Self::Index, a: A| (i, a));
+
+        let fia = <Self as FunctorWithIndex<'t, ReqIn, ConstBool<false>, ReqF1>>::map_with_index(
+            clone_a.clone(),
+            |_| AssertBlankOutput,
+            pair,
+            fa,
+        );
+
+        <Self as Traversable<'t, ReqIn, ReqOut, ReqF1>>::traverse::<_, _, F, _, _, _, _, _>(
+            move |(i, a): &(Self::Index, A)| ReqIn::map_guarded(clone_a(a), |a| (i.clone(), a)),
+            clone_b,
+            map_one_of_5_with!(ReqF1, f, |mut f| move |(i, a): (Self::Index, A)| f(i, a)),
+            fia,
+        )
+    }
+}
+
+/// Dual of [Traversable]: every object has the same shape, so a `G` of objects turns inside out into an object of `G`s.
+///
+/// [IdHkt] distributes over any [Functor] `G`.
+/// Objects with several positions need a copy of `G` for each of them, so they require `G` to be [CloneK] and `A` usually needs cloning.
+pub trait Distributive<
+    't,
+    ReqIn: TypeGuard<'t>,
+    G: Functor<'t, ConstBool<false>, ConstBool<false>, T4Of5Hkt>,
+>: Hkt<'t>
+{
+    fn distribute<'a, A>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        gfa: G::F<'a, Self::F<'a, A>>,
+    ) -> Self::F<'a, G::F<'a, A>>
+    where
+        A: 'a,
+        't: 'a;
+}
+
+/// Objects of a fixed shape, i.e. functions from their [WithIndex::Index].
+pub trait Representable<'t>: WithIndex<'t> {
+    fn tabulate<'a, A>(f: impl 'a + Fn(Self::Index) -> A + Clone) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a;
+
+    fn index<'a, A>(fa: Self::F<'a, A>, index: Self::Index) -> A
+    where
+        A: 'a,
+        't: 'a;
+}
+
+/// Structurally pairs two objects elementwise, keeping only the positions present in both.
+///
+/// `F1` usually needs cloning.
+pub trait Zip<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>:
+    Hkt<'t>
+{
+    fn zip_with<'a, A, B, C, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqIn::Output<'a, B> + Clone,
+        clone_c: impl 'a + Fn(&C) -> ReqOut::Output<'a, C> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+        fb: Self::F<'a, B>,
+    ) -> Self::F<'a, C>
+    where
+        A: 'a,
+        B: 'a,
+        C: 'a,
+        F1Once: 'a + FnOnce(A, B) -> C,
+        F1Mut: 'a + FnMut(A, B) -> C,
+        F1Fn: 'a + Fn(A, B) -> C,
+        F1Clone: 'a + Fn(A, B) -> C + Clone,
+        F1Copy: 'a + Fn(A, B) -> C + Copy,
+        't: 'a;
+
+    /// [Zip::zip_with] into tuples. `tag` only decides which variant of [OneOf5Hkt] is used.
+    fn zip<'a, A, B>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqIn::Output<'a, B> + Clone,
+        tag: ReqF1::OneOf5F<'a, impl Sized, impl Sized, impl Sized, impl Sized, impl Sized>,
+        fa: Self::F<'a, A>,
+        fb: Self::F<'a, B>,
+    ) -> Self::F<'a, (A, B)>
+    where
+        Self: Zip<'t, ReqIn, ConstBool<false>, ReqF1>,
+        A: 'a,
+        B: 'a,
+        't: 'a,
+    {
+        let f = ReqF1::create_from(&tag, |a: A, b: B| (a, b));
+
+        <Self as Zip<'t, ReqIn, ConstBool<false>, ReqF1>>::zip_with(
+            clone_a,
+            clone_b,
+            |_| AssertBlankOutput,
+            f,
+            fa,
+            fb,
+        )
+    }
+
+    /// Inverse of [Zip::zip].
+    fn unzip<'a, A, B>(fab: Self::F<'a, (A, B)>) -> (Self::F<'a, A>, Self::F<'a, B>)
+    where
+        A: 'a,
+        B: 'a,
+        't: 'a;
+}
+
+/// Like [Zip] but keeps every position present in either object, see [These].
+///
+/// `F1` usually needs cloning.
+pub trait Align<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>:
+    Hkt<'t>
+{
+    fn align_with<'a, A, B, C, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqIn::Output<'a, B> + Clone,
+        clone_c: impl 'a + Fn(&C) -> ReqOut::Output<'a, C> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+        fb: Self::F<'a, B>,
+    ) -> Self::F<'a, C>
+    where
+        A: 'a,
+        B: 'a,
+        C: 'a,
+        F1Once: 'a + FnOnce(These<A, B>) -> C,
+        F1Mut: 'a + FnMut(These<A, B>) -> C,
+        F1Fn: 'a + Fn(These<A, B>) -> C,
+        F1Clone: 'a + Fn(These<A, B>) -> C + Clone,
+        F1Copy: 'a + Fn(These<A, B>) -> C + Copy,
+        't: 'a;
+
+    /// [Align::align_with] into [These]. `tag` only decides which variant of [OneOf5Hkt] is used.
+    fn align<'a, A, B>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqIn::Output<'a, B> + Clone,
+        tag: ReqF1::OneOf5F<'a, impl Sized, impl Sized, impl Sized, impl Sized, impl Sized>,
+        fa: Self::F<'a, A>,
+        fb: Self::F<'a, B>,
+    ) -> Self::F<'a, These<A, B>>
+    where
+        Self: Align<'t, ReqIn, ConstBool<false>, ReqF1>,
+        A: 'a,
+        B: 'a,
+        't: 'a,
+    {
+        let f = ReqF1::create_from(&tag, |these: These<A, B>| these);
+
+        <Self as Align<'t, ReqIn, ConstBool<false>, ReqF1>>::align_with(
+            clone_a,
+            clone_b,
+            |_| AssertBlankOutput,
+            f,
+            fa,
+            fb,
+        )
+    }
+}
+
+/// Builds an object from a seed, the dual of [Foldable] (see [Foldable::extend]).
+///
+/// `step` returns [None] when there are no more elements.
+pub trait Unfoldable<'t, ReqIn: TypeGuard<'t>>: Hkt<'t> {
+    fn unfold<'a, A, S>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        step: impl 'a + FnMut(S) -> Option<(A, S)>,
+        seed: S,
+    ) -> Self::F<'a, A>
+    where
+        A: 'a,
+        S: 'a,
+        't: 'a;
+
+    /// Unfolds `n` clones of `a`.
+    fn replicate<'a, A>(clone_a: impl 'a + Fn(&A) -> A + Clone, n: usize, a: A) -> Self::F<'a, A>
+    where
+        Self: Unfoldable<'t, ConstBool<true>>,
+        A: 'a,
+        't: 'a,
+    {
+        let clone = clone_a.clone();
+
+        <Self as Unfoldable<'t, ConstBool<true>>>::unfold(
+            clone_a,
+            move |(n, a): (usize, A)| match n {
+                0 => None,
+                n => Some((clone(&a), (n - 1, a))),
+            },
+            (n, a),
+        )
+    }
+
+    /// Unfolds the elements of an iterator.
+    fn from_iter<'a, A>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        iter: impl 'a + IntoIterator<Item = A>,
+    ) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        Self::unfold(
+            clone_a,
+            |mut iter| iter.next().map(|a| (a, iter)),
+            iter.into_iter(),
+        )
+    }
+}
+
+/// Combines two objects regardless of `A`.
+pub trait SemigroupK<'t, Req: TypeGuard<'t>>: Hkt<'t> {
     fn combine<'a, A: 'a>(
         a: Self::F<'a, A>,
         b: Self::F<'a, A>,
-        clone_a: impl 'a + Fn(&A) -> Req::Output<'a, A> + Clone
+        clone_a: impl 'a + Fn(&A) -> Req::Output<'a, A> + Clone,
     ) -> Self::F<'a, A>
     where
         't: 'a;
 }
 
+/// Associative combination of two values, e.g. concatenation. Numbers are combined with addition.
+pub trait Semigroup {
+    fn combine(self, other: Self) -> Self;
+}
+
+/// [Semigroup] with an identity element.
+pub trait Monoid: Semigroup {
+    fn empty() -> Self;
+}
+
+impl<T> Semigroup for Vec<T> {
+    fn combine(mut self, other: Self) -> Self {
+        self.extend(other);
+        self
+    }
+}
+
+impl<T> Monoid for Vec<T> {
+    fn empty() -> Self {
+        Vec::new()
+    }
+}
+
+impl Semigroup for String {
+    fn combine(mut self, other: Self) -> Self {
+        self.push_str(&other);
+        self
+    }
+}
+
+impl Monoid for String {
+    fn empty() -> Self {
+        String::new()
+    }
+}
+
+impl Semigroup for () {
+    fn combine(self, _: Self) -> Self {}
+}
+
+impl Monoid for () {
+    fn empty() -> Self {}
+}
+
+macro_rules! impl_additive_monoid {
+    ($($t:ty),+) => {
+        $(
+            impl Semigroup for $t {
+                fn combine(self, other: Self) -> Self {
+                    self + other
+                }
+            }
+
+            impl Monoid for $t {
+                fn empty() -> Self {
+                    0 as $t
+                }
+            }
+        )+
+    };
+}
+
+impl_additive_monoid!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+);
+
 // /// TODO
 // pub(crate) trait ChoiceK<'t>: Applicative<'t> + SemigroupK<'t> {
 //     fn choose<'a, A: 'a>(a: Self::F<'a, A>, b: Self::F<'a, A>) -> Self::F<'a, A>
@@ -929,16 +1467,26 @@ impl<'t> CloneFnHkt<'t> for DynCloneFnHkt {
     }
 }
 
-#[allow(unused)]
-/// Example type
-pub(crate) struct CowT<K = IdHkt>(Infallible, PhantomData<K>);
+/// Either borrows or owns a `K::F<'a, A>`, unlike [Cow](alloc::borrow::Cow) cloning is done by `K`.
+pub struct CowT<K = IdHkt>(Infallible, PhantomData<K>);
 
-#[allow(unused)]
-pub(crate) enum Borrown<'a, T> {
+#[derive(Debug)]
+pub enum Borrown<'a, T> {
     Borrow(&'a T),
     Own(T),
 }
 
+impl<T> core::ops::Deref for Borrown<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match self {
+            Borrown::Borrow(t) => t,
+            Borrown::Own(t) => t,
+        }
+    }
+}
+
 impl<'t, K: Hkt<'t>> Hkt<'t> for CowT<K> {
     type F<'a, A: 'a>
         = Borrown<'a, K::F<'a, A>>
@@ -950,6 +1498,57 @@ impl<K> HktClassification for CowT<K> {
     type Choice = hkt_classification::OuterHkt;
 }
 
+/// Borrowed values are cloned with `clone_a` before mapping, the result is always owned.
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t>,
+    K: Functor<'t, ReqIn, ReqOut, ReqF1> + CloneK<'t, ReqIn>,
+> Functor<'t, ReqIn, ReqOut, ReqF1> for CowT<K>
+{
+    fn map<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> B,
+        F1Mut: 'a + FnMut(A) -> B,
+        F1Fn: 'a + Fn(A) -> B,
+        F1Clone: 'a + Fn(A) -> B + Clone,
+        F1Copy: 'a + Fn(A) -> B + Copy,
+        't: 'a,
+    {
+        let fa = match fa {
+            Borrown::Borrow(ka) => K::clone(clone_a.clone(), ka),
+            Borrown::Own(ka) => ka,
+        };
+
+        Borrown::Own(K::map(clone_a, clone_b, f, fa))
+    }
+}
+
+/// Borrowed values stay borrowed.
+impl<'t, ReqIn: TypeGuard<'t>, K: CloneK<'t, ReqIn>> CloneK<'t, ReqIn> for CowT<K> {
+    fn clone<'a, A>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        a: &Self::F<'a, A>,
+    ) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        match a {
+            Borrown::Borrow(ka) => Borrown::Borrow(ka),
+            Borrown::Own(ka) => Borrown::Own(K::clone(clone_a, ka)),
+        }
+    }
+}
+
 // impl<'t, K: CovariantRefK<'t>> CovariantK<'t> for CowT<K> {
 //     fn covariant_convert<'a, 'b, A: 'a>(a: Self::F<'a, A>) -> Self::F<'b, A>
 //     where
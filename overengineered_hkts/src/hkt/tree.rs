@@ -0,0 +1,587 @@
+use core::{convert::Infallible, marker::PhantomData};
+
+use alloc::{collections::VecDeque, vec, vec::Vec};
+use tap::Pipe;
+
+use crate::{
+    hkt::{
+        Applicative, CloneK, CloneOwnedK, CovariantK, FoldWhile, Foldable, Functor, Hkt,
+        HktUnsized, Monad, Pure, Rfoldable, TCloneableOf5, Traversable,
+        hkt_classification::{self, HktClassification},
+        id::IdHkt,
+        one_of::{NotT1Of5, NotT5Of5, OneOf5, OneOf5Hkt},
+        reference::{RefMutT, RefT},
+        vec::VecT,
+    },
+    marker_classification::{AssertBlankOutput, ConstBool, TypeGuard},
+};
+
+/// A rose tree: a value and any number of subtrees.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Tree<T> {
+    pub value: T,
+    pub children: Vec<Tree<T>>,
+}
+
+impl<T> Tree<T> {
+    pub fn new(value: T, children: Vec<Tree<T>>) -> Self {
+        Self { value, children }
+    }
+
+    pub fn leaf(value: T) -> Self {
+        Self::new(value, Vec::new())
+    }
+
+    pub fn as_ref(&self) -> Tree<&T> {
+        Tree {
+            value: &self.value,
+            children: self.children.iter().map(Tree::as_ref).collect(),
+        }
+    }
+
+    /// Maps in pre-order.
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Tree<U> {
+        self.map_with(&mut f)
+    }
+
+    fn map_with<U>(self, f: &mut impl FnMut(T) -> U) -> Tree<U> {
+        let value = f(self.value);
+
+        Tree {
+            value,
+            children: self
+                .children
+                .into_iter()
+                .map(|child| child.map_with(&mut *f))
+                .collect(),
+        }
+    }
+
+    pub fn into_pre_order(self) -> impl Iterator<Item = T> {
+        let mut stack = vec![self];
+
+        core::iter::from_fn(move || {
+            let Tree { value, children } = stack.pop()?;
+            stack.extend(children.into_iter().rev());
+            Some(value)
+        })
+    }
+
+    /// Pre-order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        let mut stack = vec![self];
+
+        core::iter::from_fn(move || {
+            let Tree { value, children } = stack.pop()?;
+            stack.extend(children.iter().rev());
+            Some(value)
+        })
+    }
+
+    /// Pre-order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        let mut stack = vec![self];
+
+        core::iter::from_fn(move || {
+            let Tree { value, children } = stack.pop()?;
+            stack.extend(children.iter_mut().rev());
+            Some(value)
+        })
+    }
+
+    pub fn into_breadth_first(self) -> impl Iterator<Item = T> {
+        let mut queue = VecDeque::from([self]);
+
+        core::iter::from_fn(move || {
+            let Tree { value, children } = queue.pop_front()?;
+            queue.extend(children);
+            Some(value)
+        })
+    }
+}
+
+/// [Functor], [Foldable], [Traversable] and [CloneK] are stackable, [Applicative] and [Monad] are only implemented over [IdHkt].
+pub struct TreeT<TInner = IdHkt>(Infallible, PhantomData<TInner>);
+
+impl<'t, TInner: Hkt<'t>> Hkt<'t> for TreeT<TInner> {
+    type F<'a, A: 'a>
+        = Tree<TInner::F<'a, A>>
+    where
+        't: 'a;
+}
+
+impl<'t, TInner: HktUnsized<'t>> HktUnsized<'t> for TreeT<TInner> {
+    type FUnsized<'a, A: 'a + ?Sized>
+        = Tree<TInner::FUnsized<'a, A>>
+    where
+        't: 'a;
+}
+
+impl<TInner> HktClassification for TreeT<TInner> {
+    type Choice = hkt_classification::OuterHkt;
+}
+
+impl<'t, TInner: Hkt<'t>> TreeT<TInner> {
+    /// Same as [Foldable::fold_while] but level by level.
+    pub fn fold_breadth_first_while<
+        'a,
+        'b,
+        'f,
+        ReqIn: TypeGuard<'t>,
+        ReqOut: TypeGuard<'t>,
+        ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+        A,
+        B,
+        F1Once,
+        F1Mut,
+        F1Fn,
+        F1Clone,
+        F1Copy,
+    >(
+        clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+        f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: Tree<TInner::F<'a, A>>,
+    ) -> FoldWhile<B>
+    where
+        TInner: Foldable<'t, ReqIn, ReqOut, ReqF1>,
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, A) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, A) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, A) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, A) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, A) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        let mut f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, A) -> FoldWhile<B>>);
+
+        fa.into_breadth_first().try_fold(init, move |b, ka| {
+            let f_ref = ReqF1::as_mut(&mut f)
+                .pipe(|f| map_one_of_5_with!(ReqF1, f, |mut f| |b, a| f(b, a)));
+
+            TInner::fold_while(clone_a.clone(), clone_b.clone(), f_ref, b, ka)
+        })
+    }
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: TCloneableOf5<'t>,
+    TInner: Functor<'t, ReqIn, ReqOut, ReqF1>,
+> Functor<'t, ReqIn, ReqOut, ReqF1> for TreeT<TInner>
+{
+    fn map<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> B,
+        F1Mut: 'a + FnMut(A) -> B,
+        F1Fn: 'a + Fn(A) -> B,
+        F1Clone: 'a + Fn(A) -> B + Clone,
+        F1Copy: 'a + Fn(A) -> B + Copy,
+        't: 'a,
+    {
+        let f = ReqF1::arbitrary_uncloneable(f, PhantomData::<fn(A) -> B>);
+
+        fa.map(move |x| {
+            TInner::map(
+                clone_a.clone(),
+                clone_b.clone(),
+                ReqF1::clone_one_of_5(&f),
+                x,
+            )
+        })
+    }
+}
+
+/// Pre-order.
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+    TInner: Foldable<'t, ReqIn, ReqOut, ReqF1>,
+> Foldable<'t, ReqIn, ReqOut, ReqF1> for TreeT<TInner>
+{
+    fn fold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+        f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: Self::F<'a, A>,
+    ) -> FoldWhile<B>
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, A) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, A) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, A) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, A) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, A) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        let mut f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, A) -> FoldWhile<B>>);
+
+        fa.into_pre_order().try_fold(init, move |b, ka| {
+            let f_ref = ReqF1::as_mut(&mut f)
+                .pipe(|f| map_one_of_5_with!(ReqF1, f, |mut f| |b, a| f(b, a)));
+
+            TInner::fold_while(clone_a.clone(), clone_b.clone(), f_ref, b, ka)
+        })
+    }
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+    TInner: Foldable<'t, ReqIn, ReqOut, ReqF1, RefT>,
+> Foldable<'t, ReqIn, ReqOut, ReqF1, RefT> for TreeT<TInner>
+{
+    fn fold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'f + Fn(&A) -> <ReqIn>::Output<'a, A> + Clone,
+        clone_b: impl 'f + Fn(&B) -> <ReqOut>::Output<'b, B> + Clone,
+        f: <ReqF1>::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: <RefT as Hkt<'t>>::F<'a, Self::F<'a, A>>,
+    ) -> FoldWhile<B>
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        let mut f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, &'a A) -> FoldWhile<B>>);
+
+        fa.iter().try_fold(init, move |b, ka| {
+            let f_ref = ReqF1::as_mut(&mut f)
+                .pipe(|f| map_one_of_5_with!(ReqF1, f, |mut f| |b, a| f(b, a)));
+
+            TInner::fold_while(clone_a.clone(), clone_b.clone(), f_ref, b, ka)
+        })
+    }
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+    TInner: Foldable<'t, ReqIn, ReqOut, ReqF1, RefMutT>,
+> Foldable<'t, ReqIn, ReqOut, ReqF1, RefMutT> for TreeT<TInner>
+{
+    fn fold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'f + Fn(&A) -> <ReqIn>::Output<'a, A> + Clone,
+        clone_b: impl 'f + Fn(&B) -> <ReqOut>::Output<'b, B> + Clone,
+        f: <ReqF1>::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: <RefMutT as Hkt<'t>>::F<'a, Self::F<'a, A>>,
+    ) -> FoldWhile<B>
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, <RefMutT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, <RefMutT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, <RefMutT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, <RefMutT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, <RefMutT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        let mut f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, &'a mut A) -> FoldWhile<B>>);
+
+        fa.iter_mut().try_fold(init, move |b, ka| {
+            let f_ref = ReqF1::as_mut(&mut f)
+                .pipe(|f| map_one_of_5_with!(ReqF1, f, |mut f| |b, a| f(b, a)));
+
+            TInner::fold_while(clone_a.clone(), clone_b.clone(), f_ref, b, ka)
+        })
+    }
+}
+
+/// Reverse of the pre-order, i.e. post-order visiting children from right to left.
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+    TInner: Rfoldable<'t, ReqIn, ReqOut, ReqF1>,
+> Rfoldable<'t, ReqIn, ReqOut, ReqF1> for TreeT<TInner>
+{
+    fn rfold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+        f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: Self::F<'a, A>,
+    ) -> FoldWhile<B>
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, A) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, A) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, A) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, A) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, A) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        let mut f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, A) -> FoldWhile<B>>);
+
+        fa.into_pre_order()
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .try_fold(init, move |b, ka| {
+                let f_ref = ReqF1::as_mut(&mut f)
+                    .pipe(|f| map_one_of_5_with!(ReqF1, f, |mut f| |b, a| f(b, a)));
+
+                TInner::rfold_while(clone_a.clone(), clone_b.clone(), f_ref, b, ka)
+            })
+    }
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, TInner: Pure<'t, ReqIn>> Pure<'t, ReqIn> for TreeT<TInner> {
+    fn pure<'a, A>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        a: A,
+    ) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        TInner::pure(clone_a, a).pipe(Tree::leaf)
+    }
+}
+
+fn apply_tree<A, B, F>(
+    ff: Tree<F>,
+    fa: &Tree<A>,
+    clone_a: &impl Fn(&A) -> A,
+    call: &mut impl FnMut(&F, A) -> B,
+) -> Tree<B> {
+    let Tree { value: f, children } = ff;
+    let value = call(&f, clone_a(&fa.value));
+
+    let mut mapped = fa
+        .children
+        .iter()
+        .map(|child| child.as_ref().map(|a| call(&f, clone_a(a))))
+        .collect::<Vec<_>>();
+
+    mapped.extend(
+        children
+            .into_iter()
+            .map(|tf| apply_tree(tf, fa, clone_a, &mut *call)),
+    );
+
+    Tree::new(value, mapped)
+}
+
+/// Each function is applied to the whole of `fa`, with its own subtrees appended after.
+impl<'t, ReqOut: TypeGuard<'t>, ReqF1: TCloneableOf5<'t>>
+    Applicative<'t, ConstBool<true>, ReqOut, ReqF1> for TreeT
+{
+    fn apply<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> A + Clone,
+        _clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        ff: Self::F<'a, <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> B,
+        F1Mut: 'a + FnMut(A) -> B,
+        F1Fn: 'a + Fn(A) -> B,
+        F1Clone: 'a + Fn(A) -> B + Clone,
+        F1Copy: 'a + Fn(A) -> B + Copy,
+        't: 'a,
+    {
+        let ff = ff.map(|f| ReqF1::arbitrary_uncloneable(f, PhantomData::<fn(A) -> B>));
+
+        apply_tree(ff, &fa, &clone_a, &mut |f, a| {
+            match_one_of_5!(ReqF1::into_one_of_5_enum(ReqF1::clone_one_of_5(f)), f, f(a))
+        })
+    }
+}
+
+fn bind_tree<A, B>(fa: Tree<A>, call: &mut impl FnMut(A) -> Tree<B>) -> Tree<B> {
+    let Tree { value, children } = fa;
+    let mut tree = call(value);
+
+    tree.children.extend(
+        children
+            .into_iter()
+            .map(|child| bind_tree(child, &mut *call)),
+    );
+
+    tree
+}
+
+/// Substitutes every node with the tree returned by `f`, the node's own subtrees are appended after the new ones.
+impl<'t, ReqOut: TypeGuard<'t>, ReqF1: TCloneableOf5<'t>> Monad<'t, ConstBool<true>, ReqOut, ReqF1>
+    for TreeT
+{
+    fn bind<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        _clone_a: impl 'a + Fn(&A) -> A + Clone,
+        _clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        fa: Self::F<'a, A>,
+        f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> Self::F<'a, B>,
+        F1Mut: 'a + FnMut(A) -> Self::F<'a, B>,
+        F1Fn: 'a + Fn(A) -> Self::F<'a, B>,
+        F1Clone: 'a + Fn(A) -> Self::F<'a, B> + Clone,
+        F1Copy: 'a + Fn(A) -> Self::F<'a, B> + Copy,
+        't: 'a,
+    {
+        let f = ReqF1::arbitrary_uncloneable(f, PhantomData::<fn(A) -> Tree<B>>);
+
+        bind_tree(fa, &mut |a| {
+            match_one_of_5!(
+                ReqF1::into_one_of_5_enum(ReqF1::clone_one_of_5(&f)),
+                f,
+                f(a)
+            )
+        })
+    }
+}
+
+/// Traverses in pre-order through [VecT], the shape of the tree is cloned for each result of `F`.
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: TCloneableOf5<'t> + NotT5Of5<'t>,
+    TInner: Traversable<'t, ReqIn, ReqOut, ReqF1> + CloneK<'t, ReqOut>,
+> Traversable<'t, ReqIn, ReqOut, ReqF1> for TreeT<TInner>
+{
+    fn traverse<'a, A, B, F, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> F::F<'a, Self::F<'a, B>>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> F::F<'a, B>,
+        F1Mut: 'a + FnMut(A) -> F::F<'a, B>,
+        F1Fn: 'a + Fn(A) -> F::F<'a, B>,
+        F1Clone: 'a + Fn(A) -> F::F<'a, B> + Clone,
+        F1Copy: 'a + Fn(A) -> F::F<'a, B> + Copy,
+        F: Applicative<'t, ReqIn, ReqOut, ReqF1> + Functor<'t, ReqIn, ConstBool<false>, ReqF1>,
+        't: 'a,
+    {
+        let tag = ReqF1::create_from(&f, ());
+        let shape = fa.as_ref().map(|_| ());
+
+        let values = <VecT<TInner> as Traversable<'t, ReqIn, ReqOut, ReqF1>>::traverse::<
+            A,
+            B,
+            F,
+            _,
+            _,
+            _,
+            _,
+            _,
+        >(
+            clone_a,
+            clone_b.clone(),
+            f,
+            fa.into_pre_order().collect::<Vec<_>>(),
+        );
+
+        let with_shape = ReqF1::create_from(&tag, move |values: Vec<TInner::F<'a, B>>| {
+            let mut values = values.into_iter();
+
+            shape
+                .clone()
+                .map(|()| values.next().expect("traverse preserves the node count"))
+        })
+        .pipe(|f| {
+            ReqF1::arbitrary_t5(
+                f,
+                PhantomData::<fn(Vec<TInner::F<'a, B>>) -> Tree<TInner::F<'a, B>>>,
+            )
+        });
+
+        <F as Functor<'t, ReqIn, ConstBool<false>, ReqF1>>::map(
+            move |vec: &Vec<TInner::F<'a, B>>| {
+                vec.iter()
+                    .map(|b| TInner::clone(clone_b.clone(), b))
+                    .collect::<Vec<_>>()
+                    .pipe(ReqIn::into_guarded)
+            },
+            |_| AssertBlankOutput,
+            with_shape,
+            values,
+        )
+    }
+}
+
+impl<'t, TInner: CovariantK<'t>> CovariantK<'t> for TreeT<TInner> {
+    fn covariant_convert<'a, 'b, A>(a: Self::F<'a, A>) -> Self::F<'b, A>
+    where
+        A: 'a,
+        'a: 'b,
+        't: 'a + 'b,
+    {
+        a.map(TInner::covariant_convert)
+    }
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, TInner: CloneOwnedK<'t, ReqIn>> CloneOwnedK<'t, ReqIn>
+    for TreeT<TInner>
+{
+    fn clone_owned<'a, 'b, A>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'b, A> + Clone,
+        a: &Self::F<'a, A>,
+    ) -> Self::F<'b, A>
+    where
+        A: 'a + 'b,
+        't: 'a + 'b,
+    {
+        a.as_ref()
+            .map(|ta| TInner::clone_owned(clone_a.clone(), ta))
+    }
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, TInner: CloneK<'t, ReqIn>> CloneK<'t, ReqIn> for TreeT<TInner> {
+    fn clone<'a, A>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        a: &Self::F<'a, A>,
+    ) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        a.as_ref().map(|ta| TInner::clone(clone_a.clone(), ta))
+    }
+}
@@ -0,0 +1,340 @@
+use core::convert::Infallible;
+
+use tap::Pipe as _;
+
+use crate::{
+    hkt::{
+        Applicative, Bifunctor, CloneK, CovariantK, FoldWhile, Foldable, Functor, Hkt, HktUnsized,
+        PhantomMarker, Pure, Semigroup, Traversable,
+        hkt_classification::{self, HktClassification},
+        one_of::OneOf5Hkt,
+        result::ResultT,
+    },
+    marker_classification::{ConstBool, TypeGuard},
+};
+
+/// [Result] whose errors are accumulated instead of short-circuiting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Validated<A, E> {
+    Valid(A),
+    Invalid(E),
+}
+
+impl<A, E> Validated<A, E> {
+    pub fn as_ref(&self) -> Validated<&A, &E> {
+        match self {
+            Validated::Valid(a) => Validated::Valid(a),
+            Validated::Invalid(e) => Validated::Invalid(e),
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        matches!(self, Validated::Valid(_))
+    }
+
+    pub fn into_result(self) -> Result<A, E> {
+        self.into()
+    }
+}
+
+impl<A, E> From<Result<A, E>> for Validated<A, E> {
+    fn from(value: Result<A, E>) -> Self {
+        match value {
+            Ok(a) => Validated::Valid(a),
+            Err(e) => Validated::Invalid(e),
+        }
+    }
+}
+
+impl<A, E> From<Validated<A, E>> for Result<A, E> {
+    fn from(value: Validated<A, E>) -> Self {
+        match value {
+            Validated::Valid(a) => Ok(a),
+            Validated::Invalid(e) => Err(e),
+        }
+    }
+}
+
+/// Applicative over [Validated] that combines the errors of both operands with [Semigroup], e.g. [Vec] or [NonEmptyVec](crate::hkt::non_empty_vec::NonEmptyVec),
+/// so traversing into it collects every error instead of the first.
+///
+/// There is no [Monad](crate::hkt::Monad): `bind` cannot run its continuation without a value,
+/// convert with [into_result](ValidatedT::into_result) to sequence dependent validations.
+pub struct ValidatedT<E, TInner>(Infallible, PhantomMarker<(TInner, E)>);
+
+impl<'t, TInner: Hkt<'t>, E: 't> Hkt<'t> for ValidatedT<E, TInner> {
+    type F<'a, A: 'a>
+        = Validated<TInner::F<'a, A>, E>
+    where
+        't: 'a;
+}
+
+impl<'t, TInner: HktUnsized<'t>, E: 't> HktUnsized<'t> for ValidatedT<E, TInner> {
+    type FUnsized<'a, A: 'a + ?Sized>
+        = Validated<TInner::FUnsized<'a, A>, E>
+    where
+        't: 'a;
+}
+
+impl<TInner, E> HktClassification for ValidatedT<E, TInner> {
+    type Choice = hkt_classification::OuterHkt;
+}
+
+impl<E, TInner> ValidatedT<E, TInner> {
+    pub fn from_result<'t, 'a, A>(
+        fa: <ResultT<E, TInner> as Hkt<'t>>::F<'a, A>,
+    ) -> <Self as Hkt<'t>>::F<'a, A>
+    where
+        TInner: Hkt<'t>,
+        E: 't,
+        A: 'a,
+        't: 'a,
+    {
+        fa.into()
+    }
+
+    pub fn into_result<'t, 'a, A>(
+        fa: <Self as Hkt<'t>>::F<'a, A>,
+    ) -> <ResultT<E, TInner> as Hkt<'t>>::F<'a, A>
+    where
+        TInner: Hkt<'t>,
+        E: 't,
+        A: 'a,
+        't: 'a,
+    {
+        fa.into()
+    }
+}
+
+impl<
+    't,
+    E: 't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t>,
+    TInner: Functor<'t, ReqIn, ReqOut, ReqF1>,
+> Functor<'t, ReqIn, ReqOut, ReqF1> for ValidatedT<E, TInner>
+{
+    fn map<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> B,
+        F1Mut: 'a + FnMut(A) -> B,
+        F1Fn: 'a + Fn(A) -> B,
+        F1Clone: 'a + Fn(A) -> B + Clone,
+        F1Copy: 'a + Fn(A) -> B + Copy,
+        't: 'a,
+    {
+        match fa {
+            Validated::Valid(ta) => Validated::Valid(TInner::map(clone_a, clone_b, f, ta)),
+            Validated::Invalid(e) => Validated::Invalid(e),
+        }
+    }
+}
+
+/// The errors are on the left.
+impl<
+    't,
+    E: 't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t>,
+    TInner: Functor<'t, ReqIn, ReqOut, ReqF1>,
+> Bifunctor<'t, ReqIn, ReqOut, ReqF1> for ValidatedT<E, TInner>
+{
+    type Left = E;
+    type WithLeft<E2: 't> = ValidatedT<E2, TInner>;
+
+    fn map_left<'a, A, E2: 't>(
+        f_left: impl FnOnce(E) -> E2,
+        fa: Self::F<'a, A>,
+    ) -> Validated<TInner::F<'a, A>, E2>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        match fa {
+            Validated::Valid(ta) => Validated::Valid(ta),
+            Validated::Invalid(e) => Validated::Invalid(f_left(e)),
+        }
+    }
+}
+
+impl<
+    't,
+    E: 't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t>,
+    TInner: Foldable<'t, ReqIn, ReqOut, ReqF1>,
+> Foldable<'t, ReqIn, ReqOut, ReqF1> for ValidatedT<E, TInner>
+{
+    fn fold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+        f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: Self::F<'a, A>,
+    ) -> FoldWhile<B>
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, A) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, A) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, A) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, A) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, A) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        match fa {
+            Validated::Valid(ta) => TInner::fold_while(clone_a, clone_b, f, init, ta),
+            Validated::Invalid(_) => FoldWhile::Break(init),
+        }
+    }
+}
+
+impl<'t, E: 't, ReqIn: TypeGuard<'t>, TInner: Pure<'t, ReqIn>> Pure<'t, ReqIn>
+    for ValidatedT<E, TInner>
+{
+    fn pure<'a, A>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        a: A,
+    ) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        TInner::pure(clone_a, a).pipe(Validated::Valid)
+    }
+}
+
+impl<
+    't,
+    E: 't + Semigroup,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t>,
+    TInner: Applicative<'t, ReqIn, ReqOut, ReqF1>,
+> Applicative<'t, ReqIn, ReqOut, ReqF1> for ValidatedT<E, TInner>
+{
+    fn apply<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        ff: Self::F<'a, <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> B,
+        F1Mut: 'a + FnMut(A) -> B,
+        F1Fn: 'a + Fn(A) -> B,
+        F1Clone: 'a + Fn(A) -> B + Clone,
+        F1Copy: 'a + Fn(A) -> B + Copy,
+        't: 'a,
+    {
+        match (ff, fa) {
+            (Validated::Valid(tf), Validated::Valid(ta)) => {
+                Validated::Valid(TInner::apply(clone_a, clone_b, tf, ta))
+            }
+            (Validated::Valid(_), Validated::Invalid(e))
+            | (Validated::Invalid(e), Validated::Valid(_)) => Validated::Invalid(e),
+            (Validated::Invalid(ef), Validated::Invalid(ea)) => Validated::Invalid(ef.combine(ea)),
+        }
+    }
+}
+
+impl<
+    't,
+    E: 't + Clone,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t>,
+    TInner: Traversable<'t, ReqIn, ReqOut, ReqF1> + CloneK<'t, ReqOut>,
+> Traversable<'t, ReqIn, ReqOut, ReqF1> for ValidatedT<E, TInner>
+{
+    fn traverse<'a, A, B, F, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> F::F<'a, Self::F<'a, B>>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> F::F<'a, B>,
+        F1Mut: 'a + FnMut(A) -> F::F<'a, B>,
+        F1Fn: 'a + Fn(A) -> F::F<'a, B>,
+        F1Clone: 'a + Fn(A) -> F::F<'a, B> + Clone,
+        F1Copy: 'a + Fn(A) -> F::F<'a, B> + Copy,
+        F: Applicative<'t, ReqIn, ReqOut, ReqF1> + Functor<'t, ReqIn, ConstBool<false>, ReqF1>,
+        't: 'a,
+    {
+        let f_map = ReqF1::create_from(&f, Validated::Valid);
+        let clone_vb = {
+            let clone_b = clone_b.clone();
+            move |vb: &Validated<TInner::F<'a, B>, E>| match vb {
+                Validated::Valid(tb) => Validated::Valid(TInner::clone(clone_b.clone(), tb)),
+                Validated::Invalid(e) => Validated::Invalid(e.clone()),
+            }
+        };
+
+        match fa {
+            Validated::Valid(ta) => {
+                let res: F::F<'a, TInner::F<'a, B>> =
+                    TInner::traverse::<_, _, F, _, _, _, _, _>(clone_a, clone_b.clone(), f, ta);
+
+                <F as Functor<'t, ReqIn, ReqOut, ReqF1>>::map(
+                    move |tb| TInner::clone(clone_b.clone(), tb).pipe(ReqIn::into_guarded),
+                    move |vb| clone_vb(vb).pipe(ReqOut::into_guarded),
+                    f_map,
+                    res,
+                )
+            }
+            Validated::Invalid(e) => F::pure(
+                move |vb| clone_vb(vb).pipe(ReqIn::into_guarded),
+                Validated::Invalid(e),
+            ),
+        }
+    }
+}
+
+impl<'t, E: 't, TInner: CovariantK<'t>> CovariantK<'t> for ValidatedT<E, TInner> {
+    fn covariant_convert<'a, 'b, A>(a: Self::F<'a, A>) -> Self::F<'b, A>
+    where
+        A: 'a,
+        'a: 'b,
+        't: 'a + 'b,
+    {
+        match a {
+            Validated::Valid(ta) => Validated::Valid(TInner::covariant_convert(ta)),
+            Validated::Invalid(e) => Validated::Invalid(e),
+        }
+    }
+}
+
+impl<'t, E: 't + Clone, ReqIn: TypeGuard<'t>, TInner: CloneK<'t, ReqIn>> CloneK<'t, ReqIn>
+    for ValidatedT<E, TInner>
+{
+    fn clone<'a, A>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        a: &Self::F<'a, A>,
+    ) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        match a {
+            Validated::Valid(ta) => Validated::Valid(TInner::clone(clone_a, ta)),
+            Validated::Invalid(e) => Validated::Invalid(e.clone()),
+        }
+    }
+}
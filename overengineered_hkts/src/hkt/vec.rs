@@ -9,14 +9,18 @@ use tap::Pipe;
 
 use crate::{
     hkt::{
-        Applicative, CloneK, CloneOwnedK, Converge, CovariantK, FoldWhile,
-        Foldable, Functor, Hkt, HktUnsized, Monad, Pure, Rfoldable, TCloneableOf5, Traversable,
+        Align, Applicative, CloneK, CloneOwnedK, Converge, CovariantK, FoldWhile,
+        Foldable, FoldableWithIndex, Functor, FunctorWithIndex, Hkt, HktUnsized, MapInPlace, Monad,
+        Pure,
+        Rfoldable, TCloneableOf5, Traversable, TraversableWithIndex, Unfoldable, WithIndex, Zip,
         hkt_classification::{self, HktClassification},
         id::IdHkt,
-        one_of::{NotT1Of5, NotT5Of5},
+        one_of::{NotT1Of5, NotT5Of5, OneOf5},
         reference::{RefMutT, RefT},
+        slice::SliceT,
+        these::{These, align_iter},
     },
-    marker_classification::{AssertBlankOutput, ConstBool, DependentClone, TypeGuard},
+    marker_classification::{AssertBlankOutput, AutoTraitsK, ConstBool, DependentClone, TypeGuard},
     transmute::unsafe_transmute_id,
     utils::CloneWrapper,
 };
@@ -43,6 +47,9 @@ impl<TInner> HktClassification for VecT<TInner> {
     type Choice = hkt_classification::OuterHkt;
 }
 
+// SAFETY: a [Vec] owns its `TInner::F<'a, A>` elements and nothing else.
+unsafe impl<'t, TInner: AutoTraitsK<'t>> AutoTraitsK<'t> for VecT<TInner> {}
+
 impl<
     't,
     ReqIn: TypeGuard<'t>,
@@ -678,6 +685,241 @@ impl<
     }
 }
 
+impl<'t, ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>, TInner: Hkt<'t> + MapInPlace<'t, ReqF1>>
+    MapInPlace<'t, ReqF1> for VecT<TInner>
+{
+    fn map_in_place<'a, 'f, A, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: <RefMutT<Self> as Hkt<'t>>::F<'a, A>,
+    ) where
+        A: 'a,
+        F1Once: 'f + FnOnce(&'a mut A),
+        F1Mut: 'f + FnMut(&'a mut A),
+        F1Fn: 'f + Fn(&'a mut A),
+        F1Clone: 'f + Fn(&'a mut A) + Clone,
+        F1Copy: 'f + Fn(&'a mut A) + Copy,
+        'a: 'f,
+        't: 'a,
+    {
+        SliceT::<TInner>::map_in_place(f, fa.as_mut_slice())
+    }
+}
+
+impl<'t, ReqIn: TypeGuard<'t>> Unfoldable<'t, ReqIn> for VecT {
+    fn unfold<'a, A, S>(
+        _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        mut step: impl 'a + FnMut(S) -> Option<(A, S)>,
+        seed: S,
+    ) -> Self::F<'a, A>
+    where
+        A: 'a,
+        S: 'a,
+        't: 'a,
+    {
+        let mut seed = Some(seed);
+
+        core::iter::from_fn(|| {
+            let (a, next) = step(seed.take()?)?;
+            seed = Some(next);
+            Some(a)
+        })
+        .collect::<Vec<_>>()
+    }
+}
+
+impl<'t> WithIndex<'t> for VecT {
+    type Index = usize;
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>>
+    FunctorWithIndex<'t, ReqIn, ReqOut, ReqF1> for VecT
+{
+    fn map_with_index<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        _clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(usize, A) -> B,
+        F1Mut: 'a + FnMut(usize, A) -> B,
+        F1Fn: 'a + Fn(usize, A) -> B,
+        F1Clone: 'a + Fn(usize, A) -> B + Clone,
+        F1Copy: 'a + Fn(usize, A) -> B + Copy,
+        't: 'a,
+    {
+        let f = ReqF1::arbitrary_t1(f, PhantomData::<fn(usize, A) -> B>);
+
+        match_one_of_5!(
+            ReqF1::into_one_of_5_enum(f),
+            f,
+            fa.into_iter()
+                .enumerate()
+                .map(|(i, a)| f(i, a))
+                .collect::<Vec<_>>()
+        )
+    }
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>>
+    FoldableWithIndex<'t, ReqIn, ReqOut, ReqF1> for VecT
+{
+    fn fold_while_with_index<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        _clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        _clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+        f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: Self::F<'a, A>,
+    ) -> FoldWhile<B>
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, usize, A) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, usize, A) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, usize, A) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, usize, A) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, usize, A) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        let f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, usize, A) -> FoldWhile<B>>);
+
+        match_one_of_5!(
+            ReqF1::into_one_of_5_enum(f),
+            f,
+            fa.into_iter()
+                .enumerate()
+                .try_fold(init, |b, (i, a)| f(b, i, a))
+        )
+    }
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>
+    TraversableWithIndex<'t, ReqIn, ReqOut, ReqF1> for VecT
+{
+}
+
+/// Pairs elements positionally and truncates to the shorter vector.
+///
+/// Unlike the cartesian [Applicative] impl, this is the elementwise (ZipList) combination.
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: TCloneableOf5<'t>,
+    TInner: Zip<'t, ReqIn, ReqOut, ReqF1>,
+> Zip<'t, ReqIn, ReqOut, ReqF1> for VecT<TInner>
+{
+    fn zip_with<'a, A, B, C, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqIn::Output<'a, B> + Clone,
+        clone_c: impl 'a + Fn(&C) -> ReqOut::Output<'a, C> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+        fb: Self::F<'a, B>,
+    ) -> Self::F<'a, C>
+    where
+        A: 'a,
+        B: 'a,
+        C: 'a,
+        F1Once: 'a + FnOnce(A, B) -> C,
+        F1Mut: 'a + FnMut(A, B) -> C,
+        F1Fn: 'a + Fn(A, B) -> C,
+        F1Clone: 'a + Fn(A, B) -> C + Clone,
+        F1Copy: 'a + Fn(A, B) -> C + Copy,
+        't: 'a,
+    {
+        let f = ReqF1::arbitrary_uncloneable(f, PhantomData::<fn(A, B) -> C>);
+
+        fa.into_iter()
+            .zip(fb)
+            .map(move |(ta, tb)| {
+                TInner::zip_with(
+                    clone_a.clone(),
+                    clone_b.clone(),
+                    clone_c.clone(),
+                    ReqF1::clone_one_of_5(&f),
+                    ta,
+                    tb,
+                )
+            })
+            .collect::<Vec<_>>()
+    }
+
+    fn unzip<'a, A, B>(fab: Self::F<'a, (A, B)>) -> (Self::F<'a, A>, Self::F<'a, B>)
+    where
+        A: 'a,
+        B: 'a,
+        't: 'a,
+    {
+        fab.into_iter().map(TInner::unzip).unzip()
+    }
+}
+
+/// Pairs elements positionally, the tail of the longer vector is kept as [These::This] or [These::That].
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: TCloneableOf5<'t>,
+    TInner: Align<'t, ReqIn, ReqOut, ReqF1> + Functor<'t, ReqIn, ReqOut, ReqF1>,
+> Align<'t, ReqIn, ReqOut, ReqF1> for VecT<TInner>
+{
+    fn align_with<'a, A, B, C, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqIn::Output<'a, B> + Clone,
+        clone_c: impl 'a + Fn(&C) -> ReqOut::Output<'a, C> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+        fb: Self::F<'a, B>,
+    ) -> Self::F<'a, C>
+    where
+        A: 'a,
+        B: 'a,
+        C: 'a,
+        F1Once: 'a + FnOnce(These<A, B>) -> C,
+        F1Mut: 'a + FnMut(These<A, B>) -> C,
+        F1Fn: 'a + Fn(These<A, B>) -> C,
+        F1Clone: 'a + Fn(These<A, B>) -> C + Clone,
+        F1Copy: 'a + Fn(These<A, B>) -> C + Copy,
+        't: 'a,
+    {
+        let f = ReqF1::arbitrary_uncloneable(f, PhantomData::<fn(These<A, B>) -> C>);
+
+        align_iter(fa, fb)
+            .map(move |these| {
+                let f = ReqF1::clone_one_of_5(&f);
+
+                match these {
+                    These::Both(ta, tb) => TInner::align_with(
+                        clone_a.clone(),
+                        clone_b.clone(),
+                        clone_c.clone(),
+                        f,
+                        ta,
+                        tb,
+                    ),
+                    These::This(ta) => TInner::map(
+                        clone_a.clone(),
+                        clone_c.clone(),
+                        map_one_of_5_with!(ReqF1, f, |f| move |a: A| f(These::This(a))),
+                        ta,
+                    ),
+                    These::That(tb) => TInner::map(
+                        clone_b.clone(),
+                        clone_c.clone(),
+                        map_one_of_5_with!(ReqF1, f, |f| move |b: B| f(These::That(b))),
+                        tb,
+                    ),
+                }
+            })
+            .collect::<Vec<_>>()
+    }
+}
+
 impl<'t, TInner: CovariantK<'t>> CovariantK<'t> for VecT<TInner> {
     fn covariant_convert<'a, 'b, A>(a: Self::F<'a, A>) -> Self::F<'b, A>
     where
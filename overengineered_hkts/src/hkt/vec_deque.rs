@@ -0,0 +1,26 @@
+use core::{convert::Infallible, marker::PhantomData};
+
+use alloc::{collections::VecDeque, vec::Vec};
+use tap::Pipe;
+
+use crate::{
+    hkt::{
+        Applicative, CloneK, CloneOwnedK, CovariantK, FoldWhile, Foldable, Functor, Hkt,
+        HktUnsized, Monad, Pure, Rfoldable, TCloneableOf5, Traversable,
+        hkt_classification::{self, HktClassification},
+        id::IdHkt,
+        one_of::{NotT1Of5, NotT5Of5, OneOf5Hkt},
+        reference::{RefMutT, RefT},
+        vec::VecT,
+    },
+    marker_classification::{AssertBlankOutput, ConstBool, TypeGuard},
+    utils::sum_size_hints,
+};
+
+impl_sequence_hkt!(
+    /// [VecDeque] of `TInner` layers, [Rfoldable] walks it from the back without reversing it.
+    ///
+    /// [Applicative], [Monad] and [Traversable] convert it to and from [VecT].
+    VecDequeT,
+    VecDeque
+);
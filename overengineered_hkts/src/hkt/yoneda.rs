@@ -0,0 +1,259 @@
+use core::{
+    convert::{Infallible, identity},
+    marker::PhantomData,
+};
+
+use alloc::{boxed::Box, rc::Rc};
+
+use crate::{
+    hkt::{
+        Functor, Hkt, PhantomMarker, TCloneableOf5,
+        hkt_classification::{self, HktClassification},
+        one_of::{OneOf5, T4Of5Hkt},
+    },
+    marker_classification::TypeGuard,
+};
+
+/// Shared so that lowering can pass a [Clone] function to [Functor::map].
+type Fused<'a, A, B> = Rc<dyn 'a + Fn(A) -> B>;
+
+fn fuse<'t, 'a, ReqF1: TCloneableOf5<'t>, A: 'a, B: 'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+    f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+) -> Fused<'a, A, B>
+where
+    F1Once: 'a + FnOnce(A) -> B,
+    F1Mut: 'a + FnMut(A) -> B,
+    F1Fn: 'a + Fn(A) -> B,
+    F1Clone: 'a + Fn(A) -> B + Clone,
+    F1Copy: 'a + Fn(A) -> B + Copy,
+    't: 'a,
+{
+    let f = ReqF1::arbitrary_uncloneable(f, PhantomData::<fn(A) -> B>);
+
+    match_one_of_5!(ReqF1::into_one_of_5_enum(f), f, Rc::new(f))
+}
+
+/// A `FX` holding `X`s together with the composition of every function mapped over it so far.
+pub struct Coyoneda<'a, FX, X, A> {
+    fx: FX,
+    f: Fused<'a, X, A>,
+}
+
+impl<'a, FX, X: 'a, A: 'a> Coyoneda<'a, FX, X, A> {
+    pub fn new(fx: FX, f: impl 'a + Fn(X) -> A) -> Self {
+        Self { fx, f: Rc::new(f) }
+    }
+
+    /// Composes `g` without touching `FX`.
+    pub fn map<B: 'a>(self, g: impl 'a + Fn(A) -> B) -> Coyoneda<'a, FX, X, B> {
+        let f = self.f;
+
+        Coyoneda {
+            fx: self.fx,
+            f: Rc::new(move |x| g(f(x))),
+        }
+    }
+
+    /// For layers without [Functor], the function has to be applied by hand.
+    pub fn into_parts(self) -> (FX, impl 'a + Fn(X) -> A + Clone) {
+        let f = self.f;
+
+        (self.fx, move |x| f(x))
+    }
+}
+
+impl<'a, FX, X: 'a> Coyoneda<'a, FX, X, X> {
+    pub fn lift(fx: FX) -> Self {
+        Self::new(fx, identity)
+    }
+}
+
+/// Free [Functor] over any sized `TInner`, e.g. [BinaryHeapT](crate::hkt::binary_heap::BinaryHeapT),
+/// whose `map` would need an [Ord] output.
+///
+/// `map` only composes functions, [lower](CoyonedaT::lower) then runs a single `TInner::map` with the fused function.
+/// Without a [Functor] for `TInner`, [into_parts](Coyoneda::into_parts) hands out the layer and the fused function.
+/// The element type `X` of the lifted value is a parameter of the hkt since Rust cannot hide it in an existential.
+pub struct CoyonedaT<TInner, X>(Infallible, PhantomMarker<(TInner, X)>);
+
+impl<'t, TInner: Hkt<'t>, X: 't> Hkt<'t> for CoyonedaT<TInner, X> {
+    type F<'a, A: 'a>
+        = Coyoneda<'a, TInner::F<'a, X>, X, A>
+    where
+        't: 'a;
+}
+
+impl<TInner, X> HktClassification for CoyonedaT<TInner, X> {
+    type Choice = hkt_classification::OuterHkt;
+}
+
+impl<TInner, X> CoyonedaT<TInner, X> {
+    pub fn lift<'t, 'a>(fx: TInner::F<'a, X>) -> <Self as Hkt<'t>>::F<'a, X>
+    where
+        TInner: Hkt<'t>,
+        X: 't,
+        't: 'a,
+    {
+        Coyoneda::lift(fx)
+    }
+
+    pub fn lower<'t, 'a, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, A>(
+        clone_x: impl 'a + Fn(&X) -> ReqIn::Output<'a, X> + Clone,
+        clone_a: impl 'a + Fn(&A) -> ReqOut::Output<'a, A> + Clone,
+        fa: <Self as Hkt<'t>>::F<'a, A>,
+    ) -> TInner::F<'a, A>
+    where
+        TInner: Functor<'t, ReqIn, ReqOut, T4Of5Hkt>,
+        X: 't,
+        A: 'a,
+        't: 'a,
+    {
+        let f = fa.f;
+
+        TInner::map::<X, A, fn(X) -> A, fn(X) -> A, fn(X) -> A, _, fn(X) -> A>(
+            clone_x,
+            clone_a,
+            move |x| f(x),
+            fa.fx,
+        )
+    }
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: TCloneableOf5<'t>,
+    TInner: Hkt<'t>,
+    X: 't,
+> Functor<'t, ReqIn, ReqOut, ReqF1> for CoyonedaT<TInner, X>
+{
+    fn map<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        _clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> B,
+        F1Mut: 'a + FnMut(A) -> B,
+        F1Fn: 'a + Fn(A) -> B,
+        F1Clone: 'a + Fn(A) -> B + Clone,
+        F1Copy: 'a + Fn(A) -> B + Copy,
+        't: 'a,
+    {
+        let f = fuse::<ReqF1, A, B, _, _, _, _, _>(f);
+
+        fa.map(move |a| f(a))
+    }
+}
+
+/// A `TInner::map` waiting for the continuation into `R`.
+pub struct Yoneda<'a, FR, R, A> {
+    run: Box<dyn 'a + FnOnce(Fused<'a, A, R>) -> FR>,
+}
+
+impl<'a, FR: 'a, R: 'a, A: 'a> Yoneda<'a, FR, R, A> {
+    pub fn new(run: impl 'a + FnOnce(Fused<'a, A, R>) -> FR) -> Self {
+        Self { run: Box::new(run) }
+    }
+
+    /// Composes `g` into the continuation.
+    pub fn map<B: 'a>(self, g: impl 'a + Fn(A) -> B) -> Yoneda<'a, FR, R, B> {
+        let run = self.run;
+
+        Yoneda::new(move |k: Fused<'a, B, R>| run(Rc::new(move |a| k(g(a)))))
+    }
+
+    pub fn run(self, k: impl 'a + Fn(A) -> R) -> FR {
+        (self.run)(Rc::new(k))
+    }
+}
+
+impl<'a, FR: 'a, R: 'a> Yoneda<'a, FR, R, R> {
+    pub fn lower(self) -> FR {
+        self.run(identity)
+    }
+}
+
+/// Continuation-passing counterpart of [CoyonedaT]: [lift](YonedaT::lift) needs `TInner` to be a [Functor],
+/// afterwards chains of `map` cost one closure call per element and layer instead of one `TInner::map` per `map`.
+///
+/// Without rank-2 types the final element type `R` is a parameter of the hkt.
+pub struct YonedaT<TInner, R>(Infallible, PhantomMarker<(TInner, R)>);
+
+impl<'t, TInner: Hkt<'t>, R: 't> Hkt<'t> for YonedaT<TInner, R> {
+    type F<'a, A: 'a>
+        = Yoneda<'a, TInner::F<'a, R>, R, A>
+    where
+        't: 'a;
+}
+
+impl<TInner, R> HktClassification for YonedaT<TInner, R> {
+    type Choice = hkt_classification::OuterHkt;
+}
+
+impl<TInner, R> YonedaT<TInner, R> {
+    pub fn lift<'t, 'a, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, A>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_r: impl 'a + Fn(&R) -> ReqOut::Output<'a, R> + Clone,
+        fa: TInner::F<'a, A>,
+    ) -> <Self as Hkt<'t>>::F<'a, A>
+    where
+        TInner: Functor<'t, ReqIn, ReqOut, T4Of5Hkt>,
+        R: 't,
+        A: 'a,
+        't: 'a,
+    {
+        Yoneda::new(move |k: Fused<'a, A, R>| {
+            TInner::map::<A, R, fn(A) -> R, fn(A) -> R, fn(A) -> R, _, fn(A) -> R>(
+                clone_a,
+                clone_r,
+                move |a| k(a),
+                fa,
+            )
+        })
+    }
+
+    pub fn lower<'t, 'a>(fa: <Self as Hkt<'t>>::F<'a, R>) -> TInner::F<'a, R>
+    where
+        TInner: Hkt<'t>,
+        R: 't,
+        't: 'a,
+    {
+        fa.lower()
+    }
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: TCloneableOf5<'t>,
+    TInner: Hkt<'t>,
+    R: 't,
+> Functor<'t, ReqIn, ReqOut, ReqF1> for YonedaT<TInner, R>
+{
+    fn map<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        _clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> B,
+        F1Mut: 'a + FnMut(A) -> B,
+        F1Fn: 'a + Fn(A) -> B,
+        F1Clone: 'a + Fn(A) -> B + Clone,
+        F1Copy: 'a + Fn(A) -> B + Copy,
+        't: 'a,
+    {
+        let f = fuse::<ReqF1, A, B, _, _, _, _, _>(f);
+
+        fa.map(move |a| f(a))
+    }
+}
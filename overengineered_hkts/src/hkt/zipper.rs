@@ -0,0 +1,456 @@
+use core::{convert::Infallible, marker::PhantomData};
+
+use alloc::vec::Vec;
+use tap::Pipe;
+
+use crate::{
+    hkt::{
+        Applicative, CloneK, CloneOwnedK, Comonad, CovariantK, FoldWhile, Foldable, Functor, Hkt,
+        TCloneableOf5, Traversable,
+        hkt_classification::{self, HktClassification},
+        id::IdHkt,
+        non_empty_vec::NonEmptyVec,
+        one_of::{NotT1Of5, NotT5Of5, OneOf5, OneOf5Hkt},
+        vec::VecT,
+    },
+    marker_classification::{AssertBlankOutput, ConstBool, TypeGuard},
+    utils::sum_size_hints,
+};
+
+const IN_BOUNDS: &str = "VecT preserves length";
+
+/// A non-empty sequence with a cursor on `focus`.
+///
+/// Both stacks keep the element nearest to the focus last, so moving the cursor is a push and a pop.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Zipper<T> {
+    pub left: Vec<T>,
+    pub focus: T,
+    pub right: Vec<T>,
+}
+
+impl<T> Zipper<T> {
+    pub fn new(focus: T) -> Self {
+        Self {
+            left: Vec::new(),
+            focus,
+            right: Vec::new(),
+        }
+    }
+
+    /// Focuses the first element, returns [None] if `vec` is empty.
+    pub fn from_vec(vec: Vec<T>) -> Option<Self> {
+        Self::from_vec_at(vec, 0)
+    }
+
+    /// Focuses `vec[index]`, returns [None] if it is out of bounds.
+    pub fn from_vec_at(mut vec: Vec<T>, index: usize) -> Option<Self> {
+        if index >= vec.len() {
+            return None;
+        }
+
+        let mut right = vec.split_off(index + 1);
+        right.reverse();
+        let focus = vec.pop()?;
+
+        Some(Self {
+            left: vec,
+            focus,
+            right,
+        })
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        let mut vec = self.left;
+        vec.reserve(self.right.len() + 1);
+        vec.push(self.focus);
+        vec.extend(self.right.into_iter().rev());
+        vec
+    }
+
+    /// Position of the focus.
+    pub fn index(&self) -> usize {
+        self.left.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.left.len() + 1 + self.right.len()
+    }
+
+    /// Always `false`, provided for symmetry with [len](Zipper::len).
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Moves the cursor one step to the left, returns `false` at the leftmost element.
+    pub fn left(&mut self) -> bool {
+        match self.left.pop() {
+            Some(focus) => {
+                self.right.push(core::mem::replace(&mut self.focus, focus));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Moves the cursor one step to the right, returns `false` at the rightmost element.
+    pub fn right(&mut self) -> bool {
+        match self.right.pop() {
+            Some(focus) => {
+                self.left.push(core::mem::replace(&mut self.focus, focus));
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn modify(&mut self, f: impl FnOnce(&mut T)) {
+        f(&mut self.focus)
+    }
+
+    pub fn as_ref(&self) -> Zipper<&T> {
+        Zipper {
+            left: self.left.iter().collect(),
+            focus: &self.focus,
+            right: self.right.iter().collect(),
+        }
+    }
+
+    /// Maps from left to right, keeping the cursor.
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Zipper<U> {
+        let left = self.left.into_iter().map(&mut f).collect();
+        let focus = f(self.focus);
+        let mut right = self.right.into_iter().rev().map(f).collect::<Vec<_>>();
+        right.reverse();
+
+        Zipper { left, focus, right }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.left
+            .iter()
+            .chain(core::iter::once(&self.focus))
+            .chain(self.right.iter().rev())
+    }
+
+    /// The zipper focused at every position, with this one as the focus.
+    pub fn duplicate(self, clone: impl Fn(&Self) -> Self) -> Zipper<Self> {
+        let contexts = |step: fn(&mut Self) -> bool| {
+            let mut cursor = clone(&self);
+            let mut contexts = Vec::new();
+
+            while step(&mut cursor) {
+                contexts.push(clone(&cursor));
+            }
+
+            contexts.reverse();
+            contexts
+        };
+
+        let left = contexts(Self::left);
+        let right = contexts(Self::right);
+
+        Zipper {
+            left,
+            focus: self,
+            right,
+        }
+    }
+}
+
+impl<T> IntoIterator for Zipper<T> {
+    type Item = T;
+    type IntoIter = core::iter::Chain<
+        core::iter::Chain<alloc::vec::IntoIter<T>, core::iter::Once<T>>,
+        core::iter::Rev<alloc::vec::IntoIter<T>>,
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.left
+            .into_iter()
+            .chain(core::iter::once(self.focus))
+            .chain(self.right.into_iter().rev())
+    }
+}
+
+/// Focuses the head.
+impl<T> From<NonEmptyVec<T>> for Zipper<T> {
+    fn from(value: NonEmptyVec<T>) -> Self {
+        let mut right = value.tail;
+        right.reverse();
+
+        Self {
+            left: Vec::new(),
+            focus: value.head,
+            right,
+        }
+    }
+}
+
+/// [Foldable] and [Traversable] go from left to right, [Comonad::extend] sees every position focused in turn.
+pub struct ZipperT<TInner = IdHkt>(Infallible, PhantomData<TInner>);
+
+impl<TInner> ZipperT<TInner> {
+    /// Focuses the first element, returns [None] if `fa` is empty.
+    pub fn from_vec<'t, 'a, A>(
+        fa: <VecT<TInner> as Hkt<'t>>::F<'a, A>,
+    ) -> Option<<Self as Hkt<'t>>::F<'a, A>>
+    where
+        TInner: Hkt<'t>,
+        A: 'a,
+        't: 'a,
+    {
+        Zipper::from_vec(fa)
+    }
+
+    pub fn from_non_empty_vec<'t, 'a, A>(
+        fa: NonEmptyVec<TInner::F<'a, A>>,
+    ) -> <Self as Hkt<'t>>::F<'a, A>
+    where
+        TInner: Hkt<'t>,
+        A: 'a,
+        't: 'a,
+    {
+        Zipper::from(fa)
+    }
+
+    pub fn into_vec<'t, 'a, A>(
+        fa: <Self as Hkt<'t>>::F<'a, A>,
+    ) -> <VecT<TInner> as Hkt<'t>>::F<'a, A>
+    where
+        TInner: Hkt<'t>,
+        A: 'a,
+        't: 'a,
+    {
+        fa.into_vec()
+    }
+}
+
+impl<'t, TInner: Hkt<'t>> Hkt<'t> for ZipperT<TInner> {
+    type F<'a, A: 'a>
+        = Zipper<TInner::F<'a, A>>
+    where
+        't: 'a;
+}
+
+impl<TInner> HktClassification for ZipperT<TInner> {
+    type Choice = hkt_classification::OuterHkt;
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: TCloneableOf5<'t>,
+    TInner: Functor<'t, ReqIn, ReqOut, ReqF1>,
+> Functor<'t, ReqIn, ReqOut, ReqF1> for ZipperT<TInner>
+{
+    fn map<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> B,
+        F1Mut: 'a + FnMut(A) -> B,
+        F1Fn: 'a + Fn(A) -> B,
+        F1Clone: 'a + Fn(A) -> B + Clone,
+        F1Copy: 'a + Fn(A) -> B + Copy,
+        't: 'a,
+    {
+        let f = ReqF1::arbitrary_uncloneable(f, PhantomData::<fn(A) -> B>);
+
+        fa.map(move |x| {
+            TInner::map(
+                clone_a.clone(),
+                clone_b.clone(),
+                ReqF1::clone_one_of_5(&f),
+                x,
+            )
+        })
+    }
+}
+
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+    TInner: Foldable<'t, ReqIn, ReqOut, ReqF1>,
+> Foldable<'t, ReqIn, ReqOut, ReqF1> for ZipperT<TInner>
+{
+    fn fold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+        f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        init: B,
+        fa: Self::F<'a, A>,
+    ) -> FoldWhile<B>
+    where
+        A: 'a,
+        B: 'b,
+        F1Once: 'f + FnOnce(B, A) -> FoldWhile<B>,
+        F1Mut: 'f + FnMut(B, A) -> FoldWhile<B>,
+        F1Fn: 'f + Fn(B, A) -> FoldWhile<B>,
+        F1Clone: 'f + Fn(B, A) -> FoldWhile<B> + Clone,
+        F1Copy: 'f + Fn(B, A) -> FoldWhile<B> + Copy,
+        'a: 'f,
+        'b: 'f,
+        't: 'a + 'b,
+    {
+        let mut f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, A) -> FoldWhile<B>>);
+
+        fa.into_iter().try_fold(init, move |b, ka| {
+            let f_ref = ReqF1::as_mut(&mut f)
+                .pipe(|f| map_one_of_5_with!(ReqF1, f, |mut f| |b, a| f(b, a)));
+
+            TInner::fold_while(clone_a.clone(), clone_b.clone(), f_ref, b, ka)
+        })
+    }
+
+    fn size_hint<'a, A>(s: &Self::F<'a, A>) -> (usize, Option<usize>)
+    where
+        't: 'a,
+    {
+        sum_size_hints(s.iter().map(|x| TInner::size_hint(x)))
+    }
+}
+
+/// Traverses through [VecT], keeping the cursor.
+impl<
+    't,
+    ReqIn: TypeGuard<'t>,
+    ReqOut: TypeGuard<'t>,
+    ReqF1: TCloneableOf5<'t> + NotT5Of5<'t>,
+    TInner: Traversable<'t, ReqIn, ReqOut, ReqF1> + CloneK<'t, ReqOut>,
+> Traversable<'t, ReqIn, ReqOut, ReqF1> for ZipperT<TInner>
+{
+    fn traverse<'a, A, B, F, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+        fa: Self::F<'a, A>,
+    ) -> F::F<'a, Self::F<'a, B>>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(A) -> F::F<'a, B>,
+        F1Mut: 'a + FnMut(A) -> F::F<'a, B>,
+        F1Fn: 'a + Fn(A) -> F::F<'a, B>,
+        F1Clone: 'a + Fn(A) -> F::F<'a, B> + Clone,
+        F1Copy: 'a + Fn(A) -> F::F<'a, B> + Copy,
+        F: Applicative<'t, ReqIn, ReqOut, ReqF1> + Functor<'t, ReqIn, ConstBool<false>, ReqF1>,
+        't: 'a,
+    {
+        let tag = ReqF1::create_from(&f, ());
+        let index = fa.index();
+
+        let values = <VecT<TInner> as Traversable<'t, ReqIn, ReqOut, ReqF1>>::traverse::<
+            A,
+            B,
+            F,
+            _,
+            _,
+            _,
+            _,
+            _,
+        >(clone_a, clone_b.clone(), f, fa.into_vec());
+
+        <F as Functor<'t, ReqIn, ConstBool<false>, ReqF1>>::map(
+            move |vec: &Vec<TInner::F<'a, B>>| {
+                vec.iter()
+                    .map(|b| TInner::clone(clone_b.clone(), b))
+                    .collect::<Vec<_>>()
+                    .pipe(ReqIn::into_guarded)
+            },
+            |_| AssertBlankOutput,
+            ReqF1::create_from(&tag, move |vec: Vec<TInner::F<'a, B>>| {
+                Zipper::from_vec_at(vec, index).expect(IN_BOUNDS)
+            }),
+            values,
+        )
+    }
+}
+
+/// Every position is extended with a clone of the whole zipper.
+impl<'t, ReqOut: TypeGuard<'t>, ReqF1: TCloneableOf5<'t>>
+    Comonad<'t, ConstBool<true>, ReqOut, ReqF1> for ZipperT
+{
+    fn extract<'a, A>(fa: Self::F<'a, A>) -> A
+    where
+        A: 'a,
+        't: 'a,
+    {
+        fa.focus
+    }
+
+    fn extend<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+        clone_a: impl 'a + Fn(&A) -> A + Clone,
+        _clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+        fa: Self::F<'a, A>,
+        f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+    ) -> Self::F<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        F1Once: 'a + FnOnce(Self::F<'a, A>) -> B,
+        F1Mut: 'a + FnMut(Self::F<'a, A>) -> B,
+        F1Fn: 'a + Fn(Self::F<'a, A>) -> B,
+        F1Clone: 'a + Fn(Self::F<'a, A>) -> B + Clone,
+        F1Copy: 'a + Fn(Self::F<'a, A>) -> B + Copy,
+        't: 'a,
+    {
+        let f = ReqF1::arbitrary_uncloneable(f, PhantomData::<fn(Zipper<A>) -> B>);
+
+        fa.duplicate(|fa| fa.as_ref().map(&clone_a)).map(|context| {
+            match_one_of_5!(
+                ReqF1::into_one_of_5_enum(ReqF1::clone_one_of_5(&f)),
+                f,
+                f(context)
+            )
+        })
+    }
+}
+
+impl<'t, TInner: CovariantK<'t>> CovariantK<'t> for ZipperT<TInner> {
+    fn covariant_convert<'a, 'b, A>(a: Self::F<'a, A>) -> Self::F<'b, A>
+    where
+        A: 'a,
+        'a: 'b,
+        't: 'a + 'b,
+    {
+        a.map(TInner::covariant_convert)
+    }
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, TInner: CloneOwnedK<'t, ReqIn>> CloneOwnedK<'t, ReqIn>
+    for ZipperT<TInner>
+{
+    fn clone_owned<'a, 'b, A>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'b, A> + Clone,
+        a: &Self::F<'a, A>,
+    ) -> Self::F<'b, A>
+    where
+        A: 'a + 'b,
+        't: 'a + 'b,
+    {
+        a.as_ref()
+            .map(|ta| TInner::clone_owned(clone_a.clone(), ta))
+    }
+}
+
+impl<'t, ReqIn: TypeGuard<'t>, TInner: CloneK<'t, ReqIn>> CloneK<'t, ReqIn> for ZipperT<TInner> {
+    fn clone<'a, A>(
+        clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+        a: &Self::F<'a, A>,
+    ) -> Self::F<'a, A>
+    where
+        A: 'a,
+        't: 'a,
+    {
+        a.as_ref().map(|ta| TInner::clone(clone_a.clone(), ta))
+    }
+}
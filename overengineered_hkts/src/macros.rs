@@ -77,3 +77,1013 @@ macro_rules! map_one_of_5_with {
     };
 }
 
+/// Generates the hkt of a reference counted pointer, shared by [RcT](crate::hkt::rc::RcT) and [ArcT](crate::hkt::sync::ArcT).
+macro_rules! impl_shared_pointer_hkt {
+    ($hkt:ident, $ptr:ident) => {
+        pub struct $hkt<TInner = IdHkt>(Infallible, PhantomData<TInner>);
+
+        impl<'t, TInner: UnsizedHkt<'t>> Hkt<'t> for $hkt<TInner> {
+            type F<'a, A: 'a>
+                = $ptr<TInner::UnsizedF<'a, A>>
+            where
+                't: 'a;
+        }
+
+        impl<'t, TInner: UnsizedHktUnsized<'t>> HktUnsized<'t> for $hkt<TInner> {
+            type FUnsized<'a, A: 'a + ?Sized>
+                = $ptr<TInner::UnsizedFUnsized<'a, A>>
+            where
+                't: 'a;
+        }
+
+        impl<TInner> HktClassification for $hkt<TInner> {
+            type Choice = hkt_classification::OuterHkt;
+        }
+
+        impl<'t, TInner: DerefHkt<'t>> DerefHkt<'t> for $hkt<TInner> {
+            fn deref<'a, 'b: 'a, A: 'a + ?Sized>(fa: &'a Self::UnsizedFUnsized<'b, A>) -> &'a A
+            where
+                't: 'b + 'a,
+            {
+                TInner::deref(fa)
+            }
+        }
+
+        /// Moves the contents out when uniquely owned, otherwise clones them with `clone_a`.
+        /// The mapped contents are always put in a new allocation.
+        impl<
+            't,
+            ReqIn: TypeGuard<'t>,
+            ReqOut: TypeGuard<'t>,
+            ReqF1: OneOf5Hkt<'t>,
+            TInner: Functor<'t, ReqIn, ReqOut, ReqF1> + CloneK<'t, ReqIn>,
+        > Functor<'t, ReqIn, ReqOut, ReqF1> for $hkt<TInner>
+        {
+            fn map<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+                clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+                f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+                fa: Self::F<'a, A>,
+            ) -> Self::F<'a, B>
+            where
+                A: 'a,
+                B: 'a,
+                F1Once: 'a + FnOnce(A) -> B,
+                F1Mut: 'a + FnMut(A) -> B,
+                F1Fn: 'a + Fn(A) -> B,
+                F1Clone: 'a + Fn(A) -> B + Clone,
+                F1Copy: 'a + Fn(A) -> B + Copy,
+                't: 'a,
+            {
+                let fa = fa
+                    .pipe(TInner::F::transmute_hkt_from::<$hkt>)
+                    .pipe($ptr::try_unwrap)
+                    .unwrap_or_else(|fa| TInner::clone(clone_a.clone(), &fa));
+
+                TInner::map(clone_a, clone_b, f, fa)
+                    .pipe($ptr::new)
+                    .pipe(TInner::F::transmute_hkt_into::<$hkt>)
+            }
+        }
+
+        /// `clone_a` can only be called directly on the contents, hence only [IdHkt] is supported.
+        impl<'t, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>> Foldable<'t, ConstBool<true>, ReqOut, ReqF1>
+            for $hkt
+        {
+            fn fold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                clone_a: impl 'f + Fn(&A) -> A + Clone,
+                _clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+                f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+                init: B,
+                fa: Self::F<'a, A>,
+            ) -> FoldWhile<B>
+            where
+                A: 'a,
+                B: 'b,
+                F1Once: 'f + FnOnce(B, A) -> FoldWhile<B>,
+                F1Mut: 'f + FnMut(B, A) -> FoldWhile<B>,
+                F1Fn: 'f + Fn(B, A) -> FoldWhile<B>,
+                F1Clone: 'f + Fn(B, A) -> FoldWhile<B> + Clone,
+                F1Copy: 'f + Fn(B, A) -> FoldWhile<B> + Copy,
+                'a: 'f,
+                'b: 'f,
+                't: 'a + 'b,
+            {
+                let a = $ptr::try_unwrap(fa).unwrap_or_else(|fa| clone_a(&fa));
+
+                match_one_of_5!(ReqF1::into_one_of_5_enum(f), f, f(init, a))
+            }
+        }
+
+        impl<
+            't,
+            ReqIn: TypeGuard<'t>,
+            ReqOut: TypeGuard<'t>,
+            ReqF1: OneOf5Hkt<'t>,
+            TInner: Foldable<'t, ReqIn, ReqOut, ReqF1, RefT>,
+        > Foldable<'t, ReqIn, ReqOut, ReqF1, RefT> for $hkt<TInner>
+        {
+            fn fold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                clone_a: impl 'f + Fn(&A) -> <ReqIn>::Output<'a, A> + Clone,
+                clone_b: impl 'f + Fn(&B) -> <ReqOut>::Output<'b, B> + Clone,
+                f: <ReqF1>::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+                init: B,
+                fa: <RefT as Hkt<'t>>::F<'a, Self::F<'a, A>>,
+            ) -> FoldWhile<B>
+            where
+                A: 'a,
+                B: 'b,
+                F1Once: 'f + FnOnce(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+                F1Mut: 'f + FnMut(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+                F1Fn: 'f + Fn(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+                F1Clone: 'f + Fn(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B> + Clone,
+                F1Copy: 'f + Fn(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B> + Copy,
+                'a: 'f,
+                'b: 'f,
+                't: 'a + 'b,
+            {
+                TInner::fold_while(
+                    clone_a,
+                    clone_b,
+                    f,
+                    init,
+                    fa.deref().pipe(TInner::F::transmute_hkt_from::<RefT>),
+                )
+            }
+        }
+
+        #[doc = concat!("Like [", stringify!($ptr), "::make_mut] but clones with `clone_a` when shared.")]
+        impl<'t, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t>>
+            Foldable<'t, ConstBool<true>, ReqOut, ReqF1, RefMutT> for $hkt
+        {
+            fn fold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                clone_a: impl 'f + Fn(&A) -> A + Clone,
+                _clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+                f: <ReqF1>::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+                init: B,
+                fa: <RefMutT as Hkt<'t>>::F<'a, Self::F<'a, A>>,
+            ) -> FoldWhile<B>
+            where
+                A: 'a,
+                B: 'b,
+                F1Once: 'f + FnOnce(B, <RefMutT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+                F1Mut: 'f + FnMut(B, <RefMutT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+                F1Fn: 'f + Fn(B, <RefMutT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+                F1Clone: 'f + Fn(B, <RefMutT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B> + Clone,
+                F1Copy: 'f + Fn(B, <RefMutT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B> + Copy,
+                'a: 'f,
+                'b: 'f,
+                't: 'a + 'b,
+            {
+                if $ptr::get_mut(fa).is_none() {
+                    *fa = $ptr::new(clone_a(fa));
+                }
+
+                let a = $ptr::get_mut(fa).expect("uniquely owned after cloning");
+
+                match_one_of_5!(ReqF1::into_one_of_5_enum(f), f, f(init, a))
+            }
+        }
+
+        /// Shares the allocation, `clone_a` is never called.
+        impl<'t, ReqIn: TypeGuard<'t>, TInner: UnsizedHkt<'t>> CloneK<'t, ReqIn> for $hkt<TInner> {
+            fn clone<'a, A>(
+                _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+                a: &Self::F<'a, A>,
+            ) -> Self::F<'a, A>
+            where
+                A: 'a,
+                't: 'a,
+            {
+                $ptr::clone(a)
+            }
+        }
+    };
+}
+
+/// Generates the hkt of a sequence collection, going through [VecT](crate::hkt::vec::VecT) for [Applicative](crate::hkt::Applicative), [Monad](crate::hkt::Monad) and [Traversable](crate::hkt::Traversable).
+///
+/// Shared by [VecDequeT](crate::hkt::vec_deque::VecDequeT) and [LinkedListT](crate::hkt::linked_list::LinkedListT).
+macro_rules! impl_sequence_hkt {
+    ($(#[$meta:meta])* $hkt:ident, $collection:ident) => {
+        $(#[$meta])*
+        pub struct $hkt<TInner = IdHkt>(Infallible, PhantomData<TInner>);
+
+        impl<'t, TInner: Hkt<'t>> Hkt<'t> for $hkt<TInner> {
+            type F<'a, A: 'a>
+                = $collection<TInner::F<'a, A>>
+            where
+                't: 'a;
+        }
+
+        impl<'t, TInner: HktUnsized<'t>> HktUnsized<'t> for $hkt<TInner> {
+            type FUnsized<'a, A: 'a + ?Sized>
+                = $collection<TInner::FUnsized<'a, A>>
+            where
+                't: 'a;
+        }
+
+        impl<TInner> HktClassification for $hkt<TInner> {
+            type Choice = hkt_classification::OuterHkt;
+        }
+
+        impl<
+            't,
+            ReqIn: TypeGuard<'t>,
+            ReqOut: TypeGuard<'t>,
+            ReqF1: TCloneableOf5<'t>,
+            TInner: Functor<'t, ReqIn, ReqOut, ReqF1>,
+        > Functor<'t, ReqIn, ReqOut, ReqF1> for $hkt<TInner>
+        {
+            fn map<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+                clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+                f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+                fa: Self::F<'a, A>,
+            ) -> Self::F<'a, B>
+            where
+                A: 'a,
+                B: 'a,
+                F1Once: 'a + FnOnce(A) -> B,
+                F1Mut: 'a + FnMut(A) -> B,
+                F1Fn: 'a + Fn(A) -> B,
+                F1Clone: 'a + Fn(A) -> B + Clone,
+                F1Copy: 'a + Fn(A) -> B + Copy,
+                't: 'a,
+            {
+                let f = ReqF1::arbitrary_uncloneable(f, PhantomData::<fn(A) -> B>);
+
+                fa.into_iter()
+                    .map(move |x| {
+                        TInner::map(
+                            clone_a.clone(),
+                            clone_b.clone(),
+                            ReqF1::clone_one_of_5(&f),
+                            x,
+                        )
+                    })
+                    .collect::<$collection<_>>()
+            }
+        }
+
+        impl<
+            't,
+            ReqIn: TypeGuard<'t>,
+            ReqOut: TypeGuard<'t>,
+            ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+            TInner: Foldable<'t, ReqIn, ReqOut, ReqF1>,
+        > Foldable<'t, ReqIn, ReqOut, ReqF1> for $hkt<TInner>
+        {
+            fn fold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+                clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+                f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+                init: B,
+                fa: Self::F<'a, A>,
+            ) -> FoldWhile<B>
+            where
+                A: 'a,
+                B: 'b,
+                F1Once: 'f + FnOnce(B, A) -> FoldWhile<B>,
+                F1Mut: 'f + FnMut(B, A) -> FoldWhile<B>,
+                F1Fn: 'f + Fn(B, A) -> FoldWhile<B>,
+                F1Clone: 'f + Fn(B, A) -> FoldWhile<B> + Clone,
+                F1Copy: 'f + Fn(B, A) -> FoldWhile<B> + Copy,
+                'a: 'f,
+                'b: 'f,
+                't: 'a + 'b,
+            {
+                let mut f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, A) -> FoldWhile<B>>);
+
+                fa.into_iter().try_fold(init, move |b, ka| {
+                    let f_ref = ReqF1::as_mut(&mut f)
+                        .pipe(|f| map_one_of_5_with!(ReqF1, f, |mut f| |b, a| f(b, a)));
+
+                    TInner::fold_while(clone_a.clone(), clone_b.clone(), f_ref, b, ka)
+                })
+            }
+
+            fn size_hint<'a, A>(s: &Self::F<'a, A>) -> (usize, Option<usize>)
+            where
+                't: 'a,
+            {
+                sum_size_hints(s.iter().map(|x| TInner::size_hint(x)))
+            }
+        }
+
+        impl<
+            't,
+            ReqIn: TypeGuard<'t>,
+            ReqOut: TypeGuard<'t>,
+            ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+            TInner: Foldable<'t, ReqIn, ReqOut, ReqF1, RefT>,
+        > Foldable<'t, ReqIn, ReqOut, ReqF1, RefT> for $hkt<TInner>
+        {
+            fn fold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                clone_a: impl 'f + Fn(&A) -> <ReqIn>::Output<'a, A> + Clone,
+                clone_b: impl 'f + Fn(&B) -> <ReqOut>::Output<'b, B> + Clone,
+                f: <ReqF1>::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+                init: B,
+                fa: <RefT as Hkt<'t>>::F<'a, Self::F<'a, A>>,
+            ) -> FoldWhile<B>
+            where
+                A: 'a,
+                B: 'b,
+                F1Once: 'f + FnOnce(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+                F1Mut: 'f + FnMut(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+                F1Fn: 'f + Fn(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+                F1Clone: 'f + Fn(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B> + Clone,
+                F1Copy: 'f + Fn(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B> + Copy,
+                'a: 'f,
+                'b: 'f,
+                't: 'a + 'b,
+            {
+                let mut f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, &'a A) -> FoldWhile<B>>);
+
+                fa.iter().try_fold(init, move |b, ka| {
+                    let f_ref = ReqF1::as_mut(&mut f)
+                        .pipe(|f| map_one_of_5_with!(ReqF1, f, |mut f| |b, a| f(b, a)));
+
+                    TInner::fold_while(clone_a.clone(), clone_b.clone(), f_ref, b, ka)
+                })
+            }
+        }
+
+        impl<
+            't,
+            ReqIn: TypeGuard<'t>,
+            ReqOut: TypeGuard<'t>,
+            ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+            TInner: Foldable<'t, ReqIn, ReqOut, ReqF1, RefMutT>,
+        > Foldable<'t, ReqIn, ReqOut, ReqF1, RefMutT> for $hkt<TInner>
+        {
+            fn fold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                clone_a: impl 'f + Fn(&A) -> <ReqIn>::Output<'a, A> + Clone,
+                clone_b: impl 'f + Fn(&B) -> <ReqOut>::Output<'b, B> + Clone,
+                f: <ReqF1>::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+                init: B,
+                fa: <RefMutT as Hkt<'t>>::F<'a, Self::F<'a, A>>,
+            ) -> FoldWhile<B>
+            where
+                A: 'a,
+                B: 'b,
+                F1Once: 'f + FnOnce(B, <RefMutT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+                F1Mut: 'f + FnMut(B, <RefMutT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+                F1Fn: 'f + Fn(B, <RefMutT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+                F1Clone: 'f + Fn(B, <RefMutT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B> + Clone,
+                F1Copy: 'f + Fn(B, <RefMutT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B> + Copy,
+                'a: 'f,
+                'b: 'f,
+                't: 'a + 'b,
+            {
+                let mut f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, &'a mut A) -> FoldWhile<B>>);
+
+                fa.iter_mut().try_fold(init, move |b, ka| {
+                    let f_ref = ReqF1::as_mut(&mut f)
+                        .pipe(|f| map_one_of_5_with!(ReqF1, f, |mut f| |b, a| f(b, a)));
+
+                    TInner::fold_while(clone_a.clone(), clone_b.clone(), f_ref, b, ka)
+                })
+            }
+        }
+
+        impl<
+            't,
+            ReqIn: TypeGuard<'t>,
+            ReqOut: TypeGuard<'t>,
+            ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+            TInner: Rfoldable<'t, ReqIn, ReqOut, ReqF1>,
+        > Rfoldable<'t, ReqIn, ReqOut, ReqF1> for $hkt<TInner>
+        {
+            fn rfold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+                clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+                f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+                init: B,
+                fa: Self::F<'a, A>,
+            ) -> FoldWhile<B>
+            where
+                A: 'a,
+                B: 'b,
+                F1Once: 'f + FnOnce(B, A) -> FoldWhile<B>,
+                F1Mut: 'f + FnMut(B, A) -> FoldWhile<B>,
+                F1Fn: 'f + Fn(B, A) -> FoldWhile<B>,
+                F1Clone: 'f + Fn(B, A) -> FoldWhile<B> + Clone,
+                F1Copy: 'f + Fn(B, A) -> FoldWhile<B> + Copy,
+                'a: 'f,
+                'b: 'f,
+                't: 'a + 'b,
+            {
+                let mut f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, A) -> FoldWhile<B>>);
+
+                fa.into_iter().rev().try_fold(init, move |b, ka| {
+                    let f_ref = ReqF1::as_mut(&mut f)
+                        .pipe(|f| map_one_of_5_with!(ReqF1, f, |mut f| |b, a| f(b, a)));
+
+                    TInner::rfold_while(clone_a.clone(), clone_b.clone(), f_ref, b, ka)
+                })
+            }
+        }
+
+        impl<'t, ReqIn: TypeGuard<'t>, TInner: Pure<'t, ReqIn>> Pure<'t, ReqIn> for $hkt<TInner> {
+            fn pure<'a, A>(
+                clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+                a: A,
+            ) -> Self::F<'a, A>
+            where
+                A: 'a,
+                't: 'a,
+            {
+                TInner::pure(clone_a, a).pipe(|a| $collection::from([a]))
+            }
+        }
+
+        impl<
+            't,
+            ReqIn: TypeGuard<'t>,
+            ReqOut: TypeGuard<'t>,
+            ReqF1: TCloneableOf5<'t>,
+            TInner: Applicative<'t, ReqIn, ReqOut, ReqF1> + CloneK<'t, ReqIn>,
+        > Applicative<'t, ReqIn, ReqOut, ReqF1> for $hkt<TInner>
+        {
+            fn apply<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+                clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+                ff: Self::F<'a, <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>>,
+                fa: Self::F<'a, A>,
+            ) -> Self::F<'a, B>
+            where
+                A: 'a,
+                B: 'a,
+                F1Once: 'a + FnOnce(A) -> B,
+                F1Mut: 'a + FnMut(A) -> B,
+                F1Fn: 'a + Fn(A) -> B,
+                F1Clone: 'a + Fn(A) -> B + Clone,
+                F1Copy: 'a + Fn(A) -> B + Copy,
+                't: 'a,
+            {
+                <VecT<TInner> as Applicative<'t, ReqIn, ReqOut, ReqF1>>::apply(
+                    clone_a,
+                    clone_b,
+                    ff.into_iter().collect::<Vec<_>>(),
+                    fa.into_iter().collect::<Vec<_>>(),
+                )
+                .into_iter()
+                .collect::<$collection<_>>()
+            }
+        }
+
+        impl<
+            't,
+            ReqF1: TCloneableOf5<'t> + NotT5Of5<'t>,
+            TInner: Monad<'t, ConstBool<true>, ConstBool<true>, ReqF1>
+                + Traversable<'t, ConstBool<true>, ConstBool<true>, ReqF1>
+                + Functor<'t, ConstBool<true>, ConstBool<false>, ReqF1>
+                + CloneK<'t, ConstBool<true>>,
+        > Monad<'t, ConstBool<true>, ConstBool<true>, ReqF1> for $hkt<TInner>
+        {
+            fn bind<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                clone_a: impl 'a + Fn(&A) -> A + Clone,
+                clone_b: impl 'a + Fn(&B) -> B + Clone,
+                fa: Self::F<'a, A>,
+                f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+            ) -> Self::F<'a, B>
+            where
+                A: 'a,
+                B: 'a,
+                F1Once: 'a + FnOnce(A) -> Self::F<'a, B>,
+                F1Mut: 'a + FnMut(A) -> Self::F<'a, B>,
+                F1Fn: 'a + Fn(A) -> Self::F<'a, B>,
+                F1Clone: 'a + Fn(A) -> Self::F<'a, B> + Clone,
+                F1Copy: 'a + Fn(A) -> Self::F<'a, B> + Copy,
+                't: 'a,
+            {
+                let f = map_one_of_5_with!(ReqF1, f, |mut f| move |a: A| {
+                    f(a).into_iter().collect::<Vec<_>>()
+                })
+                    .pipe(|f| ReqF1::arbitrary_t5(f, PhantomData::<fn(A) -> Vec<TInner::F<'a, B>>>));
+
+                <VecT<TInner> as Monad<'t, ConstBool<true>, ConstBool<true>, ReqF1>>::bind(
+                    clone_a,
+                    clone_b,
+                    fa.into_iter().collect::<Vec<_>>(),
+                    f,
+                )
+                .into_iter()
+                .collect::<$collection<_>>()
+            }
+        }
+
+        /// Traverses through [VecT].
+        impl<
+            't,
+            ReqIn: TypeGuard<'t>,
+            ReqOut: TypeGuard<'t>,
+            ReqF1: TCloneableOf5<'t> + NotT5Of5<'t>,
+            TInner: Traversable<'t, ReqIn, ReqOut, ReqF1> + CloneK<'t, ReqOut>,
+        > Traversable<'t, ReqIn, ReqOut, ReqF1> for $hkt<TInner>
+        {
+            fn traverse<'a, A, B, F, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+                clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+                f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+                fa: Self::F<'a, A>,
+            ) -> F::F<'a, Self::F<'a, B>>
+            where
+                A: 'a,
+                B: 'a,
+                F1Once: 'a + FnOnce(A) -> F::F<'a, B>,
+                F1Mut: 'a + FnMut(A) -> F::F<'a, B>,
+                F1Fn: 'a + Fn(A) -> F::F<'a, B>,
+                F1Clone: 'a + Fn(A) -> F::F<'a, B> + Clone,
+                F1Copy: 'a + Fn(A) -> F::F<'a, B> + Copy,
+                F: Applicative<'t, ReqIn, ReqOut, ReqF1> + Functor<'t, ReqIn, ConstBool<false>, ReqF1>,
+                't: 'a,
+            {
+                let tag = ReqF1::create_from(&f, ());
+
+                let values = <VecT<TInner> as Traversable<'t, ReqIn, ReqOut, ReqF1>>::traverse::<
+                    A,
+                    B,
+                    F,
+                    _,
+                    _,
+                    _,
+                    _,
+                    _,
+                >(clone_a, clone_b.clone(), f, fa.into_iter().collect::<Vec<_>>());
+
+                <F as Functor<'t, ReqIn, ConstBool<false>, ReqF1>>::map(
+                    move |vec: &Vec<TInner::F<'a, B>>| {
+                        vec.iter()
+                            .map(|b| TInner::clone(clone_b.clone(), b))
+                            .collect::<Vec<_>>()
+                            .pipe(ReqIn::into_guarded)
+                    },
+                    |_| AssertBlankOutput,
+                    ReqF1::create_from(&tag, |vec: Vec<TInner::F<'a, B>>| {
+                        vec.into_iter().collect::<$collection<_>>()
+                    }),
+                    values,
+                )
+            }
+        }
+
+        impl<'t, TInner: CovariantK<'t>> CovariantK<'t> for $hkt<TInner> {
+            fn covariant_convert<'a, 'b, A>(a: Self::F<'a, A>) -> Self::F<'b, A>
+            where
+                A: 'a,
+                'a: 'b,
+                't: 'a + 'b,
+            {
+                a.into_iter()
+                    .map(TInner::covariant_convert)
+                    .collect::<$collection<_>>()
+            }
+        }
+
+        impl<'t, ReqIn: TypeGuard<'t>, TInner: CloneOwnedK<'t, ReqIn>> CloneOwnedK<'t, ReqIn>
+            for $hkt<TInner>
+        {
+            fn clone_owned<'a, 'b, A>(
+                clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'b, A> + Clone,
+                a: &Self::F<'a, A>,
+            ) -> Self::F<'b, A>
+            where
+                A: 'a + 'b,
+                't: 'a + 'b,
+            {
+                a.iter()
+                    .map(|ta| TInner::clone_owned(clone_a.clone(), ta))
+                    .collect::<$collection<_>>()
+            }
+        }
+
+        impl<'t, ReqIn: TypeGuard<'t>, TInner: CloneK<'t, ReqIn>> CloneK<'t, ReqIn> for $hkt<TInner> {
+            fn clone<'a, A>(
+                clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+                a: &Self::F<'a, A>,
+            ) -> Self::F<'a, A>
+            where
+                A: 'a,
+                't: 'a,
+            {
+                a.iter()
+                    .map(|ta| TInner::clone(clone_a.clone(), ta))
+                    .collect::<$collection<_>>()
+            }
+        }
+    };
+}
+
+/// Generates the hkt over the values of a map keyed by `K`, [Rfoldable](crate::hkt::Rfoldable) is left to each map since only ordered maps can reverse.
+///
+/// Shared by [HashMapT](crate::hkt::hash_map::HashMapT) and [BTreeMapT](crate::hkt::btree_map::BTreeMapT).
+macro_rules! impl_map_hkt {
+    ($(#[$meta:meta])* $hkt:ident, $map:ident, $($key_bounds:tt)+) => {
+        $(#[$meta])*
+        pub struct $hkt<K, TInner = IdHkt>(Infallible, PhantomMarker<(TInner, K)>);
+
+        impl<'t, K: 't, TInner: Hkt<'t>> Hkt<'t> for $hkt<K, TInner> {
+            type F<'a, A: 'a>
+                = $map<K, TInner::F<'a, A>>
+            where
+                't: 'a;
+        }
+
+        impl<'t, K: 't, TInner: HktUnsized<'t>> HktUnsized<'t> for $hkt<K, TInner> {
+            type FUnsized<'a, A: 'a + ?Sized>
+                = $map<K, TInner::FUnsized<'a, A>>
+            where
+                't: 'a;
+        }
+
+        impl<K, TInner> HktClassification for $hkt<K, TInner> {
+            type Choice = hkt_classification::OuterHkt;
+        }
+
+        impl<
+            't,
+            K: 't + $($key_bounds)+,
+            ReqIn: TypeGuard<'t>,
+            ReqOut: TypeGuard<'t>,
+            ReqF1: TCloneableOf5<'t>,
+            TInner: Functor<'t, ReqIn, ReqOut, ReqF1>,
+        > Functor<'t, ReqIn, ReqOut, ReqF1> for $hkt<K, TInner>
+        {
+            fn map<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+                clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+                f: <ReqF1>::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+                fa: Self::F<'a, A>,
+            ) -> Self::F<'a, B>
+            where
+                A: 'a,
+                B: 'a,
+                F1Once: 'a + FnOnce(A) -> B,
+                F1Mut: 'a + FnMut(A) -> B,
+                F1Fn: 'a + Fn(A) -> B,
+                F1Clone: 'a + Fn(A) -> B + Clone,
+                F1Copy: 'a + Fn(A) -> B + Copy,
+                't: 'a,
+            {
+                let f = ReqF1::arbitrary_uncloneable(f, PhantomData::<fn(A) -> B>);
+
+                fa.into_iter()
+                    .map(move |(k, x)| {
+                        let x = TInner::map(
+                            clone_a.clone(),
+                            clone_b.clone(),
+                            ReqF1::clone_one_of_5(&f),
+                            x,
+                        );
+
+                        (k, x)
+                    })
+                    .collect::<$map<_, _>>()
+            }
+        }
+
+        impl<
+            't,
+            K: 't,
+            ReqIn: TypeGuard<'t>,
+            ReqOut: TypeGuard<'t>,
+            ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+            TInner: Foldable<'t, ReqIn, ReqOut, ReqF1>,
+        > Foldable<'t, ReqIn, ReqOut, ReqF1> for $hkt<K, TInner>
+        {
+            fn fold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+                clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+                f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+                init: B,
+                fa: Self::F<'a, A>,
+            ) -> FoldWhile<B>
+            where
+                A: 'a,
+                B: 'b,
+                F1Once: 'f + FnOnce(B, A) -> FoldWhile<B>,
+                F1Mut: 'f + FnMut(B, A) -> FoldWhile<B>,
+                F1Fn: 'f + Fn(B, A) -> FoldWhile<B>,
+                F1Clone: 'f + Fn(B, A) -> FoldWhile<B> + Clone,
+                F1Copy: 'f + Fn(B, A) -> FoldWhile<B> + Copy,
+                'a: 'f,
+                'b: 'f,
+                't: 'a + 'b,
+            {
+                let mut f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, A) -> FoldWhile<B>>);
+
+                fa.into_values().try_fold(init, move |b, ka| {
+                    let f_ref = ReqF1::as_mut(&mut f)
+                        .pipe(|f| map_one_of_5_with!(ReqF1, f, |mut f| |b, a| f(b, a)));
+
+                    TInner::fold_while(clone_a.clone(), clone_b.clone(), f_ref, b, ka)
+                })
+            }
+
+            fn size_hint<'a, A>(s: &Self::F<'a, A>) -> (usize, Option<usize>)
+            where
+                't: 'a,
+            {
+                sum_size_hints(s.values().map(|x| TInner::size_hint(x)))
+            }
+        }
+
+        impl<
+            't,
+            K: 't,
+            ReqIn: TypeGuard<'t>,
+            ReqOut: TypeGuard<'t>,
+            ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+            TInner: Foldable<'t, ReqIn, ReqOut, ReqF1, RefT>,
+        > Foldable<'t, ReqIn, ReqOut, ReqF1, RefT> for $hkt<K, TInner>
+        {
+            fn fold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                clone_a: impl 'f + Fn(&A) -> <ReqIn>::Output<'a, A> + Clone,
+                clone_b: impl 'f + Fn(&B) -> <ReqOut>::Output<'b, B> + Clone,
+                f: <ReqF1>::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+                init: B,
+                fa: <RefT as Hkt<'t>>::F<'a, Self::F<'a, A>>,
+            ) -> FoldWhile<B>
+            where
+                A: 'a,
+                B: 'b,
+                F1Once: 'f + FnOnce(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+                F1Mut: 'f + FnMut(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+                F1Fn: 'f + Fn(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+                F1Clone: 'f + Fn(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B> + Clone,
+                F1Copy: 'f + Fn(B, <RefT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B> + Copy,
+                'a: 'f,
+                'b: 'f,
+                't: 'a + 'b,
+            {
+                let mut f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, &'a A) -> FoldWhile<B>>);
+
+                fa.values().try_fold(init, move |b, ka| {
+                    let f_ref = ReqF1::as_mut(&mut f)
+                        .pipe(|f| map_one_of_5_with!(ReqF1, f, |mut f| |b, a| f(b, a)));
+
+                    TInner::fold_while(clone_a.clone(), clone_b.clone(), f_ref, b, ka)
+                })
+            }
+        }
+
+        impl<
+            't,
+            K: 't,
+            ReqIn: TypeGuard<'t>,
+            ReqOut: TypeGuard<'t>,
+            ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+            TInner: Foldable<'t, ReqIn, ReqOut, ReqF1, RefMutT>,
+        > Foldable<'t, ReqIn, ReqOut, ReqF1, RefMutT> for $hkt<K, TInner>
+        {
+            fn fold_while<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                clone_a: impl 'f + Fn(&A) -> <ReqIn>::Output<'a, A> + Clone,
+                clone_b: impl 'f + Fn(&B) -> <ReqOut>::Output<'b, B> + Clone,
+                f: <ReqF1>::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+                init: B,
+                fa: <RefMutT as Hkt<'t>>::F<'a, Self::F<'a, A>>,
+            ) -> FoldWhile<B>
+            where
+                A: 'a,
+                B: 'b,
+                F1Once: 'f + FnOnce(B, <RefMutT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+                F1Mut: 'f + FnMut(B, <RefMutT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+                F1Fn: 'f + Fn(B, <RefMutT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B>,
+                F1Clone: 'f + Fn(B, <RefMutT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B> + Clone,
+                F1Copy: 'f + Fn(B, <RefMutT as Hkt<'t>>::F<'a, A>) -> FoldWhile<B> + Copy,
+                'a: 'f,
+                'b: 'f,
+                't: 'a + 'b,
+            {
+                let mut f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, &'a mut A) -> FoldWhile<B>>);
+
+                fa.values_mut().try_fold(init, move |b, ka| {
+                    let f_ref = ReqF1::as_mut(&mut f)
+                        .pipe(|f| map_one_of_5_with!(ReqF1, f, |mut f| |b, a| f(b, a)));
+
+                    TInner::fold_while(clone_a.clone(), clone_b.clone(), f_ref, b, ka)
+                })
+            }
+        }
+
+        /// Traverses the values in [Foldable] order through [VecT], keys are cloned for each result of `F`.
+        impl<
+            't,
+            K: 't + $($key_bounds)+ + Clone,
+            ReqIn: TypeGuard<'t>,
+            ReqOut: TypeGuard<'t>,
+            ReqF1: TCloneableOf5<'t> + NotT5Of5<'t>,
+            TInner: Traversable<'t, ReqIn, ReqOut, ReqF1> + CloneK<'t, ReqOut>,
+        > Traversable<'t, ReqIn, ReqOut, ReqF1> for $hkt<K, TInner>
+        {
+            fn traverse<'a, A, B, F, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+                clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+                f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+                fa: Self::F<'a, A>,
+            ) -> F::F<'a, Self::F<'a, B>>
+            where
+                A: 'a,
+                B: 'a,
+                F1Once: 'a + FnOnce(A) -> F::F<'a, B>,
+                F1Mut: 'a + FnMut(A) -> F::F<'a, B>,
+                F1Fn: 'a + Fn(A) -> F::F<'a, B>,
+                F1Clone: 'a + Fn(A) -> F::F<'a, B> + Clone,
+                F1Copy: 'a + Fn(A) -> F::F<'a, B> + Copy,
+                F: Applicative<'t, ReqIn, ReqOut, ReqF1> + Functor<'t, ReqIn, ConstBool<false>, ReqF1>,
+                't: 'a,
+            {
+                let tag = ReqF1::create_from(&f, ());
+                let (keys, values): (Vec<_>, Vec<_>) = fa.into_iter().unzip();
+
+                let values = <VecT<TInner> as Traversable<'t, ReqIn, ReqOut, ReqF1>>::traverse::<
+                    A,
+                    B,
+                    F,
+                    _,
+                    _,
+                    _,
+                    _,
+                    _,
+                >(clone_a, clone_b.clone(), f, values);
+
+                let with_keys = ReqF1::create_from(&tag, move |values: Vec<TInner::F<'a, B>>| {
+                    keys.clone()
+                        .into_iter()
+                        .zip(values)
+                        .collect::<$map<_, _>>()
+                })
+                .pipe(|f| {
+                    ReqF1::arbitrary_t5(
+                        f,
+                        PhantomData::<fn(Vec<TInner::F<'a, B>>) -> $map<K, TInner::F<'a, B>>>,
+                    )
+                });
+
+                <F as Functor<'t, ReqIn, ConstBool<false>, ReqF1>>::map(
+                    move |vec: &Vec<TInner::F<'a, B>>| {
+                        vec.iter()
+                            .map(|b| TInner::clone(clone_b.clone(), b))
+                            .collect::<Vec<_>>()
+                            .pipe(ReqIn::into_guarded)
+                    },
+                    |_| AssertBlankOutput,
+                    with_keys,
+                    values,
+                )
+            }
+        }
+
+        impl<'t, K: 't + $($key_bounds)+, TInner: CovariantK<'t>> CovariantK<'t> for $hkt<K, TInner> {
+            fn covariant_convert<'a, 'b, A>(a: Self::F<'a, A>) -> Self::F<'b, A>
+            where
+                A: 'a,
+                'a: 'b,
+                't: 'a + 'b,
+            {
+                a.into_iter()
+                    .map(|(k, ta)| (k, TInner::covariant_convert(ta)))
+                    .collect::<$map<_, _>>()
+            }
+        }
+
+        impl<'t, K: 't + $($key_bounds)+ + Clone, ReqIn: TypeGuard<'t>, TInner: CloneOwnedK<'t, ReqIn>>
+            CloneOwnedK<'t, ReqIn> for $hkt<K, TInner>
+        {
+            fn clone_owned<'a, 'b, A>(
+                clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'b, A> + Clone,
+                a: &Self::F<'a, A>,
+            ) -> Self::F<'b, A>
+            where
+                A: 'a + 'b,
+                't: 'a + 'b,
+            {
+                a.iter()
+                    .map(|(k, ta)| (k.clone(), TInner::clone_owned(clone_a.clone(), ta)))
+                    .collect::<$map<_, _>>()
+            }
+        }
+
+        impl<'t, K: 't + $($key_bounds)+ + Clone, ReqIn: TypeGuard<'t>, TInner: CloneK<'t, ReqIn>>
+            CloneK<'t, ReqIn> for $hkt<K, TInner>
+        {
+            fn clone<'a, A>(
+                clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+                a: &Self::F<'a, A>,
+            ) -> Self::F<'a, A>
+            where
+                A: 'a,
+                't: 'a,
+            {
+                a.iter()
+                    .map(|(k, ta)| (k.clone(), TInner::clone(clone_a.clone(), ta)))
+                    .collect::<$map<_, _>>()
+            }
+        }
+
+        impl<'t, K: 't> WithIndex<'t> for $hkt<K> {
+            type Index = K;
+        }
+
+        /// Each key is cloned to stay in the map.
+        impl<
+            't,
+            K: 't + $($key_bounds)+ + Clone,
+            ReqIn: TypeGuard<'t>,
+            ReqOut: TypeGuard<'t>,
+            ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>,
+        > FunctorWithIndex<'t, ReqIn, ReqOut, ReqF1> for $hkt<K>
+        {
+            fn map_with_index<'a, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                _clone_a: impl 'a + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+                _clone_b: impl 'a + Fn(&B) -> ReqOut::Output<'a, B> + Clone,
+                f: ReqF1::OneOf5F<'a, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+                fa: Self::F<'a, A>,
+            ) -> Self::F<'a, B>
+            where
+                A: 'a,
+                B: 'a,
+                F1Once: 'a + FnOnce(K, A) -> B,
+                F1Mut: 'a + FnMut(K, A) -> B,
+                F1Fn: 'a + Fn(K, A) -> B,
+                F1Clone: 'a + Fn(K, A) -> B + Clone,
+                F1Copy: 'a + Fn(K, A) -> B + Copy,
+                't: 'a,
+            {
+                let f = ReqF1::arbitrary_t1(f, PhantomData::<fn(K, A) -> B>);
+
+                match_one_of_5!(
+                    ReqF1::into_one_of_5_enum(f),
+                    f,
+                    fa.into_iter()
+                        .map(|(k, a)| {
+                            let b = f(k.clone(), a);
+                            (k, b)
+                        })
+                        .collect::<$map<_, _>>()
+                )
+            }
+        }
+
+        impl<'t, K: 't, ReqIn: TypeGuard<'t>, ReqOut: TypeGuard<'t>, ReqF1: OneOf5Hkt<'t> + NotT1Of5<'t>>
+            FoldableWithIndex<'t, ReqIn, ReqOut, ReqF1> for $hkt<K>
+        {
+            fn fold_while_with_index<'a, 'b, 'f, A, B, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>(
+                _clone_a: impl 'f + Fn(&A) -> ReqIn::Output<'a, A> + Clone,
+                _clone_b: impl 'f + Fn(&B) -> ReqOut::Output<'b, B> + Clone,
+                f: ReqF1::OneOf5F<'f, F1Once, F1Mut, F1Fn, F1Clone, F1Copy>,
+                init: B,
+                fa: Self::F<'a, A>,
+            ) -> FoldWhile<B>
+            where
+                A: 'a,
+                B: 'b,
+                F1Once: 'f + FnOnce(B, K, A) -> FoldWhile<B>,
+                F1Mut: 'f + FnMut(B, K, A) -> FoldWhile<B>,
+                F1Fn: 'f + Fn(B, K, A) -> FoldWhile<B>,
+                F1Clone: 'f + Fn(B, K, A) -> FoldWhile<B> + Clone,
+                F1Copy: 'f + Fn(B, K, A) -> FoldWhile<B> + Copy,
+                'a: 'f,
+                'b: 'f,
+                't: 'a + 'b,
+            {
+                let f = ReqF1::arbitrary_t1(f, PhantomData::<fn(B, K, A) -> FoldWhile<B>>);
+
+                match_one_of_5!(
+                    ReqF1::into_one_of_5_enum(f),
+                    f,
+                    fa.into_iter().try_fold(init, |b, (k, a)| f(b, k, a))
+                )
+            }
+        }
+
+        impl<
+            't,
+            K: 't + $($key_bounds)+ + Clone,
+            ReqIn: TypeGuard<'t>,
+            ReqOut: TypeGuard<'t>,
+            ReqF1: OneOf5Hkt<'t>,
+        > TraversableWithIndex<'t, ReqIn, ReqOut, ReqF1> for $hkt<K>
+        {
+        }
+
+        /// Left biased union, values of `a` win on key collisions.
+        impl<'t, K: 't + $($key_bounds)+, Req: TypeGuard<'t>, TInner: Hkt<'t>> SemigroupK<'t, Req>
+            for $hkt<K, TInner>
+        {
+            fn combine<'a, A: 'a>(
+                a: Self::F<'a, A>,
+                mut b: Self::F<'a, A>,
+                _clone_a: impl 'a + Fn(&A) -> Req::Output<'a, A> + Clone,
+            ) -> Self::F<'a, A>
+            where
+                't: 'a,
+            {
+                b.extend(a);
+                b
+            }
+        }
+    };
+}
@@ -51,9 +51,8 @@ pub trait TyEq<T: ?Sized>: sealed::TyEqSealed<T = T> {
 
 impl<T: ?Sized> TyEq<T> for T {}
 
-#[allow(unused)]
 /// Implemented by [`ConstBool<true>`](ConstBool) and [`ConstBool<false>`](ConstBool)
-pub(crate) trait IsConstBool: sealed::BaseSealed {
+pub trait IsConstBool: sealed::BaseSealed {
     const BOOL: bool;
 }
 
@@ -76,8 +75,8 @@ impl<const VAL: bool> IsConstBool for ConstBool<VAL> {
     const BOOL: bool = VAL;
 }
 
-#[allow(unused)]
-pub(crate) trait IsSend<Bool: IsConstBool> {
+/// Implemented by every type for [`ConstBool<false>`](ConstBool), and by [Send] types for [`ConstBool<true>`](ConstBool).
+pub trait IsSend<Bool: IsConstBool> {
     type Sealed: sealed::AssociatedSealed;
 }
 
@@ -92,8 +91,8 @@ impl<T: Send> IsSend<ConstBool<true>> for T {
     type Sealed = sealed::AssociatedKey;
 }
 
-#[allow(unused)]
-pub(crate) trait IsSync<Bool: IsConstBool> {
+/// Implemented by every type for [`ConstBool<false>`](ConstBool), and by [Sync] types for [`ConstBool<true>`](ConstBool).
+pub trait IsSync<Bool: IsConstBool> {
     type Sealed: sealed::AssociatedSealed;
 }
 
@@ -151,6 +150,27 @@ pub trait TypeGuard<'t>: sealed::BaseSealed {
     where
         A: 'a,
         't: 'a;
+
+    /// Maps the guarded value, a blank output stays blank.
+    fn map_guarded<'a, A, B>(
+        guarded_val: Self::Output<'a, A>,
+        f: impl FnOnce(A) -> B,
+    ) -> Self::Output<'a, B>
+    where
+        A: 'a,
+        B: 'a,
+        't: 'a,
+    {
+        match Self::match_guard(guarded_val) {
+            Ok(a) => Self::into_guarded(f(a)),
+            Err(_) => match Self::try_create_guarded() {
+                Ok(b) => b,
+                Err(TypeGuardWasConstBoolTrue) => {
+                    unreachable!("a guard matching to Err cannot be ConstBool<true>")
+                }
+            },
+        }
+    }
 }
 
 impl<'t> TypeGuard<'t> for ConstBool<false> {
@@ -320,9 +340,26 @@ impl<T: RefUnwindSafe> IsRefUnwindSafe<ConstBool<true>> for T {
 //     }
 // }
 
-#[deprecated = "Unused"]
+/// Proves auto traits from the `IsX<ConstBool<true>>` markers, e.g. to capture closures whose
+/// [Send] requirement is only known through [IsSend].
+///
+/// Capture the whole wrapper (e.g. through [Wrapper::into_inner]), not its field.
 pub(crate) struct Wrapper<T>(T);
 
+impl<T> Wrapper<T> {
+    pub(crate) fn new(t: T) -> Self {
+        Self(t)
+    }
+
+    pub(crate) fn get(&self) -> &T {
+        &self.0
+    }
+
+    pub(crate) fn into_inner(self) -> T {
+        self.0
+    }
+}
+
 // impl<T: IsDebug<ConstBool<true>>> Debug for Wrapper<T> {
 //     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 //         f.debug_tuple("Wrapper").field(&self.0).finish()
@@ -338,6 +375,34 @@ pub(crate) struct Wrapper<T>(T);
 /// SAFETY: IsX<true> is only implemented for T: X
 unsafe impl<T: IsSend<ConstBool<true>>> Send for Wrapper<T> {}
 unsafe impl<T: IsSync<ConstBool<true>>> Sync for Wrapper<T> {}
+
+/// Hkts whose `F<'a, A>` is [Send]/[Sync] whenever `A` is, so layers like
+/// [PinBoxSendFutureT](crate::hkt::future::PinBoxSendFutureT) can hold `TInner` values across an `await`.
+///
+/// # Safety
+///
+/// `F<'a, A>` must not hold anything that is not [Send]/[Sync] other than values of `A`.
+pub unsafe trait AutoTraitsK<'t>: Hkt<'t> {}
+
+/// [Wrapper] for a `K::F<'a, A>`, proving the auto traits of `A` through [AutoTraitsK].
+pub(crate) struct WrapperK<'t, 'a, K: Hkt<'t>, A: 'a>(K::F<'a, A>)
+where
+    't: 'a;
+
+impl<'t, 'a, K: Hkt<'t>, A: 'a> WrapperK<'t, 'a, K, A> {
+    pub(crate) fn new(fa: K::F<'a, A>) -> Self {
+        Self(fa)
+    }
+
+    pub(crate) fn into_inner(self) -> K::F<'a, A> {
+        self.0
+    }
+}
+
+/// SAFETY: [AutoTraitsK] guarantees `K::F<'a, A>` is [Send] when `A` is.
+unsafe impl<'t, 'a, K: AutoTraitsK<'t>, A: IsSend<ConstBool<true>>> Send for WrapperK<'t, 'a, K, A> {}
+/// SAFETY: [AutoTraitsK] guarantees `K::F<'a, A>` is [Sync] when `A` is.
+unsafe impl<'t, 'a, K: AutoTraitsK<'t>, A: IsSync<ConstBool<true>>> Sync for WrapperK<'t, 'a, K, A> {}
 impl<T: IsUnpin<ConstBool<true>>> Unpin for Wrapper<T> {}
 impl<T: IsUnwindSafe<ConstBool<true>>> UnwindSafe for Wrapper<T> {}
 impl<T: IsRefUnwindSafe<ConstBool<true>>> RefUnwindSafe for Wrapper<T> {}
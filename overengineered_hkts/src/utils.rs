@@ -12,6 +12,18 @@ pub(crate) fn placehold() -> ! {
     unimplemented!()
 }
 
+/// Adds up [Iterator::size_hint]-style bounds, an unknown upper bound stays unknown.
+pub(crate) fn sum_size_hints(
+    hints: impl IntoIterator<Item = (usize, Option<usize>)>,
+) -> (usize, Option<usize>) {
+    hints.into_iter().fold((0, Some(0)), |(min, max), (hint_min, hint_max)| {
+        (
+            min.saturating_add(hint_min),
+            max.zip(hint_max).and_then(|(l, r)| l.checked_add(r)),
+        )
+    })
+}
+
 #[allow(unused)]
 pub(crate) trait IntoFnOnce<A> {
     type Ret;